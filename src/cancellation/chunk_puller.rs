@@ -0,0 +1,52 @@
+use super::con_iter::ConIterCancellable;
+use crate::{ChunkPuller, ConcurrentIter};
+
+/// Chunk puller of a cancellable concurrent iterator; i.e., [`ConIterCancellable`].
+///
+/// It stops pulling chunks as soon as the cancellation token is cancelled.
+pub struct ChunkPullerCancellable<'i, I>
+where
+    I: ConcurrentIter + 'i,
+{
+    con_iter: &'i ConIterCancellable<I>,
+    puller: I::ChunkPuller<'i>,
+}
+
+impl<'i, I> ChunkPullerCancellable<'i, I>
+where
+    I: ConcurrentIter + 'i,
+{
+    pub(crate) fn new(con_iter: &'i ConIterCancellable<I>, puller: I::ChunkPuller<'i>) -> Self {
+        Self { con_iter, puller }
+    }
+}
+
+impl<'i, I> ChunkPuller for ChunkPullerCancellable<'i, I>
+where
+    I: ConcurrentIter + 'i,
+{
+    type ChunkItem = I::Item;
+
+    type Chunk<'c>
+        = <I::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.puller.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        match self.con_iter.is_cancelled() {
+            true => None,
+            false => self.puller.pull(),
+        }
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        match self.con_iter.is_cancelled() {
+            true => None,
+            false => self.puller.pull_with_idx(),
+        }
+    }
+}
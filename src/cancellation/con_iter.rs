@@ -0,0 +1,146 @@
+use super::{
+    chunk_puller::ChunkPullerCancellable, seq_iter::CancellableSeqIter, token::CancellationToken,
+};
+use crate::{ConcurrentIter, ExactSizeConcurrentIter};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A concurrent iterator which stops as soon as its [`CancellationToken`] is cancelled.
+///
+/// It can be created by calling [`with_cancellation`] on a concurrent iterator.
+///
+/// Once the token is observed to be cancelled, `next`, item pullers and chunk pullers return `None`,
+/// and the underlying iterator is skipped to its end. Whether the iteration ended naturally or was
+/// cancelled can be checked by [`was_cancelled`].
+///
+/// [`with_cancellation`]: crate::ConcurrentIter::with_cancellation
+/// [`was_cancelled`]: crate::cancellation::ConIterCancellable::was_cancelled
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::cancellation::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let con_iter = (0..1000).into_con_iter().with_cancellation(&token);
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for x in con_iter.item_puller() {
+///                 if x == 500 {
+///                     token.cancel();
+///                 }
+///             }
+///         });
+///     }
+/// });
+///
+/// assert!(con_iter.was_cancelled());
+/// ```
+pub struct ConIterCancellable<I>
+where
+    I: ConcurrentIter,
+{
+    iter: I,
+    token: CancellationToken,
+    was_cancelled: AtomicBool,
+}
+
+impl<I> ConIterCancellable<I>
+where
+    I: ConcurrentIter,
+{
+    pub(crate) fn new(iter: I, token: CancellationToken) -> Self {
+        Self {
+            iter,
+            token,
+            was_cancelled: false.into(),
+        }
+    }
+
+    /// Returns true if the iteration is stopped due to the cancellation of the token; false if the iteration
+    /// has not been stopped or ended naturally.
+    pub fn was_cancelled(&self) -> bool {
+        self.was_cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns a reference to the cancellation token observed by this iterator.
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Returns true if the token is cancelled; in which case the underlying iterator is skipped to its end.
+    pub(super) fn is_cancelled(&self) -> bool {
+        match self.was_cancelled() {
+            true => true,
+            false => match self.token.is_cancelled() {
+                true => {
+                    self.was_cancelled.store(true, Ordering::Release);
+                    self.iter.skip_to_end();
+                    true
+                }
+                false => false,
+            },
+        }
+    }
+}
+
+impl<I> ConcurrentIter for ConIterCancellable<I>
+where
+    I: ConcurrentIter,
+{
+    type Item = I::Item;
+
+    type SequentialIter = CancellableSeqIter<I::SequentialIter>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerCancellable<'i, I>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        CancellableSeqIter::new(self.iter.into_seq_iter(), self.token)
+    }
+
+    fn skip_to_end(&self) {
+        self.iter.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        match self.is_cancelled() {
+            true => None,
+            false => self.iter.next(),
+        }
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        match self.is_cancelled() {
+            true => None,
+            false => self.iter.next_with_idx(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.is_cancelled() {
+            true => (0, Some(0)),
+            false => self.iter.size_hint(),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, self.iter.chunk_puller(chunk_size))
+    }
+}
+
+impl<I> ExactSizeConcurrentIter for ConIterCancellable<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    fn len(&self) -> usize {
+        match self.is_cancelled() {
+            true => 0,
+            false => self.iter.len(),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod con_iter;
+mod seq_iter;
+mod token;
+
+pub use chunk_puller::ChunkPullerCancellable;
+pub use con_iter::ConIterCancellable;
+pub use seq_iter::CancellableSeqIter;
+pub use token::CancellationToken;
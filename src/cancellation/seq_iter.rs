@@ -0,0 +1,44 @@
+use super::token::CancellationToken;
+use core::iter::FusedIterator;
+
+/// Sequential counterpart of a cancellable concurrent iterator which stops as soon as the
+/// cancellation token is cancelled.
+pub struct CancellableSeqIter<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    token: CancellationToken,
+}
+
+impl<I> CancellableSeqIter<I>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, token: CancellationToken) -> Self {
+        Self { iter, token }
+    }
+}
+
+impl<I> Iterator for CancellableSeqIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.token.is_cancelled() {
+            true => None,
+            false => self.iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.token.is_cancelled() {
+            true => (0, Some(0)),
+            false => (0, self.iter.size_hint().1),
+        }
+    }
+}
+
+impl<I> FusedIterator for CancellableSeqIter<I> where I: FusedIterator {}
@@ -0,0 +1,142 @@
+use crate::{
+    ChunkPuller, ConcurrentIter, ExactSizeConcurrentIter, IntoConcurrentIter,
+    cancellation::CancellationToken,
+};
+use alloc::{string::ToString, vec::Vec};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn next() {
+    let token = CancellationToken::new();
+    let iter = (0..10).into_con_iter().with_cancellation(&token);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_with_idx(), Some((1, 1)));
+    assert_eq!(iter.len(), 8);
+    assert!(!iter.was_cancelled());
+
+    token.cancel();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_with_idx(), None);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert!(iter.was_cancelled());
+}
+
+#[test]
+fn chunk_puller() {
+    let token = CancellationToken::new();
+    let iter = (0..10).into_con_iter().with_cancellation(&token);
+
+    let mut puller = iter.chunk_puller(4);
+    assert_eq!(
+        puller.pull().map(|x| x.collect::<Vec<_>>()),
+        Some(alloc::vec![0, 1, 2, 3])
+    );
+
+    token.cancel();
+    assert!(puller.pull().is_none());
+    assert!(puller.pull_with_idx().is_none());
+    assert!(iter.was_cancelled());
+}
+
+#[test]
+fn completed_naturally() {
+    let token = CancellationToken::new();
+    let iter = (0..10).into_con_iter().with_cancellation(&token);
+    assert_eq!(iter.item_puller().count(), 10);
+    assert!(!iter.was_cancelled());
+
+    token.cancel();
+    assert_eq!(iter.next(), None);
+    assert!(iter.was_cancelled());
+}
+
+#[test]
+fn shared_token() {
+    let token = CancellationToken::new();
+    let a = (0..10).into_con_iter().with_cancellation(&token);
+    let b = alloc::vec![1, 2, 3]
+        .into_con_iter()
+        .with_cancellation(&token);
+    let c = (0..10)
+        .into_con_iter()
+        .with_cancellation(&CancellationToken::new());
+
+    token.clone().cancel();
+    assert!(token.is_cancelled());
+    assert_eq!(a.next(), None);
+    assert_eq!(b.next(), None);
+    assert_eq!(c.next(), Some(0));
+}
+
+#[test]
+fn into_seq_iter() {
+    let token = CancellationToken::new();
+    let iter = (0..10).into_con_iter().with_cancellation(&token);
+    assert_eq!(iter.next(), Some(0));
+
+    let mut seq = iter.into_seq_iter();
+    assert_eq!(seq.next(), Some(1));
+    token.cancel();
+    assert_eq!(seq.next(), None);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn item_puller(n: usize, nt: usize) {
+    let until = n / 2;
+    let token = CancellationToken::new();
+    let vec: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+    let iter = vec.into_con_iter().with_cancellation(&token);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for (i, x) in iter.item_puller_with_idx() {
+                    match i < until {
+                        true => _ = bag.push(x),
+                        false => token.cancel(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(iter.was_cancelled(), until < n);
+    let mut collected: Vec<_> = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.parse::<usize>().expect(""));
+    assert_eq!(collected.len(), until);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, nt: usize) {
+    let until = n / 2;
+    let token = CancellationToken::new();
+    let iter = (0..n).into_con_iter().with_cancellation(&token);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.chunk_puller(7).flattened() {
+                    match x < until {
+                        true => _ = bag.push(x),
+                        false => token.cancel(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(iter.was_cancelled(), until < n);
+    let mut collected: Vec<_> = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..until).collect::<Vec<_>>());
+}
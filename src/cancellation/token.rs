@@ -0,0 +1,54 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A signal which can be shared among multiple concurrent iterators to stop all of them at once.
+///
+/// Clones of a token share the same state; once any of the clones is cancelled, all clones are cancelled.
+/// The token can be tripped from any thread, such as by a user interrupt or by a thread keeping track of
+/// a deadline.
+///
+/// Concurrent iterators observe the token when they are converted by [`with_cancellation`].
+///
+/// [`with_cancellation`]: crate::ConcurrentIter::with_cancellation
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::cancellation::CancellationToken;
+///
+/// let token = CancellationToken::new();
+///
+/// let stage1 = (0..100).into_con_iter().with_cancellation(&token);
+/// let stage2 = (0..100).into_con_iter().with_cancellation(&token);
+///
+/// assert_eq!(stage1.next(), Some(0));
+/// assert_eq!(stage2.next(), Some(0));
+///
+/// token.cancel();
+///
+/// assert_eq!(stage1.next(), None);
+/// assert_eq!(stage2.next(), None);
+/// assert!(stage1.was_cancelled() && stage2.was_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and all of its clones.
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns true if the token or any of its clones is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::Acquire)
+    }
+}
@@ -1,10 +1,12 @@
 use crate::{
     IntoConcurrentIter,
+    cancellation::{CancellationToken, ConIterCancellable},
     chain::ChainUnknownLenI,
     cloned::ConIterCloned,
     copied::ConIterCopied,
     enumerate::Enumerate,
     pullers::{ChunkPuller, EnumeratedItemPuller, ItemPuller},
+    try_iter::{ConIterTry, FirstErrorBy},
 };
 
 /// An iterator which can safely be used concurrently by multiple threads.
@@ -926,6 +928,45 @@ pub trait ConcurrentIter: Sync {
         Enumerate::new(self)
     }
 
+    /// Converts a concurrent iterator yielding `Result<T, E>` elements into a fallible concurrent iterator
+    /// which short-circuits on the first error.
+    ///
+    /// As soon as an error is observed by any of the threads, it is recorded together with its index and
+    /// this iterator is skipped to its end so that all threads stop. The `first_error_by` argument
+    /// determines which error is kept when multiple threads observe errors; see [`FirstErrorBy`].
+    ///
+    /// See [`TryConcurrentIter`] for the api of the created iterator.
+    ///
+    /// [`TryConcurrentIter`]: crate::TryConcurrentIter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let records = vec!["1", "2", "3", "x", "5", "y"];
+    /// let parsed: Vec<_> = records.iter().map(|x| x.parse::<usize>()).collect();
+    ///
+    /// let iter = parsed.into_con_iter().into_try(FirstErrorBy::Index);
+    ///
+    /// let sum: usize = std::thread::scope(|s| {
+    ///     let handles: Vec<_> = (0..4)
+    ///         .map(|_| s.spawn(|| iter.try_fold(0, |sum, x| Ok(sum + x))))
+    ///         .collect();
+    ///     handles.into_iter().filter_map(|x| x.join().unwrap()).sum()
+    /// });
+    ///
+    /// let (idx, _error) = iter.into_error().unwrap();
+    /// assert_eq!(idx, 3);
+    /// assert!(sum <= 1 + 2 + 3);
+    /// ```
+    fn into_try<T, E>(self, first_error_by: FirstErrorBy) -> ConIterTry<Self, T, E>
+    where
+        Self: ConcurrentIter<Item = Result<T, E>> + Sized,
+    {
+        ConIterTry::new(self, first_error_by)
+    }
+
     /// Creates a chain of this and `other` concurrent iterators.
     ///
     /// It is preferable to call [`chain`] over `chain_inexact` whenever the first iterator
@@ -958,4 +999,42 @@ pub trait ConcurrentIter: Sync {
     {
         ChainUnknownLenI::new(self, other.into_con_iter())
     }
+
+    /// Creates a concurrent iterator which stops as soon as the given cancellation `token` is cancelled.
+    ///
+    /// Once the token is observed to be cancelled, `next`, item pullers and chunk pullers of the created
+    /// iterator return `None`, and this iterator is skipped to its end. The same token can be shared by
+    /// multiple concurrent iterators, such as different stages of a pipeline, to stop all of them at once.
+    ///
+    /// Whether the iteration ended naturally or was cancelled can be checked by [`was_cancelled`].
+    ///
+    /// [`was_cancelled`]: crate::cancellation::ConIterCancellable::was_cancelled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::cancellation::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    ///
+    /// let vec = vec!['x', 'y', 'z'];
+    /// let con_iter = vec.con_iter().with_cancellation(&token);
+    ///
+    /// assert_eq!(con_iter.next(), Some(&'x'));
+    /// token.cancel();
+    /// assert_eq!(con_iter.next(), None);
+    /// assert!(con_iter.was_cancelled());
+    ///
+    /// let token = CancellationToken::new();
+    /// let con_iter = vec.con_iter().with_cancellation(&token);
+    /// while con_iter.next().is_some() {}
+    /// assert!(!con_iter.was_cancelled());
+    /// ```
+    fn with_cancellation(self, token: &CancellationToken) -> ConIterCancellable<Self>
+    where
+        Self: Sized,
+    {
+        ConIterCancellable::new(self, token.clone())
+    }
 }
@@ -0,0 +1,85 @@
+use crate::ConcurrentIter;
+
+/// A type which can concurrently retain only the elements satisfying a predicate, the concurrent
+/// counterpart of [`Vec::retain`].
+///
+/// [`Vec::retain`]: alloc::vec::Vec::retain
+///
+/// Calling `con_retain` creates a concurrent iterator:
+///
+/// * threads pulling from the iterator evaluate the predicate concurrently on the visited elements,
+/// * elements for which the predicate returns `false` are removed and yielded by the iterator,
+///   handing them to the consumer,
+/// * elements for which the predicate returns `true` are kept.
+///
+/// Once the iterator is dropped, the predicate is evaluated for the elements which are not visited
+/// yet and the removed ones among them are dropped. Finally, the kept elements are compacted in
+/// their original order.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let mut v: Vec<_> = (0..10).collect();
+/// let mut removed: Vec<_> = v.con_retain(|x| x % 3 == 0).item_puller().collect();
+/// removed.sort();
+///
+/// assert_eq!(v, [0, 3, 6, 9]);
+/// assert_eq!(removed, [1, 2, 4, 5, 7, 8]);
+/// ```
+pub trait ConcurrentRetainable {
+    /// Type of the elements of the collection.
+    type Item;
+
+    /// Type of the concurrent iterator created by `con_retain`, yielding the removed elements.
+    type RetainingIter<'a, F>: ConcurrentIter<Item = Self::Item>
+    where
+        Self: 'a,
+        F: Fn(&Self::Item) -> bool + Sync;
+
+    /// Creates a concurrent iterator such that:
+    ///
+    /// * the iterator yields the elements for which the `predicate` returns `false`, removing them
+    ///   from the collection,
+    /// * elements for which the `predicate` returns `true` are kept in the collection in their
+    ///   original order.
+    ///
+    /// The predicate is evaluated concurrently by the threads pulling elements from the iterator.
+    /// If the iterator is dropped before being fully consumed, the predicate is evaluated sequentially
+    /// for the remaining elements, and the removed ones are dropped.
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped (due to mem::forget, for example),
+    /// `self` may have lost and leaked elements arbitrarily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let mut v: Vec<_> = (0..1000).map(|x| x.to_string()).collect();
+    ///
+    /// let con_iter = v.con_retain(|x| x.ends_with('7'));
+    /// let num_removed = std::sync::atomic::AtomicUsize::new(0);
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| {
+    ///             let mut puller = con_iter.chunk_puller(16);
+    ///             while let Some(chunk) = puller.pull() {
+    ///                 let _ = num_removed.fetch_add(chunk.len(), std::sync::atomic::Ordering::Relaxed);
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// drop(con_iter);
+    ///
+    /// assert_eq!(num_removed.into_inner(), 900);
+    /// assert_eq!(v.len(), 100);
+    /// assert_eq!(&v[..3], &["7", "17", "27"]);
+    /// ```
+    fn con_retain<F>(&mut self, predicate: F) -> Self::RetainingIter<'_, F>
+    where
+        F: Fn(&Self::Item) -> bool + Sync;
+}
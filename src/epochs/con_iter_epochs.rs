@@ -0,0 +1,101 @@
+use crate::ResettableConcurrentIter;
+use alloc::vec::Vec;
+
+/// A fixed number of passes, or epochs, over the source of a [`ResettableConcurrentIter`].
+///
+/// It can be created by calling [`epochs`] on a resettable concurrent iterator.
+///
+/// Each epoch is an independent concurrent iterator yielding all elements of the source from the
+/// beginning. Therefore, threads of a fixed team can move on to the next epoch as soon as the
+/// current one is exhausted, without waiting for the iterator to be reset. When the algorithm
+/// requires all threads to complete a pass before starting the next one, such as k-means updating
+/// its centroids, the caller is expected to synchronize the threads in between, for instance with a
+/// [`std::sync::Barrier`].
+///
+/// Once all epochs are consumed, [`reset`] prepares them for another round of passes.
+///
+/// [`epochs`]: crate::ResettableConcurrentIter::epochs
+/// [`reset`]: crate::epochs::ConIterEpochs::reset
+/// [`std::sync::Barrier`]: https://doc.rust-lang.org/std/sync/struct.Barrier.html
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::sync::{Barrier, atomic::{AtomicUsize, Ordering}};
+///
+/// let vec: Vec<_> = (0..1000).collect();
+/// let epochs = vec.con_iter().epochs(4);
+/// let sums: Vec<_> = (0..epochs.num_epochs()).map(|_| AtomicUsize::new(0)).collect();
+/// let barrier = Barrier::new(3);
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..3 {
+///         s.spawn(|| {
+///             for (e, con_iter) in epochs.iter().enumerate() {
+///                 let sum: usize = con_iter.item_puller().sum();
+///                 sums[e].fetch_add(sum, Ordering::Relaxed);
+///                 barrier.wait();
+///             }
+///         });
+///     }
+/// });
+///
+/// assert!(sums.iter().all(|x| x.load(Ordering::Relaxed) == 499500));
+/// ```
+pub struct ConIterEpochs<I>
+where
+    I: ResettableConcurrentIter,
+{
+    epochs: Vec<I>,
+}
+
+impl<I> ConIterEpochs<I>
+where
+    I: ResettableConcurrentIter,
+{
+    pub(crate) fn new(iter: I, num_epochs: usize) -> Self {
+        let mut epochs = Vec::with_capacity(num_epochs);
+        if num_epochs > 0 {
+            epochs.extend((1..num_epochs).map(|_| iter.restarted()));
+            epochs.push(iter);
+        }
+        Self { epochs }
+    }
+
+    /// Returns the number of epochs.
+    pub fn num_epochs(&self) -> usize {
+        self.epochs.len()
+    }
+
+    /// Returns the concurrent iterator of the `epoch`-th pass; None if `epoch` is out of bounds.
+    pub fn epoch(&self, epoch: usize) -> Option<&I> {
+        self.epochs.get(epoch)
+    }
+
+    /// Returns an iterator over the concurrent iterators of the epochs, in order.
+    ///
+    /// Each thread of the team is expected to iterate over the epochs and pull elements from
+    /// each of them in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, I> {
+        self.epochs.iter()
+    }
+
+    /// Resets concurrent iterators of all epochs so that the same number of passes can be run once again.
+    pub fn reset(&mut self) {
+        self.epochs.iter_mut().for_each(|x| x.reset());
+    }
+}
+
+impl<'a, I> IntoIterator for &'a ConIterEpochs<I>
+where
+    I: ResettableConcurrentIter,
+{
+    type Item = &'a I;
+
+    type IntoIter = core::slice::Iter<'a, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod tests;
+
+mod con_iter_epochs;
+
+pub use con_iter_epochs::ConIterEpochs;
@@ -0,0 +1,71 @@
+use crate::{
+    ChunkPuller, ConcurrentCollection, ConcurrentIter, IntoConcurrentIter,
+    ResettableConcurrentIter, epochs::ConIterEpochs,
+};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Barrier;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn epochs_of_partially_consumed_iter() {
+    let iter = (0..5).into_con_iter();
+    let _ = iter.next();
+    let _ = iter.next();
+
+    let epochs = iter.epochs(3);
+    assert_eq!(epochs.num_epochs(), 3);
+    for con_iter in &epochs {
+        assert_eq!(con_iter.item_puller().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    }
+    assert!(epochs.epoch(3).is_none());
+}
+
+#[test]
+fn zero_epochs() {
+    let epochs = (0..5).into_con_iter().epochs(0);
+    assert_eq!(epochs.num_epochs(), 0);
+    assert!(epochs.iter().next().is_none());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [1, 3])]
+fn epochs_with_barrier(n: usize, nt: usize, num_epochs: usize) {
+    let vec: Vec<_> = (0..n).collect();
+    let mut epochs: ConIterEpochs<_> = vec.con_iter().epochs(num_epochs);
+
+    for _ in 0..2 {
+        let sums: Vec<_> = (0..num_epochs).map(|_| AtomicUsize::new(0)).collect();
+        let counts: Vec<_> = (0..num_epochs).map(|_| AtomicUsize::new(0)).collect();
+        let barrier = Barrier::new(nt);
+
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    for (e, con_iter) in epochs.iter().enumerate() {
+                        let mut puller = con_iter.chunk_puller(7);
+                        while let Some(chunk) = puller.pull() {
+                            for x in chunk {
+                                sums[e].fetch_add(*x, Ordering::Relaxed);
+                                counts[e].fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                        barrier.wait();
+                        // all threads completed the e-th pass
+                        assert_eq!(counts[e].load(Ordering::Relaxed), n);
+                    }
+                });
+            }
+        });
+
+        let expected = n * n.saturating_sub(1) / 2;
+        assert!(sums.iter().all(|x| x.load(Ordering::Relaxed) == expected));
+        assert!(epochs.iter().all(|x| x.next().is_none()));
+
+        epochs.reset();
+    }
+}
@@ -0,0 +1,45 @@
+use super::con_iter::ConIterArcSlice;
+use crate::pullers::ChunkPuller;
+use core::iter::Cloned;
+
+pub struct ChunkPullerArcSlice<'i, T> {
+    con_iter: &'i ConIterArcSlice<T>,
+    chunk_size: usize,
+}
+
+impl<'i, T> ChunkPullerArcSlice<'i, T> {
+    pub(super) fn new(con_iter: &'i ConIterArcSlice<T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'i, T> ChunkPuller for ChunkPullerArcSlice<'i, T>
+where
+    T: Clone,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = Cloned<core::slice::Iter<'i, T>>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_slice(self.chunk_size)
+            .map(|(_, slice)| slice.iter().cloned())
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter
+            .progress_and_get_slice(self.chunk_size)
+            .map(|(begin_idx, slice)| (begin_idx, slice.iter().cloned()))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{ExactSizeConcurrentIter, implementations::ConIterArcSlice};
+use core::fmt::Debug;
+
+impl<T> Debug for ConIterArcSlice<T>
+where
+    T: Send + Sync + Clone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterArcSlice")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
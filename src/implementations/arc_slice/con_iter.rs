@@ -0,0 +1,118 @@
+use super::{chunk_puller::ChunkPullerArcSlice, into_seq_iter::ArcSliceIntoSeqIter};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Concurrent iterator of a shared slice `Arc<[T]>` yielding clones of its elements.
+///
+/// It can be created by calling [`into_con_iter`] on an `Arc<[T]>`.
+///
+/// Since the elements of a shared slice cannot be moved out, the iterator clones them.
+/// In order to iterate over references instead, `into_con_iter` can be called on `&Arc<[T]>`.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::sync::Arc;
+///
+/// let arc: Arc<[String]> = Arc::from(vec!["a".to_string(), "b".to_string()]);
+///
+/// let con_iter = (&arc).into_con_iter();
+/// assert_eq!(con_iter.next(), Some(&"a".to_string()));
+///
+/// let con_iter = arc.clone().into_con_iter();
+/// assert_eq!(con_iter.next(), Some("a".to_string()));
+/// assert_eq!(con_iter.next(), Some("b".to_string()));
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub struct ConIterArcSlice<T> {
+    slice: Arc<[T]>,
+    counter: AtomicUsize,
+}
+
+impl<T> ConIterArcSlice<T> {
+    pub(super) fn new(slice: Arc<[T]>) -> Self {
+        Self {
+            slice,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.slice.len() {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    pub(super) fn progress_and_get_slice(&self, chunk_size: usize) -> Option<(usize, &[T])> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size)
+                    .min(self.slice.len())
+                    .max(begin_idx);
+                (begin_idx, &self.slice[begin_idx..end_idx])
+            })
+    }
+}
+
+impl<T> ConcurrentIter for ConIterArcSlice<T>
+where
+    T: Send + Sync + Clone,
+{
+    type Item = T;
+
+    type SequentialIter = ArcSliceIntoSeqIter<T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerArcSlice<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let num_taken = self.counter.load(Ordering::Acquire).min(self.slice.len());
+        ArcSliceIntoSeqIter::new(self.slice, num_taken)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.slice.len(), Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| self.slice[idx].clone())
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, self.slice[idx].clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.slice.len().saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterArcSlice<T>
+where
+    T: Send + Sync + Clone,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.slice.len().saturating_sub(num_taken)
+    }
+}
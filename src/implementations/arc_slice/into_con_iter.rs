@@ -0,0 +1,29 @@
+use super::con_iter::ConIterArcSlice;
+use crate::{implementations::slice::ConIterSlice, into_concurrent_iter::IntoConcurrentIter};
+use alloc::sync::Arc;
+
+impl<T> IntoConcurrentIter for Arc<[T]>
+where
+    T: Send + Sync + Clone,
+{
+    type Item = T;
+
+    type IntoIter = ConIterArcSlice<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoConcurrentIter for &'a Arc<[T]>
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterSlice<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
@@ -0,0 +1,42 @@
+use alloc::sync::Arc;
+use core::iter::FusedIterator;
+
+/// Sequential iterator yielding clones of the remaining elements of a shared slice, obtained by
+/// converting the concurrent iterator [`ConIterArcSlice`] into a sequential iterator.
+///
+/// [`ConIterArcSlice`]: crate::implementations::ConIterArcSlice
+pub struct ArcSliceIntoSeqIter<T> {
+    slice: Arc<[T]>,
+    current: usize,
+}
+
+impl<T> ArcSliceIntoSeqIter<T> {
+    pub(super) fn new(slice: Arc<[T]>, current: usize) -> Self {
+        Self { slice, current }
+    }
+}
+
+impl<T: Clone> Iterator for ArcSliceIntoSeqIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.slice.get(self.current).cloned();
+        if value.is_some() {
+            self.current += 1;
+        }
+        value
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for ArcSliceIntoSeqIter<T> {
+    fn len(&self) -> usize {
+        self.slice.len() - self.current
+    }
+}
+
+impl<T: Clone> FusedIterator for ArcSliceIntoSeqIter<T> {}
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod into_con_iter;
+mod into_seq_iter;
+
+pub use con_iter::ConIterArcSlice;
+pub use into_seq_iter::ArcSliceIntoSeqIter;
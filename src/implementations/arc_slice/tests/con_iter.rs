@@ -0,0 +1,93 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    into_concurrent_iter::IntoConcurrentIter, pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn new_arc(n: usize) -> Arc<[String]> {
+    (0..n).map(|i| (i + 10).to_string()).collect()
+}
+
+#[test]
+fn enumeration() {
+    let arc = new_arc(3);
+    let iter = arc.clone().into_con_iter();
+
+    assert_eq!(iter.next(), Some(10.to_string()));
+    assert_eq!(iter.next_with_idx(), Some((1, 11.to_string())));
+    assert_eq!(iter.next(), Some(12.to_string()));
+    assert_eq!(iter.next(), None);
+
+    let iter = (&arc).into_con_iter();
+    assert_eq!(iter.next(), Some(&10.to_string()));
+}
+
+#[test]
+fn size_hint() {
+    let iter = new_arc(26).into_con_iter();
+    assert_eq!(iter.len(), 26);
+
+    let _ = iter.next();
+    assert_eq!(iter.size_hint(), (25, Some(25)));
+
+    let _ = iter.chunk_puller(10).pull();
+    assert_eq!(iter.len(), 15);
+
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [1, 7, 64])]
+fn chunk_puller_with_idx(n: usize, nt: usize, chunk_size: usize) {
+    let arc = new_arc(n);
+    let iter = arc.clone().into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        assert_eq!(x, arc[begin_idx + i]);
+                        bag.push(x);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = arc.iter().cloned().collect();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn into_seq_iter(n: usize, until: usize) {
+    let until = until.min(n);
+    let iter = new_arc(n).into_con_iter();
+    for _ in 0..until {
+        _ = iter.next();
+    }
+
+    let seq = iter.into_seq_iter();
+    assert_eq!(seq.len(), n - until);
+    let remaining: Vec<_> = seq.collect();
+    let expected: Vec<_> = (until..n).map(|i| (i + 10).to_string()).collect();
+    assert_eq!(remaining, expected);
+}
@@ -0,0 +1 @@
+mod con_iter;
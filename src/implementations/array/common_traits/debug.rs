@@ -0,0 +1,17 @@
+use crate::{ExactSizeConcurrentIter, implementations::ConIterArray};
+use core::fmt::Debug;
+
+impl<T, const N: usize> Debug for ConIterArray<T, N>
+where
+    T: Send + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = N - remaining;
+        f.debug_struct("ConIterArray")
+            .field("initial_len", &N)
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
@@ -0,0 +1,174 @@
+use super::into_seq_iter::ArrayIntoIter;
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::{
+        array_utils::{ArrayChunkPuller, ArrayConIter, ArrayIntoSeqIter, ChunkPointers},
+        ptr_utils::take,
+    },
+};
+use core::{
+    cell::UnsafeCell,
+    mem::{ManuallyDrop, MaybeUninit},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator of an owned fixed-size array `[T; N]`.
+///
+/// It can be created by calling [`into_con_iter`] on an array.
+///
+/// The elements are moved out of the array which is stored in place, no allocation is required.
+/// Since elements are moved out through a shared reference and leave uninitialized positions behind,
+/// the array is held as [`MaybeUninit`] in an [`UnsafeCell`].
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let array = [1, 2];
+/// let con_iter = array.into_con_iter();
+/// assert_eq!(con_iter.next(), Some(1));
+/// assert_eq!(con_iter.next(), Some(2));
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub struct ConIterArray<T, const N: usize> {
+    array: UnsafeCell<MaybeUninit<[T; N]>>,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send, const N: usize> Sync for ConIterArray<T, N> {}
+
+impl<T, const N: usize> Drop for ConIterArray<T, N> {
+    fn drop(&mut self) {
+        let num_taken = self.num_taken();
+        let _iter = self.slice_into_seq_iter(num_taken);
+    }
+}
+
+impl<T, const N: usize> ConIterArray<T, N> {
+    pub(super) fn new(array: [T; N]) -> Self {
+        Self {
+            array: UnsafeCell::new(MaybeUninit::new(array)),
+            counter: 0.into(),
+        }
+    }
+
+    fn ptr(&self) -> *const T {
+        // MaybeUninit is transparent; the pointer grants write access since it is obtained from the cell
+        self.array.get() as *const T
+    }
+
+    fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(N)
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < N {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    /// Creates an iterator over the elements starting from `num_taken` in place, which makes sure
+    /// that they are dropped if not consumed.
+    ///
+    /// The iterator must be consumed or dropped before `self` is moved.
+    fn slice_into_seq_iter(&self, num_taken: usize) -> ArrayIntoSeqIter<T, ()> {
+        let completed = num_taken == N;
+        let (last, current) = match completed {
+            true => (core::ptr::null(), core::ptr::null()),
+            false => {
+                // SAFETY: N is positive here, would be completed o/w
+                let last = unsafe { self.ptr().add(N - 1) };
+                // SAFETY: first + num_taken is in bounds
+                let current = unsafe { self.ptr().add(num_taken) };
+                (last, current)
+            }
+        };
+
+        ArrayIntoSeqIter::new(current, last, None, ())
+    }
+}
+
+impl<T, const N: usize> ArrayConIter for ConIterArray<T, N> {
+    type Item = T;
+
+    fn progress_and_get_chunk_pointers(
+        &self,
+        chunk_size: usize,
+    ) -> Option<ChunkPointers<Self::Item>> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size).min(N).max(begin_idx);
+                let first = unsafe { self.ptr().add(begin_idx) }; // ptr + begin_idx is in bounds
+                let last = unsafe { self.ptr().add(end_idx - 1) }; // ptr + end_idx - 1 is in bounds
+                ChunkPointers {
+                    begin_idx,
+                    first,
+                    last,
+                }
+            })
+    }
+}
+
+impl<T, const N: usize> ConcurrentIter for ConIterArray<T, N>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = ArrayIntoIter<T, N>;
+
+    type ChunkPuller<'i>
+        = ArrayChunkPuller<'i, Self>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let num_taken = self.num_taken();
+        let con_iter = ManuallyDrop::new(self);
+        // SAFETY: con_iter is not dropped, ownership of the array is transferred to the sequential iterator
+        let array = unsafe { core::ptr::read(con_iter.array.get()) };
+        ArrayIntoIter::new(array, num_taken)
+    }
+
+    fn skip_to_end(&self) {
+        let current = self.counter.fetch_max(N, Ordering::Acquire);
+        let num_taken_before = current.min(N);
+        let _iter = self.slice_into_seq_iter(num_taken_before);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1) // ptr + idx is in-bounds
+            .map(|idx| unsafe { take(self.ptr().add(idx) as *mut T) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1) // ptr + idx is in-bounds
+            .map(|idx| (idx, unsafe { take(self.ptr().add(idx) as *mut T) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = N.saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T, const N: usize> ExactSizeConcurrentIter for ConIterArray<T, N>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        N.saturating_sub(num_taken)
+    }
+}
@@ -0,0 +1,44 @@
+use super::con_iter::ConIterArray;
+use crate::{
+    implementations::{slice::ConIterSlice, slice_mut::ConIterSliceMut},
+    into_concurrent_iter::IntoConcurrentIter,
+};
+
+impl<T, const N: usize> IntoConcurrentIter for [T; N]
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterArray<T, N>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoConcurrentIter for &'a [T; N]
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterSlice<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self.as_slice())
+    }
+}
+
+impl<'a, T, const N: usize> IntoConcurrentIter for &'a mut [T; N]
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterSliceMut<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self.as_mut_slice())
+    }
+}
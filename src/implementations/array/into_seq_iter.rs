@@ -0,0 +1,69 @@
+use crate::implementations::{array_utils::ArrayIntoSeqIter, ptr_utils::take};
+use core::{iter::FusedIterator, mem::MaybeUninit};
+
+/// Sequential iterator over the remaining elements of an owned array, obtained by converting
+/// the concurrent iterator [`ConIterArray`] into a sequential iterator.
+///
+/// The array is stored in place as [`MaybeUninit`] since the elements which are already taken out
+/// leave uninitialized positions behind; remaining elements that are not consumed are dropped
+/// once the iterator is dropped.
+///
+/// [`ConIterArray`]: crate::implementations::ConIterArray
+pub struct ArrayIntoIter<T, const N: usize> {
+    array: MaybeUninit<[T; N]>,
+    current: usize,
+}
+
+impl<T, const N: usize> ArrayIntoIter<T, N> {
+    /// Creates the iterator over the positions `num_taken..N` of the `array`.
+    ///
+    /// # SAFETY
+    ///
+    /// Elements within `0..num_taken` must already be taken out of the array.
+    pub(super) fn new(array: MaybeUninit<[T; N]>, num_taken: usize) -> Self {
+        Self {
+            array,
+            current: num_taken,
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayIntoIter<T, N> {
+    fn drop(&mut self) {
+        if self.current < N {
+            let ptr = self.array.as_mut_ptr() as *mut T;
+            // SAFETY: both current and N - 1 are in bounds, elements in current..N are not yet taken
+            let [first, last] = [self.current, N - 1].map(|i| unsafe { ptr.add(i) });
+            let _iter = ArrayIntoSeqIter::new(first, last, None, ());
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for ArrayIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current < N {
+            true => {
+                // SAFETY: current is in bounds and not yet taken out
+                let value = unsafe { take((self.array.as_mut_ptr() as *mut T).add(self.current)) };
+                self.current += 1;
+                Some(value)
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = N - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayIntoIter<T, N> {
+    fn len(&self) -> usize {
+        N - self.current
+    }
+}
+
+impl<T, const N: usize> FusedIterator for ArrayIntoIter<T, N> {}
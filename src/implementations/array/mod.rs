@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests;
+
+mod common_traits;
+mod con_iter;
+mod into_con_iter;
+mod into_seq_iter;
+
+pub use con_iter::ConIterArray;
+pub use into_seq_iter::ArrayIntoIter;
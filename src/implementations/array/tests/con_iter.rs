@@ -0,0 +1,146 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    into_concurrent_iter::IntoConcurrentIter, pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 1024;
+
+fn new_array<const L: usize>() -> [String; L] {
+    core::array::from_fn(|i| (i + 10).to_string())
+}
+
+#[test]
+fn enumeration() {
+    let iter = new_array::<4>().into_con_iter();
+
+    assert_eq!(iter.next(), Some(10.to_string()));
+    assert_eq!(iter.next_with_idx(), Some((1, 11.to_string())));
+    assert_eq!(iter.next(), Some(12.to_string()));
+    assert_eq!(iter.next_with_idx(), Some((3, 13.to_string())));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_with_idx(), None);
+}
+
+#[test]
+fn size_hint() {
+    let iter = new_array::<26>().into_con_iter();
+    assert_eq!(iter.len(), 26);
+
+    let _ = iter.next();
+    assert_eq!(iter.size_hint(), (25, Some(25)));
+
+    let _ = iter.chunk_puller(10).pull();
+    assert_eq!(iter.len(), 15);
+
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([1, 2, 4], [1, 7, 64])]
+fn chunk_puller_with_idx(nt: usize, chunk_size: usize) {
+    let iter = new_array::<N>().into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        assert_eq!(x, (begin_idx + i + 10).to_string());
+                        bag.push(x);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = new_array::<N>().into_iter().collect();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test_matrix([1, 2, 4])]
+fn skip_to_end(nt: usize) {
+    let iter = new_array::<N>().into_con_iter();
+    let until = N / 2;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some(x) = iter.next() {
+                    match x.parse::<usize>().expect("") < until + 10 {
+                        true => _ = bag.push(x),
+                        false => iter.skip_to_end(),
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let mut expected: Vec<_> = (0..until).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test_matrix([0, 1, N / 2, N])]
+fn into_seq_iter(until: usize) {
+    let iter = new_array::<N>().into_con_iter();
+    for _ in 0..until {
+        _ = iter.next();
+    }
+
+    let seq = iter.into_seq_iter();
+    assert_eq!(seq.len(), N - until);
+    let remaining: Vec<_> = seq.collect();
+    let expected: Vec<_> = (until..N).map(|i| (i + 10).to_string()).collect();
+    assert_eq!(remaining, expected);
+}
+
+#[test_matrix([0, 1, N / 2, N], [0, 1, 10])]
+fn drop_partially_consumed(until: usize, num_seq: usize) {
+    let iter = new_array::<N>().into_con_iter();
+    for _ in 0..until {
+        _ = iter.next();
+    }
+    let _ = iter.chunk_puller(7).pull().map(|mut chunk| chunk.next());
+
+    let mut seq = iter.into_seq_iter();
+    for _ in 0..num_seq {
+        _ = seq.next();
+    }
+}
+
+#[test]
+fn ref_and_mut() {
+    let mut array = new_array::<N>();
+
+    let iter = (&mut array).into_con_iter();
+    while let Some(x) = iter.next() {
+        x.push('!');
+    }
+
+    let iter = (&array).into_con_iter();
+    let mut count = 0;
+    while let Some(x) = iter.next() {
+        assert!(x.ends_with('!'));
+        count += 1;
+    }
+    assert_eq!(count, N);
+}
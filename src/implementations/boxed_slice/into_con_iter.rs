@@ -0,0 +1,45 @@
+use crate::{
+    implementations::{ConIterVec, slice::ConIterSlice, slice_mut::ConIterSliceMut},
+    into_concurrent_iter::IntoConcurrentIter,
+};
+use alloc::{boxed::Box, vec::Vec};
+
+impl<T> IntoConcurrentIter for Box<[T]>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterVec<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        // converting a boxed slice into a vector reuses the allocation
+        Vec::from(self).into_con_iter()
+    }
+}
+
+impl<'a, T> IntoConcurrentIter for &'a Box<[T]>
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterSlice<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoConcurrentIter for &'a mut Box<[T]>
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterSliceMut<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod tests;
+
+mod into_con_iter;
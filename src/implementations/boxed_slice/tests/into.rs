@@ -0,0 +1,59 @@
+use crate::{
+    IntoConcurrentIter, concurrent_collection::ConcurrentCollection,
+    concurrent_collection_mut::ConcurrentCollectionMut, concurrent_iter::ConcurrentIter,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+
+fn new_box(n: usize) -> Box<[String]> {
+    (0..n).map(|i| (i + 10).to_string()).collect()
+}
+
+#[test]
+fn boxed_slice_as_into_concurrent_iter() {
+    let (nt, n) = (2, 177);
+    let boxed = new_box(n);
+
+    let iter = boxed.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((i, x)) = iter.next_with_idx() {
+                    assert_eq!(x, (i + 10).to_string());
+                    bag.push(x);
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = new_box(n).into_vec();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test]
+fn boxed_slice_as_concurrent_collection() {
+    let n = 177;
+    let mut boxed = new_box(n);
+
+    let iter = boxed.con_iter_mut();
+    while let Some(x) = iter.next() {
+        x.push('!');
+    }
+
+    let iter = boxed.con_iter();
+    let mut count = 0;
+    while let Some(x) = iter.next() {
+        assert!(x.ends_with('!'));
+        count += 1;
+    }
+    assert_eq!(count, n);
+}
@@ -0,0 +1,113 @@
+use super::{con_iter_ref::ConIterBTreeRef, ordered_collection::OrderedCollection};
+use crate::pullers::ChunkPuller;
+use core::iter::FusedIterator;
+
+pub struct ChunkPullerBTreeRef<'i, 'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    con_iter: &'i ConIterBTreeRef<'a, C>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, C> ChunkPullerBTreeRef<'i, 'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    pub(super) fn new(con_iter: &'i ConIterBTreeRef<'a, C>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, C> ChunkPuller for ChunkPullerBTreeRef<'_, 'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    type ChunkItem = C::ItemRef<'a>;
+
+    type Chunk<'c>
+        = BTreeRefChunk<'a, C>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
+
+// iter
+
+/// Iterator over a range of consecutive elements of an ordered collection.
+pub struct BTreeRefChunk<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    range: C::Iter<'a>,
+    len: usize,
+}
+
+impl<'a, C> Default for BTreeRefChunk<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    fn default() -> Self {
+        Self {
+            range: Default::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<'a, C> BTreeRefChunk<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    pub(super) fn new(range: C::Iter<'a>, len: usize) -> Self {
+        Self { range, len }
+    }
+}
+
+impl<'a, C> Iterator for BTreeRefChunk<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    type Item = C::ItemRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => {
+                self.len -= 1;
+                self.range.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, C> ExactSizeIterator for BTreeRefChunk<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, C> FusedIterator for BTreeRefChunk<'a, C> where C: OrderedCollection + 'a {}
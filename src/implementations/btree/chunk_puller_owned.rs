@@ -0,0 +1,94 @@
+use super::con_iter_owned::ConIterBTree;
+use crate::pullers::ChunkPuller;
+use alloc::vec::{Drain, Vec};
+use core::iter::FusedIterator;
+
+pub struct ChunkPullerBTree<'i, I>
+where
+    I: ExactSizeIterator,
+{
+    con_iter: &'i ConIterBTree<I>,
+    chunk_size: usize,
+    buffer: Vec<I::Item>,
+}
+
+impl<'i, I> ChunkPullerBTree<'i, I>
+where
+    I: ExactSizeIterator,
+{
+    pub(super) fn new(con_iter: &'i ConIterBTree<I>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        Self {
+            con_iter,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+        }
+    }
+}
+
+impl<I> ChunkPuller for ChunkPullerBTree<'_, I>
+where
+    I: ExactSizeIterator,
+{
+    type ChunkItem = I::Item;
+
+    type Chunk<'c>
+        = BTreeChunk<'c, I::Item>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        // elements of the previous chunk which are not consumed are dropped here
+        self.buffer.clear();
+        self.con_iter
+            .progress_and_fill_buffer(self.chunk_size, &mut self.buffer)
+            .map(|begin_idx| {
+                let chunk = BTreeChunk {
+                    drain: Some(self.buffer.drain(..)),
+                };
+                (begin_idx, chunk)
+            })
+    }
+}
+
+// iter
+
+/// Iterator over consecutive elements moved out of an owned ordered collection.
+pub struct BTreeChunk<'c, T> {
+    drain: Option<Drain<'c, T>>,
+}
+
+impl<T> Default for BTreeChunk<'_, T> {
+    fn default() -> Self {
+        Self { drain: None }
+    }
+}
+
+impl<T> Iterator for BTreeChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.as_mut().and_then(|x| x.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for BTreeChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.drain.as_ref().map(|x| x.len()).unwrap_or(0)
+    }
+}
+
+impl<T> FusedIterator for BTreeChunk<'_, T> {}
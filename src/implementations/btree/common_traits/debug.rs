@@ -0,0 +1,30 @@
+use crate::implementations::btree::{ConIterBTree, ConIterBTreeRef, OrderedCollection};
+use core::fmt::Debug;
+
+impl<'a, C> Debug for ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_taken = self.num_taken();
+        f.debug_struct("ConIterBTreeRef")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &(self.initial_len() - num_taken))
+            .finish()
+    }
+}
+
+impl<I> Debug for ConIterBTree<I>
+where
+    I: ExactSizeIterator,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_taken = self.num_taken();
+        f.debug_struct("ConIterBTree")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &(self.initial_len() - num_taken))
+            .finish()
+    }
+}
@@ -0,0 +1,177 @@
+use super::chunk_puller_owned::ChunkPullerBTree;
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use alloc::vec::Vec;
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator of an owned ordered collection, such as `BTreeMap<K, V>` or `BTreeSet<T>`.
+///
+/// It can be created by calling [`into_con_iter`] on the collection.
+///
+/// The iterator holds a shared cursor which is the owning iterator of the collection. Each pull briefly
+/// locks the cursor and moves the elements of the chunk out into the buffer of the puller. The elements
+/// are then yielded from the buffer without holding the lock. Therefore, only moving the elements out
+/// of the collection is sequential.
+///
+/// The iterator knows its exact length, and `next_with_idx` returns the sorted positions of the elements.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<_, _> = (0..1000).map(|x| (x, x.to_string())).collect();
+///
+/// let con_iter = map.into_con_iter();
+/// assert_eq!(con_iter.len(), 1000);
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             while let Some((idx, (key, value))) = con_iter.next_with_idx() {
+///                 assert_eq!(idx, key); // sorted position
+///                 assert_eq!(value, key.to_string());
+///             }
+///         });
+///     }
+/// });
+/// ```
+pub struct ConIterBTree<I>
+where
+    I: ExactSizeIterator,
+{
+    len: usize,
+    cursor: UnsafeCell<I>,
+    is_locked: AtomicBool,
+    counter: AtomicUsize,
+}
+
+unsafe impl<I> Sync for ConIterBTree<I>
+where
+    I: ExactSizeIterator + Send,
+    I::Item: Send,
+{
+}
+
+impl<I> ConIterBTree<I>
+where
+    I: ExactSizeIterator,
+{
+    pub(super) fn new(iter: I) -> Self {
+        Self {
+            len: iter.len(),
+            cursor: UnsafeCell::new(iter),
+            is_locked: false.into(),
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.len)
+    }
+
+    /// Calls `f` with the cursor and the number of elements taken so far while holding the lock;
+    /// `f` returns the new number of taken elements together with its output.
+    fn with_cursor<O>(&self, f: impl FnOnce(&mut I, usize) -> (usize, O)) -> O {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        // SAFETY: cursor is accessed only while holding the lock or by exclusive reference
+        let cursor = unsafe { &mut *self.cursor.get() };
+        let (num_taken, output) = f(cursor, self.counter.load(Ordering::Acquire));
+        self.counter.store(num_taken, Ordering::Release);
+
+        self.is_locked.store(false, Ordering::Release);
+        output
+    }
+
+    /// Moves the next at most `chunk_size` elements into the `buffer` which is expected to be empty,
+    /// and returns the position of the first of them; returns None if the iterator is consumed.
+    pub(super) fn progress_and_fill_buffer(
+        &self,
+        chunk_size: usize,
+        buffer: &mut Vec<I::Item>,
+    ) -> Option<usize> {
+        self.with_cursor(|cursor, begin_idx| match begin_idx < self.len {
+            true => {
+                buffer.extend(cursor.take(chunk_size));
+                (begin_idx + buffer.len(), Some(begin_idx))
+            }
+            false => (begin_idx, None),
+        })
+    }
+
+    fn progress_and_get_next(&self) -> Option<(usize, I::Item)> {
+        self.with_cursor(|cursor, idx| match cursor.next() {
+            Some(value) => (idx + 1, Some((idx, value))),
+            None => (idx, None),
+        })
+    }
+}
+
+impl<I> ConcurrentIter for ConIterBTree<I>
+where
+    I: ExactSizeIterator + Send,
+    I::Item: Send,
+{
+    type Item = I::Item;
+
+    type SequentialIter = I;
+
+    type ChunkPuller<'i>
+        = ChunkPullerBTree<'i, I>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        self.cursor.into_inner()
+    }
+
+    fn skip_to_end(&self) {
+        self.with_cursor(|cursor, _| {
+            cursor.for_each(drop);
+            (self.len, ())
+        })
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_next().map(|(_, value)| value)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.num_taken();
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<I> ExactSizeConcurrentIter for ConIterBTree<I>
+where
+    I: ExactSizeIterator + Send,
+    I::Item: Send,
+{
+    fn len(&self) -> usize {
+        self.len - self.num_taken()
+    }
+}
@@ -0,0 +1,175 @@
+use super::{
+    chunk_puller::{BTreeRefChunk, ChunkPullerBTreeRef},
+    ordered_collection::OrderedCollection,
+};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator of a reference to an ordered collection, such as `&BTreeMap<K, V>` or `&BTreeSet<T>`.
+///
+/// It can be created by calling [`into_con_iter`] on a reference to the collection; further, sets can
+/// create it by [`con_iter`].
+///
+/// The iterator holds a shared cursor which is a range iterator over the collection. Each pull briefly
+/// locks the cursor, clones it as the beginning of the pulled chunk and skips it ahead by the chunk size.
+/// The elements of the chunk are then iterated by the clone without holding the lock. Therefore, the
+/// iterator does not walk the collection up front, and only the skipping ahead is sequential.
+///
+/// The iterator knows its exact length, and `next_with_idx` returns the sorted positions of the elements.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`con_iter`]: crate::ConcurrentCollection::con_iter
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<_, _> = (0..1000).map(|x| (x, x.to_string())).collect();
+///
+/// let con_iter = (&map).into_con_iter();
+/// assert_eq!(con_iter.len(), 1000);
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             while let Some((idx, (key, value))) = con_iter.next_with_idx() {
+///                 assert_eq!(idx, *key); // sorted position
+///                 assert_eq!(value, &key.to_string());
+///             }
+///         });
+///     }
+/// });
+/// ```
+pub struct ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    len: usize,
+    cursor: UnsafeCell<C::Iter<'a>>,
+    is_locked: AtomicBool,
+    counter: AtomicUsize,
+}
+
+unsafe impl<'a, C> Sync for ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + Sync + 'a,
+    C::Iter<'a>: Send,
+{
+}
+
+impl<'a, C> ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + 'a,
+{
+    pub(super) fn new(collection: &'a C) -> Self {
+        Self {
+            len: collection.len(),
+            cursor: UnsafeCell::new(collection.sorted_iter()),
+            is_locked: false.into(),
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.len)
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, BTreeRefChunk<'a, C>)> {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        let begin_idx = self.counter.load(Ordering::Acquire);
+        let chunk = match begin_idx < self.len {
+            true => {
+                let end_idx = (begin_idx + chunk_size).min(self.len);
+                // SAFETY: cursor is accessed only while holding the lock or by exclusive reference
+                let cursor = unsafe { &mut *self.cursor.get() };
+                let range = cursor.clone();
+                if end_idx > begin_idx {
+                    let _ = cursor.nth(end_idx - begin_idx - 1);
+                }
+                // fetch_max since skip_to_end might have moved the counter to the end without the lock
+                let _ = self.counter.fetch_max(end_idx, Ordering::Release);
+                Some((begin_idx, BTreeRefChunk::new(range, end_idx - begin_idx)))
+            }
+            false => None,
+        };
+
+        self.is_locked.store(false, Ordering::Release);
+        chunk
+    }
+}
+
+impl<'a, C> ConcurrentIter for ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + Sync + 'a,
+    C::ItemRef<'a>: Send,
+    C::Iter<'a>: Send,
+{
+    type Item = C::ItemRef<'a>;
+
+    type SequentialIter = BTreeRefChunk<'a, C>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerBTreeRef<'i, 'a, C>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let current = self.counter.load(Ordering::Acquire).min(self.len);
+        BTreeRefChunk::new(self.cursor.into_inner(), self.len - current)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.len, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_chunk(1)
+            .and_then(|(_, mut chunk)| chunk.next())
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_chunk(1)
+            .and_then(|(idx, mut chunk)| chunk.next().map(|x| (idx, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.len.saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<'a, C> ExactSizeConcurrentIter for ConIterBTreeRef<'a, C>
+where
+    C: OrderedCollection + Sync + 'a,
+    C::ItemRef<'a>: Send,
+    C::Iter<'a>: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.len.saturating_sub(num_taken)
+    }
+}
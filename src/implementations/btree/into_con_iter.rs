@@ -0,0 +1,57 @@
+use super::{con_iter_owned::ConIterBTree, con_iter_ref::ConIterBTreeRef};
+use crate::IntoConcurrentIter;
+use alloc::collections::{BTreeMap, BTreeSet, btree_map, btree_set};
+
+impl<'a, K, V> IntoConcurrentIter for &'a BTreeMap<K, V>
+where
+    K: Ord + Sync,
+    V: Sync,
+{
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = ConIterBTreeRef<'a, BTreeMap<K, V>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoConcurrentIter for &'a BTreeSet<T>
+where
+    T: Ord + Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterBTreeRef<'a, BTreeSet<T>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<K, V> IntoConcurrentIter for BTreeMap<K, V>
+where
+    K: Send,
+    V: Send,
+{
+    type Item = (K, V);
+
+    type IntoIter = ConIterBTree<btree_map::IntoIter<K, V>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self.into_iter())
+    }
+}
+
+impl<T> IntoConcurrentIter for BTreeSet<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterBTree<btree_set::IntoIter<T>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self.into_iter())
+    }
+}
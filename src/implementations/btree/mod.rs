@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod chunk_puller_owned;
+mod common_traits;
+mod con_iter_owned;
+mod con_iter_ref;
+mod into_con_iter;
+mod ordered_collection;
+
+pub use con_iter_owned::ConIterBTree;
+pub use con_iter_ref::ConIterBTreeRef;
+pub use ordered_collection::OrderedCollection;
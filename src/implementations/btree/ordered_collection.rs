@@ -0,0 +1,73 @@
+use alloc::collections::{BTreeMap, BTreeSet, btree_map, btree_set};
+
+/// An ordered collection which can be iterated by a cloneable iterator in sorted order,
+/// such as `BTreeMap` and `BTreeSet`.
+pub trait OrderedCollection {
+    /// Type of the elements yielded when the collection is iterated by reference.
+    type ItemRef<'a>
+    where
+        Self: 'a;
+
+    /// Iterator over the elements of the collection in sorted order, which can be cloned to iterate
+    /// from its current position.
+    type Iter<'a>: Iterator<Item = Self::ItemRef<'a>> + Clone + Default
+    where
+        Self: 'a;
+
+    /// Number of elements of the collection.
+    fn len(&self) -> usize;
+
+    /// Returns true if the collection has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over all elements of the collection in sorted order.
+    fn sorted_iter(&self) -> Self::Iter<'_>;
+}
+
+impl<K, V> OrderedCollection for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type ItemRef<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+
+    type Iter<'a>
+        = btree_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn sorted_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+impl<T> OrderedCollection for BTreeSet<T>
+where
+    T: Ord,
+{
+    type ItemRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Iter<'a>
+        = btree_set::Iter<'a, T>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn sorted_iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    concurrent_collection::ConcurrentCollection, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter, into_concurrent_iter::IntoConcurrentIter,
+    pullers::ChunkPuller,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn new_map(n: usize) -> BTreeMap<usize, String> {
+    (0..n).map(|i| (2 * i, (i + 10).to_string())).collect()
+}
+
+fn new_set(n: usize) -> BTreeSet<usize> {
+    (0..n).map(|i| 2 * i).collect()
+}
+
+#[test]
+fn enumeration() {
+    let set = new_set(70);
+    let iter = set.con_iter();
+
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_with_idx(), Some((1, &2)));
+
+    let mut puller = iter.chunk_puller(40);
+    let (begin_idx, chunk) = puller.pull_with_idx().expect("");
+    assert_eq!(begin_idx, 2);
+    assert_eq!(chunk.len(), 40);
+    assert_eq!(
+        chunk.copied().collect::<Vec<_>>(),
+        (2..42).map(|i| 2 * i).collect::<Vec<_>>()
+    );
+
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 28);
+    assert_eq!(
+        chunk.copied().collect::<Vec<_>>(),
+        (42..70).map(|i| 2 * i).collect::<Vec<_>>()
+    );
+
+    assert!(puller.pull().is_none());
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn size_hint() {
+    let map = new_map(100);
+    let iter = (&map).into_con_iter();
+    assert_eq!(iter.len(), 100);
+
+    let _ = iter.next();
+    assert_eq!(iter.len(), 99);
+    assert_eq!(iter.size_hint(), (99, Some(99)));
+
+    let _ = iter.chunk_puller(33).pull();
+    assert_eq!(iter.len(), 66);
+
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn next_with_idx(n: usize, nt: usize) {
+    let map = new_map(n);
+    let iter = (&map).into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((idx, (k, v))) = iter.next_with_idx() {
+                    assert_eq!(*k, 2 * idx);
+                    assert_eq!(*v, (idx + 10).to_string());
+                    bag.push(idx);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [1, 7, 64])]
+fn chunk_puller_with_idx(n: usize, nt: usize, chunk_size: usize) {
+    let set = new_set(n);
+    let iter = set.con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        assert_eq!(*x, 2 * (begin_idx + i));
+                        bag.push(*x);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).map(|i| 2 * i).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, nt: usize) {
+    let set = new_set(n);
+    let iter = set.con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.chunk_puller(7).flattened() {
+                    bag.push(*x);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).map(|i| 2 * i).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn into_seq_iter(n: usize, until: usize) {
+    let until = until.min(n);
+    let set = new_set(n);
+    let iter = set.con_iter();
+
+    let mut collected: Vec<_> = (0..until).map(|_| *iter.next().expect("")).collect();
+    let seq = iter.into_seq_iter();
+    assert_eq!(seq.len(), n - until);
+    collected.extend(seq.copied());
+    assert_eq!(collected, (0..n).map(|i| 2 * i).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn owned(n: usize, nt: usize) {
+    let map = new_map(n);
+    let iter = map.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((idx, (k, v))) = iter.next_with_idx() {
+                    assert_eq!(k, 2 * idx);
+                    bag.push(v);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let mut expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    assert_eq!(collected, expected);
+}
+
+#[test]
+fn owned_len() {
+    let set = new_set(100);
+    let iter = set.into_con_iter();
+    assert_eq!(iter.len(), 100);
+
+    let _ = iter.next();
+    let _ = iter.chunk_puller(33).pull();
+    assert_eq!(iter.len(), 66);
+    assert_eq!(iter.next_with_idx(), Some((34, 68)));
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [1, 7, 64])]
+fn owned_chunk_puller_with_idx(n: usize, nt: usize, chunk_size: usize) {
+    let map = new_map(n);
+    let iter = map.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, (k, v)) in chunk.enumerate() {
+                        assert_eq!(k, 2 * (begin_idx + i));
+                        bag.push(v);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let mut expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    assert_eq!(collected, expected);
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn owned_into_seq_iter(n: usize, until: usize) {
+    let until = until.min(n);
+    let map = new_map(n);
+    let iter = map.into_con_iter();
+
+    let mut collected: Vec<_> = (0..until).map(|_| iter.next().expect("").0).collect();
+    let seq = iter.into_seq_iter();
+    assert_eq!(seq.len(), n - until);
+    collected.extend(seq.map(|(k, _)| k));
+    assert_eq!(collected, (0..n).map(|i| 2 * i).collect::<Vec<_>>());
+}
+
+#[test]
+fn owned_skip_to_end() {
+    let set = new_set(100);
+    let iter = set.into_con_iter();
+    let _ = iter.next();
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert!(iter.chunk_puller(4).pull().is_none());
+}
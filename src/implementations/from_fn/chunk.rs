@@ -0,0 +1,53 @@
+use core::{iter::FusedIterator, ops::Range};
+
+/// An exact-size iterator yielding `f(i)` for each index `i` of a range.
+///
+/// It is the type of the chunks pulled from a [`ConIterFromFn`].
+///
+/// [`ConIterFromFn`]: crate::implementations::ConIterFromFn
+pub struct FromFnChunk<'c, F> {
+    f: Option<&'c F>,
+    range: Range<usize>,
+}
+
+impl<F> Default for FromFnChunk<'_, F> {
+    fn default() -> Self {
+        Self {
+            f: None,
+            range: 0..0,
+        }
+    }
+}
+
+impl<'c, F> FromFnChunk<'c, F> {
+    pub(super) fn new(f: &'c F, range: Range<usize>) -> Self {
+        Self { f: Some(f), range }
+    }
+}
+
+impl<T, F> Iterator for FromFnChunk<'_, F>
+where
+    F: Fn(usize) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f?;
+        self.range.next().map(f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T, F> ExactSizeIterator for FromFnChunk<'_, F>
+where
+    F: Fn(usize) -> T,
+{
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<T, F> FusedIterator for FromFnChunk<'_, F> where F: Fn(usize) -> T {}
@@ -0,0 +1,43 @@
+use super::{chunk::FromFnChunk, con_iter::ConIterFromFn};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerFromFn<'i, F> {
+    con_iter: &'i ConIterFromFn<F>,
+    chunk_size: usize,
+}
+
+impl<'i, F> ChunkPullerFromFn<'i, F> {
+    pub(super) fn new(con_iter: &'i ConIterFromFn<F>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'i, T, F> ChunkPuller for ChunkPullerFromFn<'i, F>
+where
+    T: Send,
+    F: Fn(usize) -> T,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = FromFnChunk<'i, F>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{ExactSizeConcurrentIter, implementations::ConIterFromFn};
+use core::fmt::Debug;
+
+impl<T, F> Debug for ConIterFromFn<F>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterFromFn")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
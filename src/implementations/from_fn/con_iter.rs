@@ -0,0 +1,125 @@
+use super::{chunk::FromFnChunk, chunk_puller::ChunkPullerFromFn};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    iter::Map,
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A concurrent iterator yielding `f(i)` for each index `i` in `0..len`, without any backing
+/// storage.
+///
+/// It can be created by [`iter::from_fn`].
+///
+/// The element with index `i` is always `f(i)`, regardless of which thread computes it.
+///
+/// [`iter::from_fn`]: crate::iter::from_fn
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::from_fn(4, |i| i * i);
+/// assert_eq!(con_iter.len(), 4);
+/// assert_eq!(con_iter.next(), Some(0));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 1)));
+///
+/// let rest: Vec<_> = con_iter.chunk_puller(8).flattened().collect();
+/// assert_eq!(rest, [4, 9]);
+/// ```
+pub struct ConIterFromFn<F> {
+    f: F,
+    len: usize,
+    counter: AtomicUsize,
+}
+
+impl<F> ConIterFromFn<F> {
+    pub(crate) fn new(len: usize, f: F) -> Self {
+        Self {
+            f,
+            len,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.len {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, FromFnChunk<'_, F>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size).min(self.len).max(begin_idx);
+                (begin_idx, FromFnChunk::new(&self.f, begin_idx..end_idx))
+            })
+    }
+
+    fn remaining(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.len.saturating_sub(num_taken)
+    }
+}
+
+impl<T, F> ConcurrentIter for ConIterFromFn<F>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    type Item = T;
+
+    type SequentialIter = Map<Range<usize>, F>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerFromFn<'i, F>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let current = self.counter.load(Ordering::Acquire).min(self.len);
+        (current..self.len).map(self.f)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.len, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1).map(&self.f)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, (self.f)(idx)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T, F> ExactSizeConcurrentIter for ConIterFromFn<F>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk;
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+
+pub use chunk::FromFnChunk;
+pub use con_iter::ConIterFromFn;
@@ -0,0 +1,86 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter, iter,
+    pullers::ChunkPuller,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn enumeration() {
+    let con_iter = iter::from_fn(3, |i| i * 10);
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next(), Some(0));
+    assert_eq!(con_iter.next_with_idx(), Some((1, 10)));
+    assert_eq!(con_iter.next(), Some(20));
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.next_with_idx(), None);
+    assert_eq!(con_iter.len(), 0);
+}
+
+#[test]
+fn skip_to_end() {
+    let con_iter = iter::from_fn(10, |i| i);
+    let _ = con_iter.next();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.len(), 0);
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn into_seq_iter() {
+    let con_iter = iter::from_fn(10, |i| i);
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(4).pull();
+    assert_eq!(
+        con_iter.into_seq_iter().collect::<Vec<_>>(),
+        [5, 6, 7, 8, 9]
+    );
+}
+
+#[test]
+fn successors_and_once() {
+    let con_iter = iter::successors(Some(1u64), |x| x.checked_mul(2));
+    assert_eq!(con_iter.into_seq_iter().count(), 64);
+
+    let con_iter = iter::once(String::from("a"));
+    assert_eq!(con_iter.len(), 1);
+    assert_eq!(con_iter.next_with_idx(), Some((0, String::from("a"))));
+    assert_eq!(con_iter.next(), None);
+}
+
+#[test_matrix([0, 1, N], [1, 4], [0, 1, 64])]
+fn from_fn(n: usize, nt: usize, chunk: usize) {
+    let con_iter = iter::from_fn(n, |i| i.to_string());
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                        for (i, x) in chunk.enumerate() {
+                            bag.push((begin_idx + i, x));
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    let expected: Vec<_> = (0..n).map(|i| (i, i.to_string())).collect();
+    assert_eq!(collected, expected);
+}
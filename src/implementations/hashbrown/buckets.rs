@@ -0,0 +1,114 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+use hashbrown::raw::RawTable;
+
+/// Atomic progress over the buckets of a raw table.
+///
+/// Ranges of buckets are handed out by a single atomic counter, while the empty buckets are skipped.
+/// The number of full buckets of each range is counted up front so that chunks know their exact length;
+/// and the elements are indexed in the order their ranges are pulled.
+pub(super) struct Buckets {
+    num_buckets: usize,
+    counter: AtomicUsize,
+    num_yielded: AtomicUsize,
+}
+
+impl Buckets {
+    pub(super) fn new<T>(table: &RawTable<T>) -> Self {
+        let num_buckets = match table.is_empty() {
+            true => 0,
+            false => table.buckets(),
+        };
+        Self {
+            num_buckets,
+            counter: 0.into(),
+            num_yielded: 0.into(),
+        }
+    }
+
+    pub(super) fn num_yielded(&self) -> usize {
+        self.num_yielded.load(Ordering::Acquire)
+    }
+
+    pub(super) fn num_pulled_buckets(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.num_buckets)
+    }
+
+    /// Pulls the next range of at most `num_buckets` buckets containing at least one full bucket.
+    ///
+    /// Returns the index of the first element of the range together with the full buckets of the range;
+    /// None if all buckets are already pulled.
+    pub(super) fn pull<T>(
+        &self,
+        table: &RawTable<T>,
+        num_buckets: usize,
+    ) -> Option<(usize, FullBuckets)> {
+        loop {
+            let begin = self.counter.fetch_add(num_buckets, Ordering::Relaxed);
+            match begin < self.num_buckets {
+                true => {
+                    let end = (begin + num_buckets).min(self.num_buckets);
+                    let full = FullBuckets::new(table, begin, end);
+                    if full.len > 0 {
+                        let begin_idx = self.num_yielded.fetch_add(full.len, Ordering::AcqRel);
+                        return Some((begin_idx, full));
+                    }
+                }
+                false => return None,
+            }
+        }
+    }
+
+    /// Pulls all remaining buckets at once, returning the full buckets among them.
+    pub(super) fn pull_remaining<T>(&self, table: &RawTable<T>) -> FullBuckets {
+        let begin = self.counter.fetch_max(self.num_buckets, Ordering::AcqRel);
+        let full = FullBuckets::new(table, begin.min(self.num_buckets), self.num_buckets);
+        self.num_yielded.fetch_add(full.len, Ordering::AcqRel);
+        full
+    }
+
+    /// Upper bound on the number of remaining elements.
+    pub(super) fn remaining_upper_bound(&self, table_len: usize) -> usize {
+        let remaining_buckets = self.num_buckets - self.num_pulled_buckets();
+        remaining_buckets.min(table_len.saturating_sub(self.num_yielded()))
+    }
+}
+
+/// Full buckets within a range of buckets of a raw table.
+#[derive(Default)]
+pub(super) struct FullBuckets {
+    current: usize,
+    end: usize,
+    len: usize,
+}
+
+impl FullBuckets {
+    fn new<T>(table: &RawTable<T>, begin: usize, end: usize) -> Self {
+        // SAFETY: all indices are less than the number of buckets
+        let len = (begin..end)
+            .filter(|i| unsafe { table.is_bucket_full(*i) })
+            .count();
+        Self {
+            current: begin,
+            end,
+            len,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the next full bucket.
+    pub(super) fn next<T>(&mut self, table: &RawTable<T>) -> Option<usize> {
+        while self.current < self.end {
+            let i = self.current;
+            self.current += 1;
+            // SAFETY: i is less than the number of buckets
+            if unsafe { table.is_bucket_full(i) } {
+                self.len -= 1;
+                return Some(i);
+            }
+        }
+        None
+    }
+}
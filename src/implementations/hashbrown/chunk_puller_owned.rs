@@ -0,0 +1,133 @@
+use super::{buckets::FullBuckets, con_iter_owned::ConIterHashTable, hash_table::HashTable};
+use crate::pullers::ChunkPuller;
+use core::iter::FusedIterator;
+use hashbrown::raw::RawTable;
+
+pub struct ChunkPullerHashTable<'i, C>
+where
+    C: HashTable,
+{
+    con_iter: &'i ConIterHashTable<C>,
+    chunk_size: usize,
+}
+
+impl<'i, C> ChunkPullerHashTable<'i, C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(con_iter: &'i ConIterHashTable<C>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'i, C> ChunkPuller for ChunkPullerHashTable<'i, C>
+where
+    C: HashTable,
+{
+    type ChunkItem = C::Item;
+
+    type Chunk<'c>
+        = HashTableChunk<'i, C>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter.pull(self.chunk_size).map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.pull(self.chunk_size)
+    }
+}
+
+// iter
+
+/// Iterator moving out the elements of a range of buckets of a hash table, skipping the empty buckets.
+///
+/// Elements which are not consumed by the time the chunk is dropped are dropped together with the chunk.
+pub struct HashTableChunk<'i, C>
+where
+    C: HashTable,
+{
+    table: Option<&'i RawTable<C::Entry>>,
+    full: FullBuckets,
+}
+
+impl<C> Default for HashTableChunk<'_, C>
+where
+    C: HashTable,
+{
+    fn default() -> Self {
+        Self {
+            table: None,
+            full: Default::default(),
+        }
+    }
+}
+
+impl<'i, C> HashTableChunk<'i, C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(table: &'i RawTable<C::Entry>, full: FullBuckets) -> Self {
+        Self {
+            table: Some(table),
+            full,
+        }
+    }
+}
+
+impl<C> Drop for HashTableChunk<'_, C>
+where
+    C: HashTable,
+{
+    fn drop(&mut self) {
+        if let Some(table) = self.table {
+            while let Some(i) = self.full.next(table) {
+                // SAFETY: i is the index of a full bucket which is exclusively pulled by this chunk
+                unsafe { core::ptr::drop_in_place(table.bucket(i).as_ptr()) };
+            }
+        }
+    }
+}
+
+impl<C> Iterator for HashTableChunk<'_, C>
+where
+    C: HashTable,
+{
+    type Item = C::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = self.table?;
+        self.full.next(table).map(|i| {
+            // SAFETY: i is the index of a full bucket which is exclusively pulled by this chunk,
+            // it is moved out only once
+            let entry = unsafe { table.bucket(i).as_ptr().read() };
+            C::item(entry)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.full.len();
+        (len, Some(len))
+    }
+}
+
+impl<C> ExactSizeIterator for HashTableChunk<'_, C>
+where
+    C: HashTable,
+{
+    fn len(&self) -> usize {
+        self.full.len()
+    }
+}
+
+impl<C> FusedIterator for HashTableChunk<'_, C> where C: HashTable {}
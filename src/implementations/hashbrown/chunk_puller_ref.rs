@@ -0,0 +1,116 @@
+use super::{buckets::FullBuckets, con_iter_ref::ConIterHashTableRef, hash_table::HashTable};
+use crate::pullers::ChunkPuller;
+use core::iter::FusedIterator;
+use hashbrown::raw::RawTable;
+
+pub struct ChunkPullerHashTableRef<'i, 'a, C>
+where
+    C: HashTable,
+{
+    con_iter: &'i ConIterHashTableRef<'a, C>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, C> ChunkPullerHashTableRef<'i, 'a, C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(con_iter: &'i ConIterHashTableRef<'a, C>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, C> ChunkPuller for ChunkPullerHashTableRef<'_, 'a, C>
+where
+    C: HashTable,
+{
+    type ChunkItem = C::ItemRef<'a>;
+
+    type Chunk<'c>
+        = HashTableRefChunk<'a, C>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter.pull(self.chunk_size).map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.pull(self.chunk_size)
+    }
+}
+
+// iter
+
+/// Iterator over the elements of a range of buckets of a hash table, skipping the empty buckets.
+pub struct HashTableRefChunk<'a, C>
+where
+    C: HashTable + 'a,
+{
+    table: Option<&'a RawTable<C::Entry>>,
+    full: FullBuckets,
+}
+
+impl<C> Default for HashTableRefChunk<'_, C>
+where
+    C: HashTable,
+{
+    fn default() -> Self {
+        Self {
+            table: None,
+            full: Default::default(),
+        }
+    }
+}
+
+impl<'a, C> HashTableRefChunk<'a, C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(table: &'a RawTable<C::Entry>, full: FullBuckets) -> Self {
+        Self {
+            table: Some(table),
+            full,
+        }
+    }
+}
+
+impl<'a, C> Iterator for HashTableRefChunk<'a, C>
+where
+    C: HashTable + 'a,
+{
+    type Item = C::ItemRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = self.table?;
+        self.full.next(table).map(|i| {
+            // SAFETY: i is the index of a full bucket
+            let entry = unsafe { table.bucket(i).as_ref() };
+            C::item_ref(entry)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.full.len();
+        (len, Some(len))
+    }
+}
+
+impl<C> ExactSizeIterator for HashTableRefChunk<'_, C>
+where
+    C: HashTable,
+{
+    fn len(&self) -> usize {
+        self.full.len()
+    }
+}
+
+impl<C> FusedIterator for HashTableRefChunk<'_, C> where C: HashTable {}
@@ -0,0 +1,28 @@
+use crate::implementations::hashbrown::{ConIterHashTable, ConIterHashTableRef, HashTable};
+use core::fmt::Debug;
+
+impl<C> Debug for ConIterHashTableRef<'_, C>
+where
+    C: HashTable,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_taken = self.num_yielded();
+        f.debug_struct("ConIterHashTableRef")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .finish()
+    }
+}
+
+impl<C> Debug for ConIterHashTable<C>
+where
+    C: HashTable,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_taken = self.num_yielded();
+        f.debug_struct("ConIterHashTable")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .finish()
+    }
+}
@@ -0,0 +1,186 @@
+use super::{
+    buckets::Buckets, chunk_puller_owned::ChunkPullerHashTable, chunk_puller_owned::HashTableChunk,
+    hash_table::HashTable,
+};
+use crate::concurrent_iter::ConcurrentIter;
+use core::iter::FusedIterator;
+use hashbrown::raw::RawTable;
+
+/// Concurrent iterator of an owned hash table of the [`hashbrown`] crate, such as
+/// `hashbrown::HashMap<K, V>` or `hashbrown::HashSet<T>`.
+///
+/// It can be created by calling [`into_con_iter`] on the hash table; it is available only when
+/// the `hashbrown` feature is enabled.
+///
+/// The raw bucket table is split into index ranges which are handed out by a single atomic counter;
+/// empty buckets are skipped. Elements are moved out of their buckets as they are pulled, and the
+/// elements which are not pulled are dropped together with the iterator. Since the order of the
+/// elements of a hash table is arbitrary, the index of an element is the order in which it is pulled.
+///
+/// The `chunk_size` of a [`chunk_puller`] is a number of buckets rather than a number of elements:
+/// each pull reserves the next `chunk_size` buckets and yields the elements of the full ones among
+/// them. Therefore, a chunk has at most `chunk_size` elements and can be shorter even when more
+/// elements remain, depending on the load factor of the table. Chunks are never empty though.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`chunk_puller`]: crate::ConcurrentIter::chunk_puller
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use hashbrown::HashSet;
+///
+/// let set: HashSet<_> = (0..1000).map(|x| x.to_string()).collect();
+///
+/// let con_iter = set.into_con_iter();
+/// let total_len: usize = std::thread::scope(|s| {
+///     let handles: Vec<_> = (0..4)
+///         .map(|_| s.spawn(|| con_iter.item_puller().map(|x: String| x.len()).sum::<usize>()))
+///         .collect();
+///     handles.into_iter().map(|x| x.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(total_len, (0..1000).map(|x| x.to_string().len()).sum());
+/// ```
+pub struct ConIterHashTable<C>
+where
+    C: HashTable,
+{
+    table: C,
+    buckets: Buckets,
+}
+
+unsafe impl<C> Sync for ConIterHashTable<C>
+where
+    C: HashTable,
+    C::Entry: Send,
+{
+}
+
+impl<C> Drop for ConIterHashTable<C>
+where
+    C: HashTable,
+{
+    fn drop(&mut self) {
+        let table = self.table.raw_table();
+        let mut remaining = self.buckets.pull_remaining(table);
+        while let Some(i) = remaining.next(table) {
+            // SAFETY: i is the index of a full bucket which is not moved out
+            unsafe { core::ptr::drop_in_place(table.bucket(i).as_ptr()) };
+        }
+        self.table.raw_table_mut().clear_no_drop();
+    }
+}
+
+impl<C> ConIterHashTable<C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(table: C) -> Self {
+        let buckets = Buckets::new(table.raw_table());
+        Self { table, buckets }
+    }
+
+    fn raw_table(&self) -> &RawTable<C::Entry> {
+        self.table.raw_table()
+    }
+
+    pub(super) fn pull(&self, chunk_size: usize) -> Option<(usize, HashTableChunk<'_, C>)> {
+        let table = self.raw_table();
+        self.buckets
+            .pull(table, chunk_size)
+            .map(|(begin_idx, full)| (begin_idx, HashTableChunk::new(table, full)))
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.raw_table().len()
+    }
+
+    pub(super) fn num_yielded(&self) -> usize {
+        self.buckets.num_yielded()
+    }
+}
+
+impl<C> ConcurrentIter for ConIterHashTable<C>
+where
+    C: HashTable,
+    C::Entry: Send,
+    C::Item: Send,
+{
+    type Item = C::Item;
+
+    type SequentialIter = HashTableIntoSeqIter<C>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerHashTable<'i, C>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        HashTableIntoSeqIter { con_iter: self }
+    }
+
+    fn skip_to_end(&self) {
+        let table = self.raw_table();
+        let mut remaining = self.buckets.pull_remaining(table);
+        while let Some(i) = remaining.next(table) {
+            // SAFETY: i is the index of a full bucket which is exclusively pulled by this call
+            unsafe { core::ptr::drop_in_place(table.bucket(i).as_ptr()) };
+        }
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.pull(1).and_then(|(_, mut chunk)| chunk.next())
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.pull(1)
+            .and_then(|(idx, mut chunk)| chunk.next().map(|x| (idx, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.buckets.remaining_upper_bound(self.initial_len());
+        match upper {
+            0 => (0, Some(0)),
+            _ => (0, Some(upper)),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+/// Sequential iterator over the remaining elements of a [`ConIterHashTable`].
+pub struct HashTableIntoSeqIter<C>
+where
+    C: HashTable,
+{
+    con_iter: ConIterHashTable<C>,
+}
+
+impl<C> Iterator for HashTableIntoSeqIter<C>
+where
+    C: HashTable,
+    C::Entry: Send,
+    C::Item: Send,
+{
+    type Item = C::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.con_iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.con_iter.size_hint()
+    }
+}
+
+impl<C> FusedIterator for HashTableIntoSeqIter<C>
+where
+    C: HashTable,
+    C::Entry: Send,
+    C::Item: Send,
+{
+}
@@ -0,0 +1,129 @@
+use super::{
+    buckets::Buckets, chunk_puller_ref::ChunkPullerHashTableRef,
+    chunk_puller_ref::HashTableRefChunk, hash_table::HashTable,
+};
+use crate::concurrent_iter::ConcurrentIter;
+use hashbrown::raw::RawTable;
+
+/// Concurrent iterator of a reference to a hash table of the [`hashbrown`] crate, such as
+/// `&hashbrown::HashMap<K, V>` or `&hashbrown::HashSet<T>`.
+///
+/// It can be created by calling [`into_con_iter`] on a reference to the hash table; further, hash sets
+/// can create it by [`con_iter`]. It is available only when the `hashbrown` feature is enabled.
+///
+/// The raw bucket table is split into index ranges which are handed out by a single atomic counter,
+/// the way [`ConIterSlice`] splits a slice; empty buckets are skipped. Since the order of the elements
+/// of a hash table is arbitrary, the index of an element is the order in which it is pulled.
+///
+/// The `chunk_size` of a [`chunk_puller`] is a number of buckets rather than a number of elements:
+/// each pull reserves the next `chunk_size` buckets and yields the elements of the full ones among
+/// them. Therefore, a chunk has at most `chunk_size` elements and can be shorter even when more
+/// elements remain, depending on the load factor of the table. Chunks are never empty though.
+///
+/// Note that the standard library's `HashMap` does not expose its buckets; hence, this implementation
+/// is available only for the hash tables of the `hashbrown` crate.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`chunk_puller`]: crate::ConcurrentIter::chunk_puller
+/// [`con_iter`]: crate::ConcurrentCollection::con_iter
+/// [`ConIterSlice`]: crate::implementations::ConIterSlice
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use hashbrown::HashMap;
+///
+/// let map: HashMap<_, _> = (0..1000).map(|x| (x, x.to_string())).collect();
+///
+/// let con_iter = (&map).into_con_iter();
+/// let sum: usize = std::thread::scope(|s| {
+///     let handles: Vec<_> = (0..4)
+///         .map(|_| s.spawn(|| con_iter.item_puller().map(|(k, _)| *k).sum::<usize>()))
+///         .collect();
+///     handles.into_iter().map(|x| x.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(sum, (0..1000).sum());
+/// ```
+pub struct ConIterHashTableRef<'a, C>
+where
+    C: HashTable,
+{
+    table: &'a C,
+    buckets: Buckets,
+}
+
+impl<'a, C> ConIterHashTableRef<'a, C>
+where
+    C: HashTable,
+{
+    pub(super) fn new(table: &'a C) -> Self {
+        let buckets = Buckets::new(table.raw_table());
+        Self { table, buckets }
+    }
+
+    fn raw_table(&self) -> &'a RawTable<C::Entry> {
+        self.table.raw_table()
+    }
+
+    pub(super) fn pull(&self, chunk_size: usize) -> Option<(usize, HashTableRefChunk<'a, C>)> {
+        let table = self.raw_table();
+        self.buckets
+            .pull(table, chunk_size)
+            .map(|(begin_idx, full)| (begin_idx, HashTableRefChunk::new(table, full)))
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.raw_table().len()
+    }
+
+    pub(super) fn num_yielded(&self) -> usize {
+        self.buckets.num_yielded()
+    }
+}
+
+impl<'a, C> ConcurrentIter for ConIterHashTableRef<'a, C>
+where
+    C: HashTable + Sync,
+    C::ItemRef<'a>: Send,
+{
+    type Item = C::ItemRef<'a>;
+
+    type SequentialIter = HashTableRefChunk<'a, C>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerHashTableRef<'i, 'a, C>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let table = self.raw_table();
+        HashTableRefChunk::new(table, self.buckets.pull_remaining(table))
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.buckets.pull_remaining(self.raw_table());
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.pull(1).and_then(|(_, mut chunk)| chunk.next())
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.pull(1)
+            .and_then(|(idx, mut chunk)| chunk.next().map(|x| (idx, x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.buckets.remaining_upper_bound(self.initial_len());
+        match upper {
+            0 => (0, Some(0)),
+            _ => (0, Some(upper)),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
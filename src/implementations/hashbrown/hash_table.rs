@@ -0,0 +1,92 @@
+use hashbrown::{HashMap, HashSet, raw::RawTable};
+
+/// A hash table of the [`hashbrown`] crate which exposes its raw table of buckets.
+///
+/// It is implemented for `hashbrown::HashMap` and `hashbrown::HashSet`, which allows to create
+/// concurrent iterators splitting the raw bucket table into index ranges.
+pub trait HashTable {
+    /// Type of the entries stored in the buckets of the raw table.
+    type Entry;
+
+    /// Type of the elements yielded when the hash table is consumed.
+    type Item;
+
+    /// Type of the elements yielded when the hash table is iterated by reference.
+    type ItemRef<'a>
+    where
+        Self: 'a;
+
+    /// Returns a reference to the raw table of the hash table.
+    fn raw_table(&self) -> &RawTable<Self::Entry>;
+
+    /// Returns a mutable reference to the raw table of the hash table.
+    fn raw_table_mut(&mut self) -> &mut RawTable<Self::Entry>;
+
+    /// Converts the entry moved out of the raw table into the element.
+    fn item(entry: Self::Entry) -> Self::Item;
+
+    /// Converts a reference to an entry of the raw table into the referenced element.
+    fn item_ref<'a>(entry: &'a Self::Entry) -> Self::ItemRef<'a>
+    where
+        Self: 'a;
+}
+
+impl<K, V, S> HashTable for HashMap<K, V, S> {
+    type Entry = (K, V);
+
+    type Item = (K, V);
+
+    type ItemRef<'a>
+        = (&'a K, &'a V)
+    where
+        Self: 'a;
+
+    fn raw_table(&self) -> &RawTable<Self::Entry> {
+        HashMap::raw_table(self)
+    }
+
+    fn raw_table_mut(&mut self) -> &mut RawTable<Self::Entry> {
+        HashMap::raw_table_mut(self)
+    }
+
+    fn item(entry: Self::Entry) -> Self::Item {
+        entry
+    }
+
+    fn item_ref<'a>(entry: &'a Self::Entry) -> Self::ItemRef<'a>
+    where
+        Self: 'a,
+    {
+        (&entry.0, &entry.1)
+    }
+}
+
+impl<T, S> HashTable for HashSet<T, S> {
+    type Entry = (T, ());
+
+    type Item = T;
+
+    type ItemRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    fn raw_table(&self) -> &RawTable<Self::Entry> {
+        HashSet::raw_table(self)
+    }
+
+    fn raw_table_mut(&mut self) -> &mut RawTable<Self::Entry> {
+        HashSet::raw_table_mut(self)
+    }
+
+    fn item(entry: Self::Entry) -> Self::Item {
+        entry.0
+    }
+
+    fn item_ref<'a>(entry: &'a Self::Entry) -> Self::ItemRef<'a>
+    where
+        Self: 'a,
+    {
+        &entry.0
+    }
+}
@@ -0,0 +1,59 @@
+use super::{con_iter_owned::ConIterHashTable, con_iter_ref::ConIterHashTableRef};
+use crate::into_concurrent_iter::IntoConcurrentIter;
+use hashbrown::{HashMap, HashSet};
+
+impl<'a, K, V, S> IntoConcurrentIter for &'a HashMap<K, V, S>
+where
+    K: Sync,
+    V: Sync,
+    S: Sync,
+{
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = ConIterHashTableRef<'a, HashMap<K, V, S>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, T, S> IntoConcurrentIter for &'a HashSet<T, S>
+where
+    T: Sync,
+    S: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterHashTableRef<'a, HashSet<T, S>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<K, V, S> IntoConcurrentIter for HashMap<K, V, S>
+where
+    K: Send,
+    V: Send,
+{
+    type Item = (K, V);
+
+    type IntoIter = ConIterHashTable<HashMap<K, V, S>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<T, S> IntoConcurrentIter for HashSet<T, S>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterHashTable<HashSet<T, S>>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests;
+
+mod buckets;
+mod chunk_puller_owned;
+mod chunk_puller_ref;
+mod common_traits;
+mod con_iter_owned;
+mod con_iter_ref;
+mod hash_table;
+mod into_con_iter;
+
+pub use con_iter_owned::{ConIterHashTable, HashTableIntoSeqIter};
+pub use con_iter_ref::ConIterHashTableRef;
+pub use hash_table::HashTable;
@@ -0,0 +1,252 @@
+use crate::{
+    concurrent_collection::ConcurrentCollection, concurrent_iter::ConcurrentIter,
+    into_concurrent_iter::IntoConcurrentIter, pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use hashbrown::{HashMap, HashSet};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn new_map(n: usize) -> HashMap<usize, String> {
+    (0..n).map(|i| (i, (i + 10).to_string())).collect()
+}
+
+fn new_set(n: usize) -> HashSet<String> {
+    (0..n).map(|i| (i + 10).to_string()).collect()
+}
+
+fn expected(n: usize) -> Vec<String> {
+    let mut expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    expected
+}
+
+#[test]
+fn enumeration() {
+    let map = new_map(3);
+    let iter = (&map).into_con_iter();
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+
+    let mut collected = Vec::new();
+    let (idx, first) = iter.next_with_idx().expect("");
+    assert_eq!(idx, 0);
+    collected.push(first);
+    let (idx, second) = iter.next_with_idx().expect("");
+    assert_eq!(idx, 1);
+    collected.push(second);
+    collected.push(iter.next().expect(""));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    collected.sort();
+    assert_eq!(
+        collected,
+        [
+            (&0, &10.to_string()),
+            (&1, &11.to_string()),
+            (&2, &12.to_string())
+        ]
+    );
+}
+
+#[test]
+fn sparse_table() {
+    let mut map = new_map(1000);
+    map.retain(|k, _| k % 97 == 0);
+    let iter = (&map).into_con_iter();
+
+    let mut puller = iter.chunk_puller(64);
+    let mut keys = Vec::new();
+    let mut num_pulled = 0;
+    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+        assert_eq!(begin_idx, num_pulled);
+        assert!(chunk.len() > 0);
+        num_pulled += chunk.len();
+        keys.extend(chunk.map(|(k, _)| *k));
+    }
+    keys.sort();
+    assert_eq!(keys, (0..1000).filter(|x| x % 97 == 0).collect::<Vec<_>>());
+}
+
+#[test]
+fn empty() {
+    let map = HashMap::<usize, String>::new();
+    assert!((&map).into_con_iter().next().is_none());
+    assert!(map.into_con_iter().next().is_none());
+
+    let set = HashSet::<String>::with_capacity(100);
+    assert!(set.con_iter().chunk_puller(4).pull().is_none());
+    assert!(set.into_con_iter().next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn ref_zero_chunk_size() {
+    let map = new_map(10);
+    let _ = (&map).into_con_iter().chunk_puller(0);
+}
+
+#[test]
+#[should_panic]
+fn owned_zero_chunk_size() {
+    let _ = new_set(10).into_con_iter().chunk_puller(0);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn set_ref_item_puller(n: usize, nt: usize) {
+    let set = new_set(n);
+    let iter = set.con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.item_puller() {
+                    bag.push(x.clone());
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn map_ref_chunk_puller_with_idx(n: usize, nt: usize) {
+    let map = new_map(n);
+    let iter = (&map).into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    let indices = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(7);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    for (i, (k, v)) in chunk.enumerate() {
+                        assert_eq!(*v, (k + 10).to_string());
+                        indices.push(begin_idx + i);
+                        bag.push(v.clone());
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected(n), collected);
+
+    let mut indices = indices.into_inner().to_vec();
+    indices.sort();
+    assert_eq!(indices, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn map_owned_next(n: usize, nt: usize) {
+    let map = new_map(n);
+    let iter = map.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((_, v)) = iter.next() {
+                    bag.push(v);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn set_owned_flattened_chunk_puller(n: usize, nt: usize) {
+    let set = new_set(n);
+    let iter = set.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.chunk_puller(7).flattened() {
+                    bag.push(x);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn owned_skip_to_end(n: usize, nt: usize) {
+    let until = n / 2;
+    let set = new_set(n);
+    let iter = set.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((idx, x)) = iter.next_with_idx() {
+                    match idx < until {
+                        true => _ = bag.push(x),
+                        false => iter.skip_to_end(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(bag.len(), until);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn owned_partially_consumed() {
+    let set = new_set(N);
+    let iter = set.into_con_iter();
+
+    let mut puller = iter.chunk_puller(7);
+    let mut chunk = puller.pull().expect("");
+    let _ = chunk.next();
+    drop(chunk);
+    let _ = iter.next();
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn into_seq_iter(n: usize, until: usize) {
+    let until = until.min(n);
+
+    let map = new_map(n);
+    let iter = (&map).into_con_iter();
+    let mut collected: Vec<_> = (0..until)
+        .map(|_| iter.next().expect("").1.clone())
+        .collect();
+    collected.extend(iter.into_seq_iter().map(|(_, v)| v.clone()));
+    collected.sort();
+    assert_eq!(expected(n), collected);
+
+    let set = new_set(n);
+    let iter = set.into_con_iter();
+    let mut collected: Vec<_> = (0..until).map(|_| iter.next().expect("")).collect();
+    collected.extend(iter.into_seq_iter());
+    collected.sort();
+    assert_eq!(expected(n), collected);
+}
@@ -0,0 +1,50 @@
+use super::JaggedIndex;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Reserves the next contiguous sub-slice of a single fragment of a jagged array having `len` elements
+/// in total, progressing the shared `counter` of the concurrent iterator.
+///
+/// The reserved range starts at the current value of the counter and ends at the earliest of:
+/// * the end of the fragment containing the first reserved element,
+/// * `max_chunk_size` elements later.
+///
+/// Returns the flat index of the first reserved element, its jagged index and the number of reserved
+/// elements; or None if the iterator is consumed.
+///
+/// * `jagged_index` maps a flat index which is less than `len` to its jagged index.
+/// * `len_of` returns the length of the `f`-th fragment, None if `f` is out of bounds.
+pub(super) fn reserve_fragment(
+    counter: &AtomicUsize,
+    len: usize,
+    max_chunk_size: usize,
+    jagged_index: impl Fn(usize) -> Option<JaggedIndex>,
+    len_of: impl Fn(usize) -> Option<usize>,
+) -> Option<(usize, JaggedIndex, usize)> {
+    let mut begin_idx = counter.load(Ordering::Relaxed);
+    loop {
+        if begin_idx >= len {
+            return None;
+        }
+
+        let mut idx = jagged_index(begin_idx)?;
+        let mut remaining_in_fragment = len_of(idx.f)?.saturating_sub(idx.i);
+        while remaining_in_fragment == 0 {
+            idx = JaggedIndex::new(idx.f + 1, 0);
+            remaining_in_fragment = len_of(idx.f)?;
+        }
+
+        let chunk_len = remaining_in_fragment
+            .min(max_chunk_size)
+            .min(len - begin_idx);
+
+        match counter.compare_exchange_weak(
+            begin_idx,
+            begin_idx + chunk_len,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return Some((begin_idx, idx, chunk_len)),
+            Err(current) => begin_idx = current,
+        }
+    }
+}
@@ -34,4 +34,16 @@ pub trait JaggedIndexer: Clone + PseudoDefault + Send + Sync {
         arrays: &[impl AsRawSlice<T>],
         flat_index: usize,
     ) -> JaggedIndex;
+
+    // provided
+
+    /// Returns true if this indexer computes jagged indices which are in bounds of the arrays having
+    /// the given `lengths`.
+    ///
+    /// Raw jagged arrays assert this condition when they are created. Indexers computing the jagged
+    /// indices from their own parameters rather than from the arrays must override this method.
+    fn is_valid_for(&self, lengths: impl Iterator<Item = usize>) -> bool {
+        let _ = lengths;
+        true
+    }
 }
@@ -0,0 +1,96 @@
+use super::fills_capacities_in_order;
+use crate::implementations::jagged_arrays::{
+    Slices, as_raw_slice::AsRawSlice, index::JaggedIndex, indexer::JaggedIndexer,
+};
+use orx_pseudo_default::PseudoDefault;
+
+/// Indexer of jagged arrays where the capacity of each array doubles the capacity of the
+/// previous one, and all arrays except possibly the last one are full; as in the `Doubling`
+/// growth strategy of [`SplitVec`].
+///
+/// In other words, the `f`-th array has `first_fragment_len * 2^f` elements. The jagged index of
+/// an element is computed in constant time using the position of its leading bit. Raw jagged arrays
+/// created with this indexer panic unless every array before the last non-empty one is full and no
+/// array has more elements than its capacity.
+///
+/// [`SplitVec`]: https://crates.io/crates/orx-split-vec
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::jagged_arrays::{DoublingIndexer, RawJaggedRef};
+///
+/// let fragments = vec![vec![0, 1], vec![2, 3, 4, 5], vec![6, 7, 8]];
+/// let jagged = RawJaggedRef::new(fragments.as_slice(), DoublingIndexer::new(2), None);
+///
+/// let values: Vec<_> = jagged.into_con_iter().into_seq_iter().copied().collect();
+/// assert_eq!(values, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DoublingIndexer {
+    first_fragment_len: usize,
+}
+
+impl DoublingIndexer {
+    /// Creates an indexer for arrays where the first one has `first_fragment_len` elements and
+    /// each following array has twice the elements of the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first_fragment_len` is zero.
+    pub fn new(first_fragment_len: usize) -> Self {
+        assert!(
+            first_fragment_len > 0,
+            "length of the first fragment must be positive"
+        );
+        Self { first_fragment_len }
+    }
+
+    /// Length of the first array.
+    pub fn first_fragment_len(&self) -> usize {
+        self.first_fragment_len
+    }
+
+    fn jagged_index(&self, flat_index: usize) -> JaggedIndex {
+        // the f-th fragment begins at first_fragment_len * (2^f - 1); hence,
+        // f = floor(log2(flat_index / first_fragment_len + 1))
+        let q = flat_index / self.first_fragment_len + 1;
+        let f = (usize::BITS - 1 - q.leading_zeros()) as usize;
+        let begin = self.first_fragment_len * ((1 << f) - 1);
+        JaggedIndex::new(f, flat_index - begin)
+    }
+}
+
+impl PseudoDefault for DoublingIndexer {
+    fn pseudo_default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl JaggedIndexer for DoublingIndexer {
+    unsafe fn jagged_index_unchecked<'a, T: 'a>(
+        &self,
+        _: &impl Slices<'a, T>,
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    unsafe fn jagged_index_unchecked_from_slice<'a, T: 'a>(
+        &self,
+        _: &[impl AsRawSlice<T>],
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    fn is_valid_for(&self, lengths: impl Iterator<Item = usize>) -> bool {
+        fills_capacities_in_order(lengths, |f| {
+            1usize
+                .checked_shl(f as u32)
+                .and_then(|x| x.checked_mul(self.first_fragment_len))
+                .unwrap_or(usize::MAX)
+        })
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests;
+
+mod doubling;
+mod prefix_sum;
+mod uniform;
+
+pub use doubling::DoublingIndexer;
+pub use prefix_sum::PrefixSumIndexer;
+pub use uniform::UniformIndexer;
+
+/// Returns true if each array has exactly `capacity(f)` elements up to the first array which is
+/// not full, which might be shorter, and all arrays following it are empty.
+fn fills_capacities_in_order(
+    lengths: impl Iterator<Item = usize>,
+    capacity: impl Fn(usize) -> usize,
+) -> bool {
+    let mut is_full = true;
+    lengths.enumerate().all(|(f, len)| match is_full {
+        true => {
+            let cap = capacity(f);
+            is_full = len == cap;
+            len <= cap
+        }
+        false => len == 0,
+    })
+}
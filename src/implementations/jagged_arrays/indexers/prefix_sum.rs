@@ -0,0 +1,102 @@
+use crate::implementations::jagged_arrays::{
+    Slices, as_raw_slice::AsRawSlice, index::JaggedIndex, indexer::JaggedIndexer,
+};
+use alloc::{sync::Arc, vec::Vec};
+use orx_pseudo_default::PseudoDefault;
+
+/// Indexer of jagged arrays with arbitrary lengths.
+///
+/// It precomputes the cumulative lengths of the arrays, and computes the jagged index of an
+/// element by a binary search in O(log F) time where F is the number of arrays. Empty arrays are
+/// allowed and are skipped.
+///
+/// Cumulative lengths are shared among the clones of the indexer.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::jagged_arrays::{PrefixSumIndexer, RawJaggedRef};
+///
+/// let arrays = vec![vec![0, 1], vec![], vec![2, 3, 4], vec![5]];
+/// let indexer = PrefixSumIndexer::new(arrays.iter().map(|x| x.len()));
+/// assert_eq!(indexer.total_len(), 6);
+///
+/// let jagged = RawJaggedRef::new(arrays.as_slice(), indexer, None);
+///
+/// let values: Vec<_> = jagged.into_con_iter().into_seq_iter().copied().collect();
+/// assert_eq!(values, [0, 1, 2, 3, 4, 5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrefixSumIndexer {
+    /// `ends[f]` is the total length of the arrays `0..=f`.
+    ends: Arc<[usize]>,
+}
+
+impl PrefixSumIndexer {
+    /// Creates an indexer for the arrays with the given `lengths`.
+    pub fn new(lengths: impl IntoIterator<Item = usize>) -> Self {
+        let ends: Vec<_> = lengths
+            .into_iter()
+            .scan(0, |end, len| {
+                *end += len;
+                Some(*end)
+            })
+            .collect();
+        Self { ends: ends.into() }
+    }
+
+    /// Creates an indexer for the given collection of `arrays`.
+    pub fn from_slices<'a, T: 'a>(arrays: &impl Slices<'a, T>) -> Self {
+        Self::new(arrays.lengths())
+    }
+
+    /// Total length of the arrays.
+    pub fn total_len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    fn jagged_index(&self, flat_index: usize) -> JaggedIndex {
+        // the element belongs to the first array ending after it
+        let f = self.ends.partition_point(|end| *end <= flat_index);
+        let begin = match f {
+            0 => 0,
+            f => self.ends[f - 1],
+        };
+        JaggedIndex::new(f, flat_index - begin)
+    }
+}
+
+impl PseudoDefault for PrefixSumIndexer {
+    fn pseudo_default() -> Self {
+        Self::new([])
+    }
+}
+
+impl JaggedIndexer for PrefixSumIndexer {
+    unsafe fn jagged_index_unchecked<'a, T: 'a>(
+        &self,
+        _: &impl Slices<'a, T>,
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    unsafe fn jagged_index_unchecked_from_slice<'a, T: 'a>(
+        &self,
+        _: &[impl AsRawSlice<T>],
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    fn is_valid_for(&self, mut lengths: impl Iterator<Item = usize>) -> bool {
+        let mut ends = self.ends.iter();
+        let mut end = 0;
+        let matches = lengths.all(|len| {
+            end += len;
+            ends.next() == Some(&end)
+        });
+        matches && ends.next().is_none()
+    }
+}
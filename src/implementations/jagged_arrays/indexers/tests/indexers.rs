@@ -0,0 +1,240 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::jagged_arrays::{
+        DoublingIndexer, JaggedIndex, JaggedIndexer, PrefixSumIndexer, RawJagged, RawJaggedRef,
+        RawVec, UniformIndexer,
+    },
+    into_concurrent_iter::IntoConcurrentIter,
+    pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+fn arrays_of(lengths: &[usize]) -> Vec<Vec<usize>> {
+    let mut begin = 0;
+    lengths
+        .iter()
+        .map(|len| {
+            let array = (begin..(begin + len)).collect();
+            begin += len;
+            array
+        })
+        .collect()
+}
+
+fn assert_indexer<X: JaggedIndexer>(indexer: &X, lengths: &[usize]) {
+    let arrays = arrays_of(lengths);
+    for (f, array) in arrays.iter().enumerate() {
+        for (i, flat_index) in array.iter().enumerate() {
+            let idx = unsafe { indexer.jagged_index_unchecked(&arrays.as_slice(), *flat_index) };
+            assert_eq!(idx, JaggedIndex::new(f, i));
+            let idx = unsafe { indexer.jagged_index_unchecked_from_slice(&arrays, *flat_index) };
+            assert_eq!(idx, JaggedIndex::new(f, i));
+        }
+    }
+}
+
+#[test_matrix([1, 2, 7], [0, 1, 5, 6])]
+fn uniform(fragment_len: usize, num_fragments: usize) {
+    let mut lengths = vec![fragment_len; num_fragments];
+    assert_indexer(&UniformIndexer::new(fragment_len), &lengths);
+    if let Some(last) = lengths.last_mut() {
+        *last = fragment_len / 2;
+    }
+    assert_indexer(&UniformIndexer::new(fragment_len), &lengths);
+}
+
+#[test_matrix([1, 2, 4, 5], [0, 1, 5, 10])]
+fn doubling(first_fragment_len: usize, num_fragments: usize) {
+    let mut lengths: Vec<_> = (0..num_fragments)
+        .map(|f| first_fragment_len << f)
+        .collect();
+    assert_indexer(&DoublingIndexer::new(first_fragment_len), &lengths);
+    if let Some(last) = lengths.last_mut() {
+        *last = 1;
+    }
+    assert_indexer(&DoublingIndexer::new(first_fragment_len), &lengths);
+}
+
+#[test]
+fn prefix_sum() {
+    let cases: [&[usize]; 6] = [
+        &[],
+        &[0, 0],
+        &[3],
+        &[2, 0, 3, 1],
+        &[0, 1, 0, 0, 5, 0],
+        &[7, 1, 1, 12, 3, 0, 4],
+    ];
+    for lengths in cases {
+        let indexer = PrefixSumIndexer::new(lengths.iter().copied());
+        assert_eq!(indexer.total_len(), lengths.iter().sum::<usize>());
+        assert_indexer(&indexer, lengths);
+
+        let arrays = arrays_of(lengths);
+        let indexer = PrefixSumIndexer::from_slices(&arrays.as_slice());
+        assert_indexer(&indexer, lengths);
+    }
+}
+
+#[test]
+#[should_panic]
+fn uniform_zero_len() {
+    let _ = UniformIndexer::new(0);
+}
+
+#[test]
+#[should_panic]
+fn doubling_zero_len() {
+    let _ = DoublingIndexer::new(0);
+}
+
+#[test]
+fn is_valid_for() {
+    let uniform = UniformIndexer::new(3);
+    let valid: [&[usize]; 4] = [&[], &[3, 3], &[3, 1], &[3, 2, 0, 0]];
+    let invalid: [&[usize]; 4] = [&[4], &[2, 3], &[3, 1, 1], &[0, 3]];
+    for lengths in valid {
+        assert!(uniform.is_valid_for(lengths.iter().copied()));
+    }
+    for lengths in invalid {
+        assert!(!uniform.is_valid_for(lengths.iter().copied()));
+    }
+
+    let doubling = DoublingIndexer::new(2);
+    let valid: [&[usize]; 4] = [&[], &[2, 4, 8], &[2, 4, 1], &[2, 3, 0]];
+    let invalid: [&[usize]; 4] = [&[3], &[2, 2, 8], &[2, 4, 9], &[1, 0, 1]];
+    for lengths in valid {
+        assert!(doubling.is_valid_for(lengths.iter().copied()));
+    }
+    for lengths in invalid {
+        assert!(!doubling.is_valid_for(lengths.iter().copied()));
+    }
+
+    let prefix_sum = PrefixSumIndexer::new([2, 0, 3]);
+    assert!(prefix_sum.is_valid_for([2, 0, 3].into_iter()));
+    let invalid: [&[usize]; 4] = [&[2, 3], &[2, 0, 3, 0], &[2, 0, 4], &[2, 1, 2]];
+    for lengths in invalid {
+        assert!(!prefix_sum.is_valid_for(lengths.iter().copied()));
+    }
+}
+
+#[test]
+#[should_panic]
+fn uniform_longer_than_fragment_len() {
+    let arrays = [vec![0, 1], vec![2, 3, 4]];
+    let _ = RawJaggedRef::new(&arrays[..], UniformIndexer::new(3), None);
+}
+
+#[test]
+#[should_panic]
+fn doubling_not_full() {
+    let arrays = [vec![0], vec![1, 2, 3, 4]];
+    let _ = RawJagged::new(
+        arrays.into_iter().map(RawVec::from).collect(),
+        DoublingIndexer::new(2),
+        None,
+    );
+}
+
+fn con_iter_collect<X: JaggedIndexer>(arrays: &[Vec<usize>], indexer: X, nt: usize, chunk: usize) {
+    let jagged = RawJaggedRef::new(arrays, indexer, None);
+    let total_len = arrays.iter().map(|x| x.len()).sum();
+    let con_iter = jagged.into_con_iter();
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = con_iter.chunk_puller(chunk);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    for (i, x) in chunk.enumerate() {
+                        bag.push((begin_idx + i, *x));
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let expected: Vec<_> = (0..total_len).map(|i| (i, i)).collect();
+    assert_eq!(collected, expected);
+}
+
+#[test_matrix([1, 4], [1, 3, 64])]
+fn con_iter_with_indexers(nt: usize, chunk: usize) {
+    let lengths = vec![10; 30];
+    con_iter_collect(&arrays_of(&lengths), UniformIndexer::new(10), nt, chunk);
+
+    let lengths: Vec<_> = (0..8).map(|f| 4 << f).collect();
+    con_iter_collect(&arrays_of(&lengths), DoublingIndexer::new(4), nt, chunk);
+
+    let lengths: Vec<_> = (0..50).map(|f| (f * 7) % 11).collect();
+    let indexer = PrefixSumIndexer::new(lengths.iter().copied());
+    con_iter_collect(&arrays_of(&lengths), indexer, nt, chunk);
+}
+
+#[test_matrix([1, 4], [1, 3, 64], [0, 10, 100])]
+fn owned_with_empty_arrays(nt: usize, chunk: usize, num_taken_by_main: usize) {
+    let lengths: Vec<_> = (0..50).map(|f| (f * 7) % 11).collect();
+    let arrays: Vec<Vec<String>> = arrays_of(&lengths)
+        .into_iter()
+        .map(|x| x.into_iter().map(|x| x.to_string()).collect())
+        .collect();
+    let total_len: usize = lengths.iter().sum();
+    let indexer = PrefixSumIndexer::new(lengths.iter().copied());
+    let jagged = RawJagged::new(
+        arrays.into_iter().map(RawVec::from).collect(),
+        indexer,
+        None,
+    );
+
+    let con_iter = jagged.into_con_iter();
+    for i in 0..num_taken_by_main {
+        assert_eq!(con_iter.next(), Some(i.to_string()));
+    }
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = con_iter.chunk_puller(chunk);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    for (i, x) in chunk.enumerate() {
+                        bag.push((begin_idx + i, x));
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let expected: Vec<_> = (num_taken_by_main..total_len)
+        .map(|i| (i, i.to_string()))
+        .collect();
+    assert_eq!(collected, expected);
+}
+
+#[test]
+fn owned_with_empty_arrays_into_seq_iter() {
+    let lengths = [0, 3, 0, 0, 2, 0];
+    let arrays: Vec<Vec<String>> = arrays_of(&lengths)
+        .into_iter()
+        .map(|x| x.into_iter().map(|x| x.to_string()).collect())
+        .collect();
+    let indexer = PrefixSumIndexer::new(lengths);
+    let jagged = RawJagged::new(
+        arrays.into_iter().map(RawVec::from).collect(),
+        indexer,
+        None,
+    );
+
+    let con_iter = jagged.into_con_iter();
+    assert_eq!(con_iter.next(), Some(0.to_string()));
+    let rest: Vec<_> = con_iter.into_seq_iter().collect();
+    assert_eq!(rest, ["1", "2", "3", "4"]);
+}
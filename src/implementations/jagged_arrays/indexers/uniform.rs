@@ -0,0 +1,80 @@
+use super::fills_capacities_in_order;
+use crate::implementations::jagged_arrays::{
+    Slices, as_raw_slice::AsRawSlice, index::JaggedIndex, indexer::JaggedIndexer,
+};
+use orx_pseudo_default::PseudoDefault;
+
+/// Indexer of jagged arrays where every array, except possibly the last one, has the same
+/// length, such as the rows of a matrix.
+///
+/// The jagged index of an element is computed in constant time by a division. Raw jagged arrays
+/// created with this indexer panic unless every array before the last non-empty one has exactly
+/// `fragment_len` elements and no array has more.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::jagged_arrays::{RawJaggedRef, UniformIndexer};
+///
+/// let rows = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7]];
+/// let jagged = RawJaggedRef::new(rows.as_slice(), UniformIndexer::new(3), None);
+///
+/// let values: Vec<_> = jagged.into_con_iter().into_seq_iter().copied().collect();
+/// assert_eq!(values, [0, 1, 2, 3, 4, 5, 6, 7]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformIndexer {
+    fragment_len: usize,
+}
+
+impl UniformIndexer {
+    /// Creates an indexer for arrays having `fragment_len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragment_len` is zero.
+    pub fn new(fragment_len: usize) -> Self {
+        assert!(fragment_len > 0, "length of the fragments must be positive");
+        Self { fragment_len }
+    }
+
+    /// Length of each array.
+    pub fn fragment_len(&self) -> usize {
+        self.fragment_len
+    }
+
+    fn jagged_index(&self, flat_index: usize) -> JaggedIndex {
+        let f = flat_index / self.fragment_len;
+        let i = flat_index % self.fragment_len;
+        JaggedIndex::new(f, i)
+    }
+}
+
+impl PseudoDefault for UniformIndexer {
+    fn pseudo_default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl JaggedIndexer for UniformIndexer {
+    unsafe fn jagged_index_unchecked<'a, T: 'a>(
+        &self,
+        _: &impl Slices<'a, T>,
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    unsafe fn jagged_index_unchecked_from_slice<'a, T: 'a>(
+        &self,
+        _: &[impl AsRawSlice<T>],
+        flat_index: usize,
+    ) -> JaggedIndex {
+        self.jagged_index(flat_index)
+    }
+
+    fn is_valid_for(&self, lengths: impl Iterator<Item = usize>) -> bool {
+        fills_capacities_in_order(lengths, |_| self.fragment_len)
+    }
+}
@@ -1,17 +1,26 @@
 mod as_raw_slice;
 mod as_slice;
+mod fragment;
 mod index;
 mod indexer;
+mod indexers;
+mod mutable;
 mod owned;
 mod raw_slice;
 mod reference;
 mod slices;
+mod with_jagged_idx;
 
 pub use as_raw_slice::AsRawSlice;
 pub use as_slice::AsSlice;
 pub use index::JaggedIndex;
 pub use indexer::JaggedIndexer;
-pub use owned::{ConIterJaggedOwned, RawJagged, RawVec};
+pub use indexers::{DoublingIndexer, PrefixSumIndexer, UniformIndexer};
+pub use mutable::{ConIterJaggedMut, FragmentPullerJaggedMut, RawJaggedMut};
+pub use owned::{
+    ConIterJaggedDrain, ConIterJaggedOwned, FragmentPullerJaggedOwned, JaggedDrainSeqIter,
+    JaggedFragmentOwned, RawJagged, RawVec,
+};
 pub use raw_slice::RawSlice;
-pub use reference::{ConIterJaggedRef, RawJaggedRef};
+pub use reference::{ConIterJaggedRef, FragmentPullerJaggedRef, RawJaggedRef};
 pub use slices::Slices;
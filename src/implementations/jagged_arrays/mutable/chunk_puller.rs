@@ -0,0 +1,66 @@
+use super::{con_iter::ConIterJaggedMut, slice_iter::RawJaggedSliceIterMut};
+use crate::{
+    ChunkPuller,
+    implementations::jagged_arrays::{JaggedIndex, JaggedIndexer},
+};
+
+pub struct ChunkPullerJaggedMut<'i, 'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    con_iter: &'i ConIterJaggedMut<'a, T, X>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T, X> ChunkPullerJaggedMut<'i, 'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    pub(super) fn new(con_iter: &'i ConIterJaggedMut<'a, T, X>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+
+    /// Pulls the next chunk of at most `chunk_size` elements; and returns an iterator yielding each element of
+    /// the chunk together with its [`JaggedIndex`]; i.e., the index `f` of the array containing the element
+    /// and the position `i` of the element within this array.
+    ///
+    /// Returns None if the concurrent iterator is consumed.
+    pub fn pull_with_jagged_idx(
+        &mut self,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, &'a mut T)> + 'i> {
+        self.con_iter
+            .progress_and_get_iter_with_jagged_idx(self.chunk_size)
+    }
+}
+
+impl<'i, 'a, T, X> ChunkPuller for ChunkPullerJaggedMut<'i, 'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    type ChunkItem = &'a mut T;
+
+    type Chunk<'c>
+        = RawJaggedSliceIterMut<'i, 'a, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_iter(self.chunk_size)
+            .map(|(_, iter)| iter)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_iter(self.chunk_size)
+    }
+}
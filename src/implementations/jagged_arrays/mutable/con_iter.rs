@@ -0,0 +1,230 @@
+use super::{
+    chunk_puller::ChunkPullerJaggedMut, fragment_puller::FragmentPullerJaggedMut,
+    raw_jagged_mut::RawJaggedMut, slice_iter::RawJaggedSliceIterMut,
+};
+use crate::{
+    ConcurrentIter, ExactSizeConcurrentIter,
+    implementations::jagged_arrays::{
+        JaggedIndex, JaggedIndexer, fragment::reserve_fragment, with_jagged_idx::WithJaggedIdx,
+    },
+};
+use alloc::vec::IntoIter;
+use core::{
+    iter::Flatten,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Flattened concurrent iterator of a raw jagged array yielding mutable references to elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::jagged_arrays::{RawJaggedMut, UniformIndexer};
+///
+/// let mut a = [1, 2, 3];
+/// let mut b = [4, 5, 6];
+/// let arrays = [a.as_mut_slice(), b.as_mut_slice()];
+///
+/// let jagged = RawJaggedMut::new(arrays, UniformIndexer::new(3), None);
+/// let con_iter = jagged.into_con_iter();
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..2 {
+///         s.spawn(|| {
+///             for (idx, x) in con_iter.chunk_puller(2).flattened_with_idx() {
+///                 *x += 10 * idx;
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(a, [1, 12, 23]);
+/// assert_eq!(b, [34, 45, 56]);
+/// ```
+pub struct ConIterJaggedMut<'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    jagged: RawJaggedMut<'a, T, X>,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send, X: JaggedIndexer> Sync for ConIterJaggedMut<'_, T, X> {}
+
+unsafe impl<T: Send, X: JaggedIndexer> Send for ConIterJaggedMut<'_, T, X> {}
+
+impl<'a, T, X> ConIterJaggedMut<'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    pub(crate) fn new(jagged: RawJaggedMut<'a, T, X>, begin: usize) -> Self {
+        Self {
+            jagged,
+            counter: begin.into(),
+        }
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.jagged.len() {
+            true => Some(begin_idx),
+            false => None,
+        }
+    }
+
+    pub(super) fn progress_and_get_iter(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, RawJaggedSliceIterMut<'_, 'a, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size)
+                    .min(self.jagged.len())
+                    .max(begin_idx);
+                let slice = self.jagged.slice(begin_idx, end_idx);
+                // SAFETY: `counter` ensures that each position is handed out only once
+                let iter = unsafe { RawJaggedSliceIterMut::new(slice) };
+                (begin_idx, iter)
+            })
+    }
+
+    pub(super) fn progress_and_get_iter_with_jagged_idx(
+        &self,
+        chunk_size: usize,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, &'a mut T)> + '_> {
+        self.progress_and_get_iter(chunk_size)
+            .and_then(|(begin_idx, iter)| {
+                self.jagged.jagged_index(begin_idx).map(|begin| {
+                    WithJaggedIdx::new(iter, begin, |f| self.jagged.len_of(f).unwrap_or(0))
+                })
+            })
+    }
+
+    /// Returns the next element of the iterator together with its [`JaggedIndex`]; i.e., the index `f` of the
+    /// array containing the element and the position `i` of the element within this array.
+    ///
+    /// Returns None if the iterator is consumed.
+    pub fn next_with_jagged_idx(&self) -> Option<(JaggedIndex, &'a mut T)> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that each position is handed out only once
+            unsafe { self.jagged.get_mut_with_jagged_index(idx) }
+        })
+    }
+
+    pub(super) fn progress_and_get_fragment(
+        &self,
+        max_chunk_size: usize,
+    ) -> Option<(usize, JaggedIndex, &'a mut [T])> {
+        reserve_fragment(
+            &self.counter,
+            self.jagged.len(),
+            max_chunk_size,
+            |i| self.jagged.jagged_index(i),
+            |f| self.jagged.len_of(f),
+        )
+        .map(|(begin_idx, idx, len)| {
+            // SAFETY: reserve_fragment guarantees that the range is within bounds of the idx.f-th array,
+            // and `counter` ensures that each position is handed out only once
+            let slice = unsafe { self.jagged.slice_mut(idx.f, idx.i, len) };
+            (begin_idx, idx, slice)
+        })
+    }
+
+    /// Creates a [`FragmentPullerJaggedMut`] which pulls contiguous mutable slices that never span two
+    /// fragments of the jagged array, each with at most `max_chunk_size` elements.
+    ///
+    /// Pass `usize::MAX` as `max_chunk_size` to pull whole fragments, or remaining parts of fragments
+    /// which are partially consumed by other pulls.
+    ///
+    /// The fragment puller shares the progress of this iterator; hence, it can be used together with
+    /// `next` calls and chunk pullers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let mut vectors = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7]];
+    /// let con_iter = (&mut vectors).into_flat_con_iter();
+    ///
+    /// let mut puller = con_iter.fragment_puller(usize::MAX);
+    /// while let Some((idx, slice)) = puller.pull() {
+    ///     slice.fill(idx.f);
+    /// }
+    ///
+    /// assert_eq!(vectors, [vec![0, 0, 0], vec![], vec![2, 2, 2, 2, 2]]);
+    /// ```
+    pub fn fragment_puller(&self, max_chunk_size: usize) -> FragmentPullerJaggedMut<'_, 'a, T, X> {
+        assert!(max_chunk_size > 0, "max_chunk_size must be positive");
+        FragmentPullerJaggedMut::new(self, max_chunk_size)
+    }
+}
+
+impl<'a, T, X> ConcurrentIter for ConIterJaggedMut<'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    type Item = &'a mut T;
+
+    type SequentialIter = Flatten<IntoIter<&'a mut [T]>>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerJaggedMut<'i, 'a, T, X>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.jagged
+            .into_slices_from(num_taken)
+            .into_iter()
+            .flatten()
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.jagged.len(), Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that each position is handed out only once
+            unsafe { self.jagged.get_mut(idx) }
+        })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that each position is handed out only once
+            unsafe { self.jagged.get_mut(idx).map(|value| (idx, value)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.jagged.len().saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T, X> ExactSizeConcurrentIter for ConIterJaggedMut<'_, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.jagged.len().saturating_sub(num_taken)
+    }
+}
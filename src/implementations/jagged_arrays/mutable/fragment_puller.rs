@@ -0,0 +1,51 @@
+use super::con_iter::ConIterJaggedMut;
+use crate::implementations::jagged_arrays::{JaggedIndex, JaggedIndexer};
+
+/// A puller which pulls contiguous mutable slices of a jagged array, each of which lies within a single
+/// fragment of the jagged array.
+///
+/// Created by [`ConIterJaggedMut::fragment_puller`].
+///
+/// Each pull yields a whole fragment, or the remaining part of a fragment, with at most
+/// `max_chunk_size` elements, together with the [`JaggedIndex`] of its first element.
+/// Since the slices never span two fragments, they can be processed with slice-optimized code.
+pub struct FragmentPullerJaggedMut<'i, 'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    con_iter: &'i ConIterJaggedMut<'a, T, X>,
+    max_chunk_size: usize,
+}
+
+impl<'i, 'a, T, X> FragmentPullerJaggedMut<'i, 'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    pub(super) fn new(con_iter: &'i ConIterJaggedMut<'a, T, X>, max_chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            max_chunk_size,
+        }
+    }
+
+    /// Maximum number of elements of a pulled slice.
+    pub fn max_chunk_size(&self) -> usize {
+        self.max_chunk_size
+    }
+
+    /// Pulls the next contiguous slice of a fragment together with the [`JaggedIndex`] of its first element;
+    /// returns None if the concurrent iterator is consumed.
+    pub fn pull(&mut self) -> Option<(JaggedIndex, &'a mut [T])> {
+        self.con_iter
+            .progress_and_get_fragment(self.max_chunk_size)
+            .map(|(_, idx, slice)| (idx, slice))
+    }
+
+    /// Pulls the next contiguous slice of a fragment together with the flat index and [`JaggedIndex`] of its
+    /// first element; returns None if the concurrent iterator is consumed.
+    pub fn pull_with_idx(&mut self) -> Option<(usize, JaggedIndex, &'a mut [T])> {
+        self.con_iter.progress_and_get_fragment(self.max_chunk_size)
+    }
+}
@@ -0,0 +1,16 @@
+use super::{con_iter::ConIterJaggedMut, raw_jagged_mut::RawJaggedMut};
+use crate::{IntoConcurrentIter, implementations::jagged_arrays::JaggedIndexer};
+
+impl<'a, T, X> IntoConcurrentIter for RawJaggedMut<'a, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterJaggedMut<'a, T, X>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        ConIterJaggedMut::new(self, 0)
+    }
+}
@@ -0,0 +1,49 @@
+use super::{con_iter::ConIterJaggedMut, raw_jagged_mut::RawJaggedMut};
+use crate::{IntoFlatConcurrentIter, implementations::jagged_arrays::PrefixSumIndexer};
+use alloc::vec::Vec;
+
+impl<'a, T> IntoFlatConcurrentIter for &'a mut [Vec<T>]
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterJaggedMut<'a, T, PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        let indexer = PrefixSumIndexer::new(self.iter().map(|x| x.len()));
+        let total_len = indexer.total_len();
+        let arrays = self.iter_mut().map(|x| x.as_mut_slice());
+        ConIterJaggedMut::new(RawJaggedMut::new(arrays, indexer, Some(total_len)), 0)
+    }
+}
+
+impl<'a, T> IntoFlatConcurrentIter for &'a mut Vec<Vec<T>>
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterJaggedMut<'a, T, PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().into_flat_con_iter()
+    }
+}
+
+impl<'a, 'b, T> IntoFlatConcurrentIter for &'a mut [&'b mut [T]]
+where
+    'b: 'a,
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterJaggedMut<'a, T, PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        let indexer = PrefixSumIndexer::new(self.iter().map(|x| x.len()));
+        let total_len = indexer.total_len();
+        let arrays = self.iter_mut().map(|x| &mut **x);
+        ConIterJaggedMut::new(RawJaggedMut::new(arrays, indexer, Some(total_len)), 0)
+    }
+}
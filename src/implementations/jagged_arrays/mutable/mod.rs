@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod con_iter;
+mod fragment_puller;
+mod into_con_iter;
+mod into_flat_con_iter;
+mod raw_jagged_mut;
+mod slice_iter;
+
+pub use con_iter::ConIterJaggedMut;
+pub use fragment_puller::FragmentPullerJaggedMut;
+pub use raw_jagged_mut::RawJaggedMut;
@@ -0,0 +1,184 @@
+use crate::implementations::jagged_arrays::{
+    as_raw_slice::AsRawSlice, index::JaggedIndex, indexer::JaggedIndexer,
+    owned::slice::RawJaggedSlice, raw_slice::RawSlice,
+};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, marker::PhantomData};
+
+/// Raw representation of a jagged array of mutable references.
+///
+/// Further, jagged has an indexer which maps a flat-element-index to a
+/// two-dimensional index where the first is the index of the array and
+/// the second is the position of the element within this array.
+///
+/// The raw jagged array exclusively borrows the arrays for lifetime `'a`.
+pub struct RawJaggedMut<'a, T, X>
+where
+    X: JaggedIndexer,
+{
+    arrays: Vec<RawSlice<T>>,
+    len: usize,
+    indexer: X,
+    phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, X> RawJaggedMut<'a, T, X>
+where
+    X: JaggedIndexer,
+{
+    /// Creates a new raw jagged array of the mutable `arrays`.
+    ///
+    /// If the total number of elements in all `arrays` is known, it can be passed in as `total_len`,
+    /// which will be assumed to be correct.
+    /// If `None` is passed as the total length, it will be computed as sum of all arrays.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `indexer` is not valid for the lengths of the `arrays`; see
+    /// [`JaggedIndexer::is_valid_for`].
+    pub fn new(
+        arrays: impl IntoIterator<Item = &'a mut [T]>,
+        indexer: X,
+        total_len: Option<usize>,
+    ) -> Self {
+        let arrays: Vec<_> = arrays
+            .into_iter()
+            .map(|x| RawSlice::new(x.as_mut_ptr() as *const T, x.len()))
+            .collect();
+        assert!(
+            indexer.is_valid_for(arrays.iter().map(|x| x.length())),
+            "indexer is not valid for the lengths of the arrays"
+        );
+        let len = total_len.unwrap_or_else(|| arrays.iter().map(|x| x.length()).sum());
+        Self {
+            arrays,
+            len,
+            indexer,
+            phantom: PhantomData,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn len_of(&self, f: usize) -> Option<usize> {
+        self.arrays.get(f).map(|x| x.length())
+    }
+
+    /// Returns the [`JaggedIndex`] of the element at the given `flat_index` position of the flattened
+    /// jagged array.
+    ///
+    /// It returns `None` when `flat_index > self.len()`; and the exclusive end of the jagged index
+    /// range of the jagged array when `flat_index == self.len()`.
+    pub(super) fn jagged_index(&self, flat_index: usize) -> Option<JaggedIndex> {
+        match flat_index.cmp(&self.len) {
+            Ordering::Less => Some(unsafe {
+                // SAFETY: flat_index is within bounds
+                self.indexer
+                    .jagged_index_unchecked_from_slice(&self.arrays, flat_index)
+            }),
+            Ordering::Equal => match self.arrays.is_empty() {
+                true => None,
+                false => {
+                    let f = self.arrays.len() - 1;
+                    let i = self.arrays[f].length();
+                    Some(JaggedIndex::new(f, i))
+                }
+            },
+            Ordering::Greater => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element at the given `flat_index`; None if out of bounds.
+    ///
+    /// # SAFETY
+    ///
+    /// The caller must ensure that the element at `flat_index` is not referenced by anyone else.
+    pub(super) unsafe fn get_mut(&self, flat_index: usize) -> Option<&'a mut T> {
+        unsafe { self.get_mut_with_jagged_index(flat_index) }.map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the element at the given `flat_index` together with its [`JaggedIndex`];
+    /// None if out of bounds.
+    ///
+    /// # SAFETY
+    ///
+    /// The caller must ensure that the element at `flat_index` is not referenced by anyone else.
+    pub(super) unsafe fn get_mut_with_jagged_index(
+        &self,
+        flat_index: usize,
+    ) -> Option<(JaggedIndex, &'a mut T)> {
+        match flat_index < self.len {
+            true => self.jagged_index(flat_index).map(|idx| {
+                // SAFETY: index is in bounds
+                let ptr = unsafe { self.arrays[idx.f].ptr_at(idx.i) } as *mut T;
+                (idx, unsafe { &mut *ptr })
+            }),
+            false => None,
+        }
+    }
+
+    /// Returns the mutable slice of `len` elements of the `f`-th array starting at position `begin_within_slice`.
+    ///
+    /// # SAFETY
+    ///
+    /// The range must be in bounds of the `f`-th array, and the caller must ensure that none of the elements
+    /// within the range is referenced by anyone else.
+    pub(super) unsafe fn slice_mut(
+        &self,
+        f: usize,
+        begin_within_slice: usize,
+        len: usize,
+    ) -> &'a mut [T] {
+        debug_assert!(begin_within_slice + len <= self.arrays[f].length());
+        let ptr = unsafe { self.arrays[f].ptr().add(begin_within_slice) } as *mut T;
+        unsafe { core::slice::from_raw_parts_mut(ptr, len) }
+    }
+
+    /// Returns the raw jagged array slice containing all elements having positions in range `flat_begin..flat_end`
+    /// of the flattened jagged array.
+    ///
+    /// Returns an empty slice if any of the indices are out of bounds or if `flat_end <= flat_begin`.
+    pub(super) fn slice(
+        &self,
+        flat_begin: usize,
+        flat_end: usize,
+    ) -> RawJaggedSlice<'_, T, RawSlice<T>> {
+        match flat_end.saturating_sub(flat_begin) {
+            0 => Default::default(),
+            len => {
+                let [begin, end] = [flat_begin, flat_end].map(|i| self.jagged_index(i));
+                match (begin, end) {
+                    (Some(begin), Some(end)) => RawJaggedSlice::new(&self.arrays, begin, end, len),
+                    _ => Default::default(),
+                }
+            }
+        }
+    }
+
+    /// Converts the jagged array into the mutable slices containing the elements at positions
+    /// `flat_begin..self.len()`.
+    pub(super) fn into_slices_from(self, flat_begin: usize) -> Vec<&'a mut [T]> {
+        let begin = match flat_begin < self.len {
+            true => self.jagged_index(flat_begin),
+            false => None,
+        };
+        match begin {
+            Some(begin) => self.arrays[begin.f..]
+                .iter()
+                .enumerate()
+                .map(|(s, x)| {
+                    let skip = match s {
+                        0 => begin.i,
+                        _ => 0,
+                    };
+                    let ptr = unsafe { x.ptr().add(skip) } as *mut T;
+                    // SAFETY: the arrays are exclusively borrowed for 'a
+                    unsafe { core::slice::from_raw_parts_mut(ptr, x.length() - skip) }
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
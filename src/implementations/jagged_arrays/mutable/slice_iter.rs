@@ -0,0 +1,74 @@
+use crate::implementations::jagged_arrays::{
+    as_raw_slice::AsRawSlice, owned::slice::RawJaggedSlice, raw_slice::RawSlice,
+};
+
+/// An iterator over mutable references to elements of a slice of a raw jagged array;
+/// i.e., a [`RawJaggedSlice`].
+pub struct RawJaggedSliceIterMut<'j, 'a, T> {
+    slice: RawJaggedSlice<'j, T, RawSlice<T>>,
+    len_of_remaining_slices: usize,
+    f: usize,
+    current: core::slice::IterMut<'a, T>,
+}
+
+impl<T> Default for RawJaggedSliceIterMut<'_, '_, T> {
+    fn default() -> Self {
+        Self {
+            slice: Default::default(),
+            len_of_remaining_slices: 0,
+            f: 0,
+            current: Default::default(),
+        }
+    }
+}
+
+impl<'j, T> RawJaggedSliceIterMut<'j, '_, T> {
+    /// Creates an iterator over the elements of the `slice`.
+    ///
+    /// # SAFETY
+    ///
+    /// The elements of the slice must not be referenced by anyone else during the lifetime
+    /// of the yielded references.
+    pub(super) unsafe fn new(slice: RawJaggedSlice<'j, T, RawSlice<T>>) -> Self {
+        Self {
+            len_of_remaining_slices: slice.len(),
+            slice,
+            ..Default::default()
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.len_of_remaining_slices + self.current.len()
+    }
+}
+
+impl<'a, T> Iterator for RawJaggedSliceIterMut<'_, 'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.current.next() {
+                return Some(x);
+            }
+
+            let slice = self.slice.get_raw_slice(self.f)?;
+            self.f += 1;
+            self.len_of_remaining_slices -= slice.length();
+            let ptr = slice.ptr() as *mut T;
+            // SAFETY: elements of the slice are exclusively accessed by this iterator
+            self.current =
+                unsafe { core::slice::from_raw_parts_mut(ptr, slice.length()) }.iter_mut();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RawJaggedSliceIterMut<'_, '_, T> {
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    ChunkPuller, IntoFlatConcurrentIter,
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::jagged_arrays::{
+        DoublingIndexer, PrefixSumIndexer, UniformIndexer, mutable::raw_jagged_mut::RawJaggedMut,
+    },
+    into_concurrent_iter::IntoConcurrentIter,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn nested(len: usize) -> Vec<Vec<String>> {
+    let mut nested = vec![];
+    let mut begin = 0;
+    let mut f = 0;
+    while begin < len {
+        let end = (begin + (f * 7) % 13).min(len);
+        nested.push((begin..end).map(|x| x.to_string()).collect());
+        begin = end;
+        f += 1;
+    }
+    nested
+}
+
+fn update_all<'a, C>(con_iter: C, nt: usize, chunk: usize)
+where
+    C: ConcurrentIter<Item = &'a mut String>,
+{
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some((idx, x)) = con_iter.next_with_idx() {
+                        assert_eq!(x, &idx.to_string());
+                        x.push('!');
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    let chunk_size = puller.chunk_size();
+                    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                        assert!(chunk.len() <= chunk_size);
+                        for (i, x) in chunk.enumerate() {
+                            assert_eq!(x, &(begin_idx + i).to_string());
+                            x.push('!');
+                        }
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn enumeration() {
+    let mut vectors = vec![vec![1, 2], vec![], vec![3]];
+    let con_iter = vectors.as_mut_slice().into_flat_con_iter();
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next(), Some(&mut 1));
+    let (idx, x) = con_iter.next_with_idx().expect("");
+    assert_eq!(idx, 1);
+    *x = 42;
+    assert_eq!(con_iter.next(), Some(&mut 3));
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.next_with_idx(), None);
+    assert_eq!(con_iter.len(), 0);
+    assert_eq!(vectors, [vec![1, 42], vec![], vec![3]]);
+}
+
+#[test]
+fn into_seq_iter() {
+    let mut vectors = vec![vec![0, 1, 2], vec![], vec![3, 4], vec![5, 6, 7]];
+    let con_iter = (&mut vectors).into_flat_con_iter();
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(3).pull();
+    for x in con_iter.into_seq_iter() {
+        *x = 0;
+    }
+    assert_eq!(vectors, [vec![0, 1, 2], vec![], vec![3, 0], vec![0, 0, 0]]);
+
+    let con_iter = (&mut vectors).into_flat_con_iter();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn mut_slices() {
+    let mut a = [1, 2];
+    let mut b = [3];
+    let mut slices = [a.as_mut_slice(), b.as_mut_slice()];
+    let con_iter = slices.as_mut_slice().into_flat_con_iter();
+    for x in con_iter.item_puller() {
+        *x *= -1;
+    }
+    assert_eq!(a, [-1, -2]);
+    assert_eq!(b, [-3]);
+}
+
+#[test_matrix([0, 1, N], [1, 4], [0, 1, 64])]
+fn flat_con_iter_mut(len: usize, nt: usize, chunk: usize) {
+    let mut vectors = nested(len);
+    update_all(vectors.as_mut_slice().into_flat_con_iter(), nt, chunk);
+    let expected: Vec<_> = (0..len).map(|i| alloc::format!("{i}!")).collect();
+    assert_eq!(vectors.into_iter().flatten().collect::<Vec<_>>(), expected);
+}
+
+#[test_matrix([1, 4], [0, 1, 64])]
+fn with_indexers(nt: usize, chunk: usize) {
+    let mut matrix: Vec<Vec<String>> = (0..20)
+        .map(|r| (0..10).map(|c| (r * 10 + c).to_string()).collect())
+        .collect();
+    let arrays = matrix.iter_mut().map(|x| x.as_mut_slice());
+    let jagged = RawJaggedMut::new(arrays, UniformIndexer::new(10), None);
+    update_all(jagged.into_con_iter(), nt, chunk);
+    assert!(matrix.iter().flatten().all(|x| x.ends_with('!')));
+
+    let mut fragments: Vec<Vec<String>> = vec![];
+    let mut begin = 0;
+    for f in 0..6 {
+        let len = 4 << f;
+        fragments.push((begin..(begin + len)).map(|x| x.to_string()).collect());
+        begin += len;
+    }
+    let lengths: Vec<_> = fragments.iter().map(|x| x.len()).collect();
+    let arrays = fragments.iter_mut().map(|x| x.as_mut_slice());
+    let jagged = RawJaggedMut::new(arrays, DoublingIndexer::new(4), None);
+    update_all(jagged.into_con_iter(), nt, chunk);
+    assert!(fragments.iter().flatten().all(|x| x.ends_with('!')));
+
+    let arrays = fragments.iter_mut().map(|x| x.as_mut_slice());
+    let jagged = RawJaggedMut::new(arrays, PrefixSumIndexer::new(lengths), None);
+    let con_iter = jagged.into_con_iter();
+    assert_eq!(con_iter.len(), begin);
+    for x in con_iter.into_seq_iter() {
+        x.pop();
+    }
+    assert!(fragments.iter().flatten().all(|x| !x.ends_with('!')));
+}
+
+#[test_matrix([0, 1, N], [1, 4], [1, 64, usize::MAX])]
+fn fragment_puller(len: usize, nt: usize, max_chunk_size: usize) {
+    let mut vectors = nested(len);
+    let lengths: Vec<_> = vectors.iter().map(|x| x.len()).collect();
+    let con_iter = vectors.as_mut_slice().into_flat_con_iter();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = con_iter.fragment_puller(max_chunk_size);
+                while let Some((begin_idx, idx, slice)) = puller.pull_with_idx() {
+                    assert!((1..=max_chunk_size).contains(&slice.len()));
+                    assert!(idx.i + slice.len() <= lengths[idx.f]);
+                    for (i, x) in slice.iter_mut().enumerate() {
+                        assert_eq!(x, &(begin_idx + i).to_string());
+                        x.push('!');
+                    }
+                }
+            });
+        }
+    });
+    let expected: Vec<_> = (0..len).map(|i| alloc::format!("{i}!")).collect();
+    assert_eq!(vectors.into_iter().flatten().collect::<Vec<_>>(), expected);
+}
+
+#[test_matrix([0, 1, N], [1, 4], [0, 1, 64])]
+fn jagged_idx(len: usize, nt: usize, chunk: usize) {
+    let mut vectors = nested(len);
+    let con_iter = vectors.as_mut_slice().into_flat_con_iter();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some((idx, x)) = con_iter.next_with_jagged_idx() {
+                        *x = alloc::format!("{}-{}", idx.f, idx.i);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some(chunk) = puller.pull_with_jagged_idx() {
+                        for (idx, x) in chunk {
+                            *x = alloc::format!("{}-{}", idx.f, idx.i);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    for (f, vec) in vectors.iter().enumerate() {
+        for (i, x) in vec.iter().enumerate() {
+            assert_eq!(x, &alloc::format!("{f}-{i}"));
+        }
+    }
+}
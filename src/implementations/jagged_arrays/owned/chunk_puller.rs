@@ -1,5 +1,8 @@
 use super::{con_iter::ConIterJaggedOwned, slice_iter::RawJaggedSliceIterOwned};
-use crate::{ChunkPuller, implementations::jagged_arrays::indexer::JaggedIndexer};
+use crate::{
+    ChunkPuller,
+    implementations::jagged_arrays::{index::JaggedIndex, indexer::JaggedIndexer},
+};
 
 pub struct ChunkPullerJaggedOwned<'i, T, X>
 where
@@ -21,6 +24,36 @@ where
             chunk_size,
         }
     }
+
+    /// Pulls the next chunk of at most `chunk_size` elements; and returns an iterator yielding each element of
+    /// the chunk together with its [`JaggedIndex`]; i.e., the index `f` of the array containing the element
+    /// and the position `i` of the element within this array.
+    ///
+    /// Returns None if the concurrent iterator is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let matrix = vec![vec!['a', 'b'], vec![], vec!['c', 'd']];
+    /// let con_iter = matrix.into_flat_con_iter();
+    /// let mut puller = con_iter.chunk_puller(3);
+    ///
+    /// let chunk: Vec<_> = puller.pull_with_jagged_idx().unwrap().map(|(idx, x)| (idx.f, idx.i, x)).collect();
+    /// assert_eq!(chunk, [(0, 0, 'a'), (0, 1, 'b'), (2, 0, 'c')]);
+    ///
+    /// let chunk: Vec<_> = puller.pull_with_jagged_idx().unwrap().map(|(idx, x)| (idx.f, idx.i, x)).collect();
+    /// assert_eq!(chunk, [(2, 1, 'd')]);
+    ///
+    /// assert!(puller.pull_with_jagged_idx().is_none());
+    /// ```
+    pub fn pull_with_jagged_idx(
+        &mut self,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, T)> + 'i> {
+        self.con_iter
+            .progress_and_get_iter_with_jagged_idx(self.chunk_size)
+    }
 }
 
 impl<T, X> ChunkPuller for ChunkPullerJaggedOwned<'_, T, X>
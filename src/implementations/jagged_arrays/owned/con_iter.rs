@@ -1,9 +1,14 @@
 use super::{
-    chunk_puller::ChunkPullerJaggedOwned, into_iter::RawJaggedIterOwned, raw_jagged::RawJagged,
-    slice_iter::RawJaggedSliceIterOwned,
+    chunk_puller::ChunkPullerJaggedOwned, fragment::JaggedFragmentOwned,
+    fragment_puller::FragmentPullerJaggedOwned, into_iter::RawJaggedIterOwned,
+    raw_jagged::RawJagged, slice_iter::RawJaggedSliceIterOwned,
 };
 use crate::{
-    ConcurrentIter, ExactSizeConcurrentIter, implementations::jagged_arrays::indexer::JaggedIndexer,
+    ConcurrentIter, ExactSizeConcurrentIter,
+    implementations::jagged_arrays::{
+        as_raw_slice::AsRawSlice, fragment::reserve_fragment, index::JaggedIndex,
+        indexer::JaggedIndexer, with_jagged_idx::WithJaggedIdx,
+    },
 };
 use core::sync::atomic::{AtomicUsize, Ordering};
 
@@ -56,6 +61,105 @@ where
                 (begin_idx, iter)
             })
     }
+
+    pub(super) fn progress_and_get_iter_with_jagged_idx(
+        &self,
+        chunk_size: usize,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, T)> + '_> {
+        self.progress_and_get_iter(chunk_size)
+            .and_then(|(begin_idx, iter)| {
+                self.jagged.jagged_index(begin_idx).map(|begin| {
+                    WithJaggedIdx::new(iter, begin, |f| {
+                        self.jagged.get(f).map(|x| x.length()).unwrap_or(0)
+                    })
+                })
+            })
+    }
+
+    /// Returns the next element of the iterator together with its [`JaggedIndex`]; i.e., the index `f` of the
+    /// array containing the element and the position `i` of the element within this array.
+    ///
+    /// Returns None if the iterator is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::implementations::jagged_arrays::JaggedIndex;
+    ///
+    /// let matrix = vec![vec!['a', 'b'], vec![], vec!['c']];
+    /// let con_iter = matrix.into_flat_con_iter();
+    ///
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(0, 0), 'a')));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(0, 1), 'b')));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(2, 0), 'c')));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), None);
+    /// ```
+    pub fn next_with_jagged_idx(&self) -> Option<(JaggedIndex, T)> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that elements from each position is taken only once
+            unsafe { self.jagged.take_with_jagged_index(idx) }
+        })
+    }
+
+    pub(super) fn progress_and_get_fragment(
+        &self,
+        max_chunk_size: usize,
+    ) -> Option<(usize, JaggedIndex, JaggedFragmentOwned<'_, T>)> {
+        reserve_fragment(
+            &self.counter,
+            self.jagged.len(),
+            max_chunk_size,
+            |i| self.jagged.jagged_index(i),
+            |f| self.jagged.get(f).map(|x| x.length()),
+        )
+        .map(|(begin_idx, idx, len)| {
+            // SAFETY: reserve_fragment guarantees that idx.i + len is within bounds of the idx.f-th array
+            let vec = unsafe { self.jagged.get_unchecked(idx.f) };
+            let ptr = unsafe { vec.ptr_at(idx.i) } as *mut T;
+            // SAFETY: `counter` ensures that elements from each position is taken only once
+            let fragment = unsafe { JaggedFragmentOwned::new(ptr, len) };
+            (begin_idx, idx, fragment)
+        })
+    }
+
+    /// Creates a [`FragmentPullerJaggedOwned`] which pulls contiguous slices that never span two
+    /// fragments of the jagged array, each with at most `max_chunk_size` elements.
+    ///
+    /// Pass `usize::MAX` as `max_chunk_size` to pull whole fragments, or remaining parts of fragments
+    /// which are partially consumed by other pulls.
+    ///
+    /// The fragment puller shares the progress of this iterator; hence, it can be used together with
+    /// `next` calls and chunk pullers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let vectors = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7]];
+    /// let con_iter = vectors.into_flat_con_iter();
+    ///
+    /// let mut puller = con_iter.fragment_puller(usize::MAX);
+    ///
+    /// let (idx, fragment) = puller.pull().unwrap();
+    /// assert_eq!((idx.f, idx.i), (0, 0));
+    /// assert_eq!(fragment.as_slice(), &[0, 1, 2]);
+    ///
+    /// let (idx, fragment) = puller.pull().unwrap();
+    /// assert_eq!((idx.f, idx.i), (2, 0));
+    /// assert_eq!(fragment.collect::<Vec<_>>(), vec![3, 4, 5, 6, 7]);
+    ///
+    /// assert!(puller.pull().is_none());
+    /// ```
+    pub fn fragment_puller(&self, max_chunk_size: usize) -> FragmentPullerJaggedOwned<'_, T, X> {
+        assert!(max_chunk_size > 0, "max_chunk_size must be positive");
+        FragmentPullerJaggedOwned::new(self, max_chunk_size)
+    }
 }
 
 impl<T, X> ConcurrentIter for ConIterJaggedOwned<T, X>
@@ -115,8 +219,7 @@ where
 impl<T, X> ExactSizeConcurrentIter for ConIterJaggedOwned<T, X>
 where
     T: Send,
-    T: Send + Sync,
-    X: JaggedIndexer + Send + Sync,
+    X: JaggedIndexer,
 {
     fn len(&self) -> usize {
         let num_taken = self.counter.load(Ordering::Acquire);
@@ -0,0 +1,239 @@
+use super::{
+    drain_chunk_puller::ChunkPullerJaggedDrain, raw_jagged::RawJagged,
+    slice_iter::RawJaggedSliceIterOwned,
+};
+use crate::{
+    ConcurrentIter, ExactSizeConcurrentIter, implementations::jagged_arrays::PrefixSumIndexer,
+};
+use core::{
+    ops::{Bound, Range, RangeBounds},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent draining iterator of a range of the flattened elements of a [`RawJagged`]:
+///
+/// * the iterator yields all elements within the range;
+/// * once the iterator is dropped, the range is removed from the jagged array which keeps the
+///   remaining elements in their original order;
+/// * none, some or all elements may be traversed and returned by the concurrent iterator;
+///   regardless, all elements of the range will be cleaned up.
+///
+/// Since removing a range changes the lengths of the arrays of the jagged array, the drain is
+/// available for jagged arrays using the [`PrefixSumIndexer`], which is rebuilt once the range
+/// is removed.
+///
+/// It can be created by calling [`con_drain`] on a raw jagged array.
+///
+/// [`con_drain`]: crate::ConcurrentDrainableOverSlice::con_drain
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::jagged_arrays::*;
+///
+/// let arrays = vec![vec![0, 1, 2], vec![3, 4], vec![5, 6, 7]];
+/// let indexer = PrefixSumIndexer::new(arrays.iter().map(|x| x.len()));
+/// let mut jagged = RawJagged::new(arrays.into_iter().map(RawVec::from).collect(), indexer, None);
+///
+/// let drained: Vec<_> = jagged.con_drain(2..6).item_puller().collect();
+/// assert_eq!(drained, [2, 3, 4, 5]);
+///
+/// assert_eq!(jagged.len(), 4);
+/// let remaining: Vec<_> = jagged.into_con_iter().item_puller().collect();
+/// assert_eq!(remaining, [0, 1, 6, 7]);
+/// ```
+pub struct ConIterJaggedDrain<'a, T> {
+    target: &'a mut RawJagged<T, PrefixSumIndexer>,
+    jagged: RawJagged<T, PrefixSumIndexer>,
+    range: Range<usize>,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send> Sync for ConIterJaggedDrain<'_, T> {}
+
+unsafe impl<T: Send> Send for ConIterJaggedDrain<'_, T> {}
+
+impl<T> Drop for ConIterJaggedDrain<'_, T> {
+    fn drop(&mut self) {
+        /// Closes the gap and gives the jagged array back even if dropping one of the untaken
+        /// elements panics; the elements which could not be dropped are leaked then.
+        struct DropGuard<'d, 'a, T>(&'d mut ConIterJaggedDrain<'a, T>);
+
+        impl<T> Drop for DropGuard<'_, '_, T> {
+            fn drop(&mut self) {
+                let drain = &mut self.0;
+                // SAFETY: all elements within the range are either taken out, dropped or leaked
+                unsafe { drain.jagged.close_gap(drain.range.clone()) };
+                *drain.target = drain.jagged.move_into_new(0);
+            }
+        }
+
+        let num_taken = self.num_taken();
+        let guard = DropGuard(self);
+        if num_taken < guard.0.range.len() {
+            let begin = guard.0.range.start + num_taken;
+            let slice = guard.0.jagged.slice(begin, guard.0.range.end);
+            drop(RawJaggedSliceIterOwned::new(slice));
+        }
+    }
+}
+
+impl<'a, T> ConIterJaggedDrain<'a, T> {
+    /// Creates a new concurrent draining iterator over the `jagged` array for the given flat `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics:
+    ///
+    /// * if the starting point of the `range` is greater than the ending point; or
+    /// * if the ending point of the `range` is greater than `jagged.len()`.
+    pub(super) fn new<R>(target: &'a mut RawJagged<T, PrefixSumIndexer>, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Excluded(x) => x + 1,
+            Bound::Included(x) => *x,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Excluded(x) => *x,
+            Bound::Included(x) => x + 1,
+            Bound::Unbounded => target.len(),
+        };
+        let range = start..end;
+
+        assert!(range.start <= range.end);
+        assert!(range.end <= target.len());
+
+        // the target is left empty without anything to drop as a safeguard for if the iterator is leaked
+        let jagged = target.move_into_new(0);
+
+        Self {
+            target,
+            jagged,
+            range,
+            counter: 0.into(),
+        }
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.range.len() {
+            true => Some(begin_idx),
+            false => None,
+        }
+    }
+
+    fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.range.len())
+    }
+
+    pub(super) fn progress_and_get_iter(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, RawJaggedSliceIterOwned<'_, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size)
+                    .min(self.range.len())
+                    .max(begin_idx);
+                let [begin, end] = [begin_idx, end_idx].map(|i| self.range.start + i);
+                let slice = self.jagged.slice(begin, end);
+                let iter = RawJaggedSliceIterOwned::new(slice);
+                (begin_idx, iter)
+            })
+    }
+}
+
+/// Sequential iterator over the remaining elements of a [`ConIterJaggedDrain`].
+///
+/// Once dropped, the drained range is removed from the jagged array.
+pub struct JaggedDrainSeqIter<'a, T> {
+    con_iter: ConIterJaggedDrain<'a, T>,
+}
+
+impl<T> Iterator for JaggedDrainSeqIter<'_, T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.con_iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.con_iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for JaggedDrainSeqIter<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        self.con_iter.len()
+    }
+}
+
+impl<'a, T> ConcurrentIter for ConIterJaggedDrain<'a, T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = JaggedDrainSeqIter<'a, T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerJaggedDrain<'i, 'a, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        JaggedDrainSeqIter { con_iter: self }
+    }
+
+    fn skip_to_end(&self) {
+        let current = self.counter.fetch_max(self.range.len(), Ordering::Acquire);
+        let num_taken_before = current.min(self.range.len());
+        let begin = self.range.start + num_taken_before;
+        let slice = self.jagged.slice(begin, self.range.end);
+        let _iter = RawJaggedSliceIterOwned::new(slice);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that elements from each position is taken only once
+            unsafe { self.jagged.take(self.range.start + idx) }
+        })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1).and_then(|idx| {
+            // SAFETY: `counter` ensures that elements from each position is taken only once
+            unsafe { self.jagged.take(self.range.start + idx) }.map(|value| (idx, value))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.range.len().saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterJaggedDrain<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.range.len().saturating_sub(num_taken)
+    }
+}
@@ -0,0 +1,48 @@
+use super::{drain::ConIterJaggedDrain, slice_iter::RawJaggedSliceIterOwned};
+use crate::ChunkPuller;
+
+pub struct ChunkPullerJaggedDrain<'i, 'a, T>
+where
+    T: Send,
+{
+    con_iter: &'i ConIterJaggedDrain<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T> ChunkPullerJaggedDrain<'i, 'a, T>
+where
+    T: Send,
+{
+    pub(super) fn new(con_iter: &'i ConIterJaggedDrain<'a, T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<T> ChunkPuller for ChunkPullerJaggedDrain<'_, '_, T>
+where
+    T: Send,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = RawJaggedSliceIterOwned<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_iter(self.chunk_size)
+            .map(|(_begin_idx, iter)| iter)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_iter(self.chunk_size)
+    }
+}
@@ -0,0 +1,22 @@
+use super::{drain::ConIterJaggedDrain, raw_jagged::RawJagged};
+use crate::{ConcurrentDrainableOverSlice, implementations::jagged_arrays::PrefixSumIndexer};
+use core::ops::RangeBounds;
+
+impl<T> ConcurrentDrainableOverSlice for RawJagged<T, PrefixSumIndexer>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type DrainingIter<'a>
+        = ConIterJaggedDrain<'a, T>
+    where
+        Self: 'a;
+
+    fn con_drain<R>(&mut self, range: R) -> Self::DrainingIter<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        ConIterJaggedDrain::new(self, range)
+    }
+}
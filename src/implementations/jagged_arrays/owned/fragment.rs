@@ -0,0 +1,97 @@
+use crate::implementations::ptr_utils::take;
+use core::marker::PhantomData;
+
+/// A contiguous sub-slice of a single fragment of a jagged array whose elements are owned by this
+/// fragment; pulled by a [`FragmentPullerJaggedOwned`].
+///
+/// Similar to `std::vec::IntoIter`, the remaining elements can be accessed as a slice through
+/// [`as_slice`] and [`as_mut_slice`], or taken out one by one by iterating over the fragment.
+///
+/// All elements of the fragment are dropped in either one of the two ways:
+///
+/// * If the element is visited by the iterator, it is taken out and dropped by the caller side.
+/// * Otherwise, it is dropped in place while this fragment is being dropped.
+///
+/// [`FragmentPullerJaggedOwned`]: crate::implementations::jagged_arrays::FragmentPullerJaggedOwned
+/// [`as_slice`]: Self::as_slice
+/// [`as_mut_slice`]: Self::as_mut_slice
+pub struct JaggedFragmentOwned<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    phantom: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Send> Send for JaggedFragmentOwned<'_, T> {}
+
+impl<T> Default for JaggedFragmentOwned<'_, T> {
+    fn default() -> Self {
+        Self {
+            ptr: core::ptr::NonNull::dangling().as_ptr(),
+            len: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> JaggedFragmentOwned<'_, T> {
+    /// Creates the fragment owning the `len` elements starting at `ptr`.
+    ///
+    /// # SAFETY
+    ///
+    /// The `len` elements must be initialized and must not be accessed or dropped by anyone else.
+    pub(super) unsafe fn new(ptr: *mut T, len: usize) -> Self {
+        Self {
+            ptr,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the remaining elements of the fragment as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the remaining len elements are initialized and exclusively owned
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Returns the remaining elements of the fragment as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the remaining len elements are initialized and exclusively owned
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<T> Iterator for JaggedFragmentOwned<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => {
+                let ptr = self.ptr;
+                self.len -= 1;
+                // SAFETY: ptr is in bounds; ptr + 1 is at most the exclusive end of the fragment
+                self.ptr = unsafe { self.ptr.add(1) };
+                // SAFETY: ptr is valid and its value can be taken; it is no longer within the fragment
+                Some(unsafe { take(ptr) })
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for JaggedFragmentOwned<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> Drop for JaggedFragmentOwned<'_, T> {
+    fn drop(&mut self) {
+        let remaining = core::ptr::slice_from_raw_parts_mut(self.ptr, self.len);
+        // SAFETY: the remaining elements are initialized and owned by this fragment
+        unsafe { remaining.drop_in_place() };
+    }
+}
@@ -0,0 +1,52 @@
+use super::{con_iter::ConIterJaggedOwned, fragment::JaggedFragmentOwned};
+use crate::implementations::jagged_arrays::{JaggedIndex, JaggedIndexer};
+
+/// A puller which pulls contiguous slices of a jagged array, each of which lies within a single
+/// fragment of the jagged array.
+///
+/// Created by [`ConIterJaggedOwned::fragment_puller`].
+///
+/// Each pull yields a whole fragment, or the remaining part of a fragment, with at most
+/// `max_chunk_size` elements, together with the [`JaggedIndex`] of its first element.
+/// The elements are owned by the pulled [`JaggedFragmentOwned`] which can be accessed as a slice
+/// and processed with slice-optimized code.
+pub struct FragmentPullerJaggedOwned<'i, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    con_iter: &'i ConIterJaggedOwned<T, X>,
+    max_chunk_size: usize,
+}
+
+impl<'i, T, X> FragmentPullerJaggedOwned<'i, T, X>
+where
+    T: Send,
+    X: JaggedIndexer,
+{
+    pub(super) fn new(con_iter: &'i ConIterJaggedOwned<T, X>, max_chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            max_chunk_size,
+        }
+    }
+
+    /// Maximum number of elements of a pulled fragment.
+    pub fn max_chunk_size(&self) -> usize {
+        self.max_chunk_size
+    }
+
+    /// Pulls the next contiguous slice of a fragment together with the [`JaggedIndex`] of its first element;
+    /// returns None if the concurrent iterator is consumed.
+    pub fn pull(&mut self) -> Option<(JaggedIndex, JaggedFragmentOwned<'i, T>)> {
+        self.con_iter
+            .progress_and_get_fragment(self.max_chunk_size)
+            .map(|(_, idx, fragment)| (idx, fragment))
+    }
+
+    /// Pulls the next contiguous slice of a fragment together with the flat index and [`JaggedIndex`] of its
+    /// first element; returns None if the concurrent iterator is consumed.
+    pub fn pull_with_idx(&mut self) -> Option<(usize, JaggedIndex, JaggedFragmentOwned<'i, T>)> {
+        self.con_iter.progress_and_get_fragment(self.max_chunk_size)
+    }
+}
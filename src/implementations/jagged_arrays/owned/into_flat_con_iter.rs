@@ -0,0 +1,19 @@
+use super::{ConIterJaggedOwned, RawJagged, RawVec};
+use crate::{IntoFlatConcurrentIter, implementations::jagged_arrays::PrefixSumIndexer};
+use alloc::vec::Vec;
+
+impl<T> IntoFlatConcurrentIter for Vec<Vec<T>>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterJaggedOwned<T, PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        let indexer = PrefixSumIndexer::new(self.iter().map(|x| x.len()));
+        let total_len = indexer.total_len();
+        let arrays = self.into_iter().map(RawVec::from).collect();
+        ConIterJaggedOwned::new(RawJagged::new(arrays, indexer, Some(total_len)), 0)
+    }
+}
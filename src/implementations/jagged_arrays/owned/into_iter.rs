@@ -79,7 +79,10 @@ where
 
     fn next_vec(&mut self) -> Option<T> {
         match self.jagged.get(self.f) {
-            Some(slice) if slice.is_empty() => self.next_vec(),
+            Some(slice) if slice.is_empty() => {
+                self.f += 1;
+                self.next_vec()
+            }
             Some(slice) => {
                 // SAFETY: pointers are not null since slice is not empty
                 [self.current_ptr, self.current_last] = slice.first_and_last_ptrs();
@@ -92,7 +95,10 @@ where
 
     fn drop_next_vec(&mut self) -> bool {
         match self.jagged.get(self.f) {
-            Some(slice) if slice.is_empty() => self.drop_next_vec(),
+            Some(slice) if slice.is_empty() => {
+                self.f += 1;
+                self.drop_next_vec()
+            }
             Some(slice) => {
                 // SAFETY: pointers are not null since slice is not empty
                 [self.current_ptr, self.current_last] = slice.first_and_last_ptrs();
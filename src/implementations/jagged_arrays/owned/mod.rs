@@ -3,13 +3,22 @@ mod tests;
 
 mod chunk_puller;
 mod con_iter;
+mod drain;
+mod drain_chunk_puller;
+mod drainable;
+mod fragment;
+mod fragment_puller;
 mod into_con_iter;
+mod into_flat_con_iter;
 mod into_iter;
 mod raw_jagged;
 mod raw_vec;
-mod slice;
+pub(super) mod slice;
 mod slice_iter;
 
 pub use con_iter::ConIterJaggedOwned;
+pub use drain::{ConIterJaggedDrain, JaggedDrainSeqIter};
+pub use fragment::JaggedFragmentOwned;
+pub use fragment_puller::FragmentPullerJaggedOwned;
 pub use raw_jagged::RawJagged;
 pub use raw_vec::RawVec;
@@ -1,6 +1,7 @@
 use super::{raw_vec::RawVec, slice::RawJaggedSlice};
 use crate::implementations::{
     jagged_arrays::{
+        PrefixSumIndexer,
         as_raw_slice::{AsOwningSlice, AsRawSlice},
         index::JaggedIndex,
         indexer::JaggedIndexer,
@@ -8,7 +9,7 @@ use crate::implementations::{
     ptr_utils::take,
 };
 use alloc::vec::Vec;
-use core::cmp::Ordering;
+use core::{cmp::Ordering, ops::Range};
 
 /// Raw representation of a jagged array.
 /// Internally, the jagged array is stored as a vector of `RawVec<T>`.
@@ -41,7 +42,16 @@ where
     ///
     /// Once the jagged array is dropped, the elements and allocation of the vectors
     /// will also be dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `indexer` is not valid for the lengths of the `arrays`; see
+    /// [`JaggedIndexer::is_valid_for`].
     pub fn new(arrays: Vec<RawVec<T>>, indexer: X, total_len: Option<usize>) -> Self {
+        assert!(
+            indexer.is_valid_for(arrays.iter().map(|v| v.length())),
+            "indexer is not valid for the lengths of the arrays"
+        );
         let len = total_len.unwrap_or_else(|| arrays.iter().map(|v| v.length()).sum());
         Self {
             arrays,
@@ -72,10 +82,15 @@ where
     }
 
     /// Total number of elements in the jagged array (`O(1)`).
-    pub(super) fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns true if the jagged array does not contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Returns number of arrays of the jagged array.
     pub(super) fn num_arrays(&self) -> usize {
         self.arrays.len()
@@ -178,10 +193,25 @@ where
     ///
     /// [`set_num_taken`]: Self::set_num_taken
     pub(super) unsafe fn take(&self, flat_index: usize) -> Option<T> {
+        unsafe { self.take_with_jagged_index(flat_index) }.map(|(_, value)| value)
+    }
+
+    /// Takes the element at the `flat-index`-th position of the flattened jagged array together with
+    /// its [`JaggedIndex`].
+    ///
+    /// # Safety
+    ///
+    /// Has the same safety requirements as [`take`].
+    ///
+    /// [`take`]: Self::take
+    pub(super) unsafe fn take_with_jagged_index(
+        &self,
+        flat_index: usize,
+    ) -> Option<(JaggedIndex, T)> {
         self.jagged_index(flat_index).map(|idx| {
             let vec = &self.arrays[idx.f];
             let ptr = unsafe { vec.ptr_at(idx.i) as *mut T }; // index is in bounds
-            unsafe { take(ptr) }
+            (idx, unsafe { take(ptr) })
         })
     }
 
@@ -223,6 +253,43 @@ where
     }
 }
 
+impl<T> RawJagged<T, PrefixSumIndexer> {
+    /// Removes the positions within the flat `range` from the jagged array by shifting the subsequent
+    /// elements of each array to the left; and rebuilds the indexer with respect to the new lengths
+    /// of the arrays.
+    ///
+    /// # Safety
+    ///
+    /// All elements within the `range` must already be taken out or dropped.
+    pub(super) unsafe fn close_gap(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let [begin, end] = [range.start, range.end].map(|i| self.jagged_index(i));
+        let (Some(begin), Some(end)) = (begin, end) else {
+            return;
+        };
+
+        for f in begin.f..=end.f {
+            let vec = &mut self.arrays[f];
+            let len = vec.length();
+            let lo = if f == begin.f { begin.i } else { 0 };
+            let hi = if f == end.f { end.i } else { len };
+            if hi > lo {
+                let ptr = vec.ptr() as *mut T;
+                // SAFETY: both lo and hi are within bounds of the array
+                unsafe { core::ptr::copy(ptr.add(hi), ptr.add(lo), len - hi) };
+                // SAFETY: first len - (hi - lo) elements are initialized after the shift
+                unsafe { vec.set_len(len - (hi - lo)) };
+            }
+        }
+
+        self.indexer = PrefixSumIndexer::new(self.arrays.iter().map(|x| x.length()));
+        self.len -= range.len();
+    }
+}
+
 impl<T, X> Drop for RawJagged<T, X>
 where
     X: JaggedIndexer,
@@ -41,6 +41,18 @@ impl<T> From<Vec<T>> for RawVec<T> {
     }
 }
 
+impl<T> RawVec<T> {
+    /// Sets the length of the vector to `len`.
+    ///
+    /// # SAFETY
+    ///
+    /// `len` must not exceed the capacity, and the first `len` elements must be initialized.
+    pub(super) unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.capacity);
+        self.len = len;
+    }
+}
+
 impl<T> AsRawSlice<T> for RawVec<T> {
     fn ptr(&self) -> *const T {
         self.ptr
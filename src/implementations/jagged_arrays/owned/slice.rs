@@ -4,8 +4,14 @@ use core::marker::PhantomData;
 
 /// A slice of a jagged array which might be empty, a slice of a single vector,
 /// or a series of slices of subsequent arrays of the jagged array.
-pub struct RawJaggedSlice<'a, T> {
-    vectors: &'a [RawVec<T>],
+///
+/// The arrays are owned raw vectors by default, while any raw slice representation can be used,
+/// such as the raw slices of the mutable jagged arrays.
+pub struct RawJaggedSlice<'a, T, V = RawVec<T>>
+where
+    V: AsRawSlice<T>,
+{
+    vectors: &'a [V],
     begin: JaggedIndex,
     end: JaggedIndex,
     len: usize,
@@ -13,7 +19,10 @@ pub struct RawJaggedSlice<'a, T> {
     phantom: PhantomData<T>,
 }
 
-impl<T> Default for RawJaggedSlice<'_, T> {
+impl<T, V> Default for RawJaggedSlice<'_, T, V>
+where
+    V: AsRawSlice<T>,
+{
     fn default() -> Self {
         Self {
             vectors: Default::default(),
@@ -26,10 +35,13 @@ impl<T> Default for RawJaggedSlice<'_, T> {
     }
 }
 
-impl<'a, T> RawJaggedSlice<'a, T> {
+impl<'a, T, V> RawJaggedSlice<'a, T, V>
+where
+    V: AsRawSlice<T>,
+{
     /// Constructs a non-empty raw jagged slice.
-    pub(super) fn new(
-        arrays: &'a [RawVec<T>],
+    pub(in crate::implementations::jagged_arrays) fn new(
+        arrays: &'a [V],
         begin: JaggedIndex,
         end: JaggedIndex,
         len: usize,
@@ -71,8 +83,8 @@ impl<'a, T> RawJaggedSlice<'a, T> {
 
     /// Returns the `s`-th raw slice among the slices of this jagged array slice.
     ///
-    /// Returns None if `f` is out of bounds, or the corresponding slice is empty.
-    /// Therefore, if this method returns Some, returned slice always have at least one element.
+    /// Returns None if `s` is out of bounds.
+    /// Note that the returned slice is empty if the corresponding array of the jagged array is empty.
     pub fn get_raw_slice(&self, s: usize) -> Option<RawSlice<T>> {
         match s < self.num_slices {
             true => {
@@ -92,8 +104,8 @@ impl<'a, T> RawJaggedSlice<'a, T> {
                     },
                 };
 
+                // might be empty when the jagged array contains empty arrays
                 let len = end_exc - start;
-                debug_assert!(len > 0);
 
                 Some(vec.raw_slice(start, len))
             }
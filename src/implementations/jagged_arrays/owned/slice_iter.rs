@@ -55,7 +55,10 @@ impl<'a, T> RawJaggedSliceIterOwned<'a, T> {
 
     fn next_slice(&mut self) -> Option<T> {
         match self.slice.get_raw_slice(self.f) {
-            Some(slice) if slice.is_empty() => self.next_slice(),
+            Some(slice) if slice.is_empty() => {
+                self.f += 1;
+                self.next_slice()
+            }
             Some(slice) => {
                 self.len_of_remaining_slices -= slice.length();
                 // SAFETY: pointers are not null since slice is not empty
@@ -69,7 +72,10 @@ impl<'a, T> RawJaggedSliceIterOwned<'a, T> {
 
     fn drop_next_slice(&mut self) -> bool {
         match self.slice.get_raw_slice(self.f) {
-            Some(slice) if slice.is_empty() => self.drop_next_slice(),
+            Some(slice) if slice.is_empty() => {
+                self.f += 1;
+                self.drop_next_slice()
+            }
             Some(slice) => {
                 // SAFETY: pointers are not null since slice is not empty
                 [self.current_ptr, self.current_last] = slice.first_and_last_ptrs();
@@ -2,13 +2,17 @@ use crate::{
     ChunkPuller,
     concurrent_iter::ConcurrentIter,
     exact_size_concurrent_iter::ExactSizeConcurrentIter,
-    implementations::jagged_arrays::owned::{
-        con_iter::ConIterJaggedOwned, raw_jagged::RawJagged, raw_vec::RawVec,
-        tests::indexers::MatrixIndexer,
+    implementations::jagged_arrays::{
+        JaggedIndex,
+        owned::{
+            con_iter::ConIterJaggedOwned, raw_jagged::RawJagged, raw_vec::RawVec,
+            tests::indexers::MatrixIndexer,
+        },
     },
 };
 use alloc::{
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use orx_concurrent_bag::ConcurrentBag;
@@ -501,3 +505,75 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix([0, 2, N], [1, 2, 4], [0, 1, 7])]
+fn jagged_idx(n: usize, nt: usize, chunk_size: usize) {
+    let arrays: Vec<Vec<String>> = (0..n)
+        .map(|f| {
+            (0..((f * 7) % 5))
+                .map(|i| alloc::format!("{f}-{i}"))
+                .collect()
+        })
+        .collect();
+    let mut expected: Vec<_> = arrays.iter().flatten().cloned().collect();
+    let indexer = crate::implementations::jagged_arrays::PrefixSumIndexer::new(
+        arrays.iter().map(|x| x.len()),
+    );
+    let arrays: Vec<_> = arrays.into_iter().map(RawVec::from).collect();
+    let jagged = RawJagged::new(arrays, indexer, None);
+    let iter = ConIterJaggedOwned::new(jagged, 0);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk_size {
+                0 => {
+                    while let Some((idx, x)) = iter.next_with_jagged_idx() {
+                        assert_eq!(x, alloc::format!("{}-{}", idx.f, idx.i));
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = iter.chunk_puller(chunk_size);
+                    while let Some(chunk) = puller.pull_with_jagged_idx() {
+                        assert!(chunk.len() <= chunk_size);
+                        for (idx, x) in chunk {
+                            assert_eq!(x, alloc::format!("{}-{}", idx.f, idx.i));
+                            bag.push(x);
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test]
+fn jagged_idx_partially_consumed_chunk() {
+    let arrays = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7]];
+    let arrays: Vec<Vec<String>> = arrays
+        .into_iter()
+        .map(|x| x.into_iter().map(|x| x.to_string()).collect())
+        .collect();
+    let indexer = crate::implementations::jagged_arrays::PrefixSumIndexer::new(
+        arrays.iter().map(|x| x.len()),
+    );
+    let arrays: Vec<_> = arrays.into_iter().map(RawVec::from).collect();
+    let iter = ConIterJaggedOwned::new(RawJagged::new(arrays, indexer, None), 0);
+
+    let mut puller = iter.chunk_puller(5);
+    let mut chunk = puller.pull_with_jagged_idx().expect("");
+    assert_eq!(chunk.len(), 5);
+    assert_eq!(chunk.next(), Some((JaggedIndex::new(0, 0), 0.to_string())));
+    drop(chunk);
+
+    assert_eq!(
+        iter.next_with_jagged_idx(),
+        Some((JaggedIndex::new(2, 2), 5.to_string()))
+    );
+}
@@ -0,0 +1,182 @@
+use crate::{
+    ChunkPuller, ConcurrentDrainableOverSlice, IntoConcurrentIter,
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::jagged_arrays::{
+        PrefixSumIndexer,
+        owned::{raw_jagged::RawJagged, raw_vec::RawVec},
+    },
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::RangeBounds;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 11;
+#[cfg(not(miri))]
+const N: usize = 66;
+
+fn get_arrays(n: usize) -> Vec<Vec<String>> {
+    let mut begin = 0;
+    (0..n)
+        .map(|f| {
+            let len = (f * 7) % 5;
+            let array = (begin..(begin + len)).map(|x| x.to_string()).collect();
+            begin += len;
+            array
+        })
+        .collect()
+}
+
+fn new_jagged(arrays: Vec<Vec<String>>) -> RawJagged<String, PrefixSumIndexer> {
+    let indexer = PrefixSumIndexer::new(arrays.iter().map(|x| x.len()));
+    let arrays = arrays.into_iter().map(RawVec::from).collect();
+    RawJagged::new(arrays, indexer, None)
+}
+
+#[test_matrix(
+    [8],
+    [.., 5.., ..12, 5..12, 3..3, 0..0],
+    [0, 1, 4, 7, 30]
+)]
+fn validation(n: usize, range: impl RangeBounds<usize> + Clone, num_pull: usize) {
+    let arrays = get_arrays(n);
+    let mut vec: Vec<_> = arrays.iter().flatten().cloned().collect();
+    let mut jagged = new_jagged(arrays);
+    assert_eq!(jagged.len(), vec.len());
+
+    {
+        let mut iter = vec.drain(range.clone());
+        let con_iter = jagged.con_drain(range);
+
+        for _ in 0..num_pull {
+            let a = iter.next();
+            let b = con_iter.next();
+            assert_eq!(a, b);
+            assert_eq!(iter.len(), con_iter.len());
+        }
+    }
+
+    assert_eq!(jagged.len(), vec.len());
+    assert_eq!(jagged.is_empty(), vec.is_empty());
+    let remaining: Vec<_> = jagged.into_con_iter().into_seq_iter().collect();
+    assert_eq!(remaining, vec);
+}
+
+#[test_matrix([0, 1, 7], [1, 3])]
+fn drain_into_seq_iter(num_pull: usize, num_drains: usize) {
+    let arrays = get_arrays(8);
+    let mut vec: Vec<_> = arrays.iter().flatten().cloned().collect();
+    let mut jagged = new_jagged(arrays);
+
+    for _ in 0..num_drains {
+        let range = 2..(vec.len() / 2);
+        let expected: Vec<_> = vec.drain(range.clone()).collect();
+
+        let con_iter = jagged.con_drain(range);
+        let mut drained = Vec::new();
+        for _ in 0..num_pull {
+            drained.extend(con_iter.next());
+        }
+        drained.extend(con_iter.into_seq_iter());
+        assert_eq!(drained, expected);
+    }
+
+    let remaining: Vec<_> = jagged.into_con_iter().into_seq_iter().collect();
+    assert_eq!(remaining, vec);
+}
+
+#[test_matrix([0, 2, N], [1, 2, 4], [1, 7])]
+fn drain_concurrently(n: usize, nt: usize, chunk_size: usize) {
+    let arrays = get_arrays(n);
+    let mut vec: Vec<_> = arrays.iter().flatten().cloned().collect();
+    let mut jagged = new_jagged(arrays);
+
+    let range = (vec.len() / 3)..(vec.len() * 2 / 3);
+    let mut expected: Vec<_> = vec.drain(range.clone()).collect();
+
+    let bag = ConcurrentBag::new();
+    {
+        let con_iter = jagged.con_drain(range);
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    let mut puller = con_iter.chunk_puller(chunk_size);
+                    while let Some(chunk) = puller.pull() {
+                        for x in chunk {
+                            bag.push(x);
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, expected);
+
+    let remaining: Vec<_> = jagged.into_con_iter().into_seq_iter().collect();
+    assert_eq!(remaining, vec);
+}
+
+#[test]
+fn drain_skip_to_end() {
+    let arrays = get_arrays(N);
+    let mut vec: Vec<_> = arrays.iter().flatten().cloned().collect();
+    let mut jagged = new_jagged(arrays);
+
+    let range = 3..(vec.len() - 3);
+    let expected: Vec<_> = vec.drain(range.clone()).collect();
+    {
+        let con_iter = jagged.con_drain(range);
+        let chunk: Vec<_> = con_iter.chunk_puller(4).pull().expect("").collect();
+        assert_eq!(chunk, expected[..4]);
+        con_iter.skip_to_end();
+        assert_eq!(con_iter.next(), None);
+    }
+
+    let remaining: Vec<_> = jagged.into_con_iter().into_seq_iter().collect();
+    assert_eq!(remaining, vec);
+}
+
+#[test]
+fn panic_while_dropping_untaken_elements() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NUM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    struct PanicOnDrop(usize);
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            NUM_DROPPED.fetch_add(1, Ordering::Relaxed);
+            assert_ne!(self.0, 5, "dropping 5 panics");
+        }
+    }
+
+    let arrays: Vec<Vec<_>> = [0..3, 3..7, 7..10]
+        .into_iter()
+        .map(|r| r.map(PanicOnDrop).collect())
+        .collect();
+    let indexer = PrefixSumIndexer::new(arrays.iter().map(|x| x.len()));
+    let arrays = arrays.into_iter().map(RawVec::from).collect();
+    let mut jagged = RawJagged::new(arrays, indexer, None);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let con_iter = jagged.con_drain(2..8);
+        let _ = con_iter.next();
+    }));
+    assert!(result.is_err());
+    assert_eq!(jagged.len(), 4);
+
+    NUM_DROPPED.store(0, Ordering::Relaxed);
+    let remaining: Vec<_> = jagged.into_con_iter().item_puller().map(|x| x.0).collect();
+    assert_eq!(remaining, [0, 1, 8, 9]);
+    assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 4);
+}
@@ -0,0 +1,132 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::jagged_arrays::{
+        JaggedIndex, PrefixSumIndexer,
+        owned::{con_iter::ConIterJaggedOwned, raw_jagged::RawJagged, raw_vec::RawVec},
+    },
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 11;
+#[cfg(not(miri))]
+const N: usize = 66;
+
+fn get_jagged(n: usize) -> Vec<Vec<String>> {
+    let mut begin = 0;
+    (0..n)
+        .map(|f| {
+            let len = (f * 7) % 5;
+            let array = (begin..(begin + len)).map(|x| x.to_string()).collect();
+            begin += len;
+            array
+        })
+        .collect()
+}
+
+fn new_iter(arrays: Vec<Vec<String>>) -> ConIterJaggedOwned<String, PrefixSumIndexer> {
+    let indexer = PrefixSumIndexer::new(arrays.iter().map(|x| x.len()));
+    let arrays: Vec<_> = arrays.into_iter().map(RawVec::from).collect();
+    let jagged = RawJagged::new(arrays, indexer, None);
+    ConIterJaggedOwned::new(jagged, 0)
+}
+
+#[test]
+fn fragment_puller_with_next() {
+    let arrays = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7], vec![8]];
+    let arrays: Vec<Vec<String>> = arrays
+        .into_iter()
+        .map(|x| x.into_iter().map(|x| x.to_string()).collect())
+        .collect();
+    let iter = new_iter(arrays);
+
+    let mut puller = iter.fragment_puller(2);
+    assert_eq!(puller.max_chunk_size(), 2);
+
+    let (idx, mut fragment) = puller.pull().expect("");
+    assert_eq!(idx, JaggedIndex::new(0, 0));
+    assert_eq!(fragment.as_slice(), &["0", "1"]);
+    fragment.as_mut_slice()[1].push('!');
+    assert_eq!(fragment.len(), 2);
+    assert_eq!(fragment.next(), Some("0".to_string()));
+    assert_eq!(fragment.as_slice(), &["1!"]);
+    assert_eq!(fragment.next(), Some("1!".to_string()));
+    assert_eq!(fragment.next(), None);
+
+    let (idx, fragment) = puller.pull().expect("");
+    assert_eq!(idx, JaggedIndex::new(0, 2));
+    assert_eq!(fragment.as_slice(), &["2"]);
+
+    assert_eq!(iter.next(), Some(3.to_string()));
+
+    let (begin_idx, idx, mut fragment) = puller.pull_with_idx().expect("");
+    assert_eq!((begin_idx, idx), (4, JaggedIndex::new(2, 1)));
+    assert_eq!(fragment.next(), Some(4.to_string()));
+    drop(fragment); // drops 5 in place
+
+    let mut puller = iter.fragment_puller(usize::MAX);
+    let (idx, fragment) = puller.pull().expect("");
+    assert_eq!(idx, JaggedIndex::new(2, 3));
+    assert_eq!(
+        fragment.collect::<Vec<_>>(),
+        vec![6.to_string(), 7.to_string()]
+    );
+
+    let (idx, _fragment) = puller.pull().expect("");
+    assert_eq!(idx, JaggedIndex::new(3, 0));
+    assert!(puller.pull().is_none());
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([0, 2, N], [1, 2, 4], [1, 3, usize::MAX])]
+fn fragment_puller(n: usize, nt: usize, max_chunk_size: usize) {
+    let arrays = get_jagged(n);
+    let total_len: usize = arrays.iter().map(|x| x.len()).sum();
+    let iter = new_iter(arrays);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.fragment_puller(max_chunk_size);
+                while let Some((begin_idx, _, fragment)) = puller.pull_with_idx() {
+                    assert!((1..=max_chunk_size).contains(&fragment.len()));
+                    for (i, x) in fragment.enumerate() {
+                        assert_eq!(x, (begin_idx + i).to_string());
+                        bag.push(x);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = (0..total_len).map(|x| x.to_string()).collect();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
+
+#[test_matrix([0, 2, N], [1, 3, usize::MAX])]
+fn fragment_puller_partially_consumed(n: usize, max_chunk_size: usize) {
+    let arrays = get_jagged(n);
+    let iter = new_iter(arrays);
+
+    let mut puller = iter.fragment_puller(max_chunk_size);
+    let mut fragments = Vec::new();
+    for _ in 0..3 {
+        if let Some((_, mut fragment)) = puller.pull() {
+            let _ = fragment.next();
+            fragments.push(fragment);
+        }
+    }
+    let _ = iter.next();
+    drop(fragments);
+    let _ = puller.pull();
+}
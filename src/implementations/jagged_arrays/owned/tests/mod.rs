@@ -1,3 +1,5 @@
 mod con_iter;
+mod drain;
+mod fragment_puller;
 mod indexers;
 mod raw_jagged_iter_owned;
@@ -1,7 +1,7 @@
 use super::{con_iter::ConIterJaggedRef, slice_iter::RawJaggedSliceIterRef};
 use crate::{
     ChunkPuller,
-    implementations::jagged_arrays::{JaggedIndexer, Slices},
+    implementations::jagged_arrays::{JaggedIndex, JaggedIndexer, Slices},
 };
 
 pub struct ChunkPullerJaggedRef<'i, 'a, T, S, X>
@@ -26,6 +26,37 @@ where
             chunk_size,
         }
     }
+
+    /// Pulls the next chunk of at most `chunk_size` elements; and returns an iterator yielding each element of
+    /// the chunk together with its [`JaggedIndex`]; i.e., the index `f` of the array containing the element
+    /// and the position `i` of the element within this array.
+    ///
+    /// Returns None if the concurrent iterator is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::implementations::jagged_arrays::JaggedIndex;
+    ///
+    /// let matrix = vec![vec![0, 1], vec![], vec![2, 3]];
+    /// let con_iter = matrix.as_slice().into_flat_con_iter();
+    /// let mut puller = con_iter.chunk_puller(3);
+    ///
+    /// let chunk: Vec<_> = puller.pull_with_jagged_idx().unwrap().map(|(idx, x)| (idx.f, idx.i, *x)).collect();
+    /// assert_eq!(chunk, [(0, 0, 0), (0, 1, 1), (2, 0, 2)]);
+    ///
+    /// let chunk: Vec<_> = puller.pull_with_jagged_idx().unwrap().map(|(idx, x)| (idx.f, idx.i, *x)).collect();
+    /// assert_eq!(chunk, [(2, 1, 3)]);
+    ///
+    /// assert!(puller.pull_with_jagged_idx().is_none());
+    /// ```
+    pub fn pull_with_jagged_idx(
+        &mut self,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, &'a T)> + 'i> {
+        self.con_iter
+            .progress_and_get_iter_with_jagged_idx(self.chunk_size)
+    }
 }
 
 impl<'a, T, S, X> ChunkPuller for ChunkPullerJaggedRef<'_, 'a, T, S, X>
@@ -1,10 +1,13 @@
 use super::{
-    chunk_puller::ChunkPullerJaggedRef, raw_jagged_ref::RawJaggedRef,
-    slice_iter::RawJaggedSliceIterRef,
+    chunk_puller::ChunkPullerJaggedRef, fragment_puller::FragmentPullerJaggedRef,
+    raw_jagged_ref::RawJaggedRef, slice_iter::RawJaggedSliceIterRef,
 };
 use crate::{
-    ConcurrentIter, ExactSizeConcurrentIter,
-    implementations::jagged_arrays::{JaggedIndexer, Slices},
+    ConcurrentIter, ExactSizeConcurrentIter, ResettableConcurrentIter,
+    implementations::jagged_arrays::{
+        JaggedIndex, JaggedIndexer, Slices, fragment::reserve_fragment,
+        with_jagged_idx::WithJaggedIdx,
+    },
 };
 use core::sync::atomic::{AtomicUsize, Ordering};
 
@@ -62,6 +65,97 @@ where
                 (begin_idx, iter)
             })
     }
+
+    pub(super) fn progress_and_get_iter_with_jagged_idx(
+        &self,
+        chunk_size: usize,
+    ) -> Option<impl ExactSizeIterator<Item = (JaggedIndex, &'a T)> + '_> {
+        self.progress_and_get_iter(chunk_size)
+            .and_then(|(begin_idx, iter)| {
+                self.jagged.jagged_index(begin_idx).map(|begin| {
+                    WithJaggedIdx::new(iter, begin, |f| self.jagged.len_of(f).unwrap_or(0))
+                })
+            })
+    }
+
+    /// Returns the next element of the iterator together with its [`JaggedIndex`]; i.e., the index `f` of the
+    /// array containing the element and the position `i` of the element within this array.
+    ///
+    /// Returns None if the iterator is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::implementations::jagged_arrays::JaggedIndex;
+    ///
+    /// let matrix = vec![vec![0, 1], vec![], vec![2]];
+    /// let con_iter = matrix.as_slice().into_flat_con_iter();
+    ///
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(0, 0), &0)));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(0, 1), &1)));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), Some((JaggedIndex::new(2, 0), &2)));
+    /// assert_eq!(con_iter.next_with_jagged_idx(), None);
+    /// ```
+    pub fn next_with_jagged_idx(&self) -> Option<(JaggedIndex, &'a T)> {
+        self.progress_and_get_begin_idx(1)
+            .and_then(|idx| self.jagged.get_with_jagged_index(idx))
+    }
+
+    pub(super) fn progress_and_get_fragment(
+        &self,
+        max_chunk_size: usize,
+    ) -> Option<(usize, JaggedIndex, &'a [T])> {
+        reserve_fragment(
+            &self.counter,
+            self.jagged.len(),
+            max_chunk_size,
+            |i| self.jagged.jagged_index(i),
+            |f| self.jagged.len_of(f),
+        )
+        .and_then(|(begin_idx, idx, len)| {
+            self.jagged
+                .slice(idx.f, idx.i, len)
+                .map(|slice| (begin_idx, idx, slice))
+        })
+    }
+
+    /// Creates a [`FragmentPullerJaggedRef`] which pulls contiguous slices that never span two
+    /// fragments of the jagged array, each with at most `max_chunk_size` elements.
+    ///
+    /// Pass `usize::MAX` as `max_chunk_size` to pull whole fragments, or remaining parts of fragments
+    /// which are partially consumed by other pulls.
+    ///
+    /// The fragment puller shares the progress of this iterator; hence, it can be used together with
+    /// `next` calls and chunk pullers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::implementations::jagged_arrays::*;
+    ///
+    /// let vectors = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7]];
+    /// let jagged = RawJaggedRef::new(vectors.as_slice(), PrefixSumIndexer::from_slices(&vectors.as_slice()), None);
+    /// let con_iter = jagged.into_con_iter();
+    ///
+    /// let mut puller = con_iter.fragment_puller(4);
+    /// assert_eq!(puller.pull(), Some((JaggedIndex::new(0, 0), [0, 1, 2].as_slice())));
+    /// assert_eq!(puller.pull(), Some((JaggedIndex::new(2, 0), [3, 4, 5, 6].as_slice())));
+    /// assert_eq!(puller.pull_with_idx(), Some((7, JaggedIndex::new(2, 4), [7].as_slice())));
+    /// assert_eq!(puller.pull(), None);
+    /// ```
+    pub fn fragment_puller(
+        &self,
+        max_chunk_size: usize,
+    ) -> FragmentPullerJaggedRef<'_, 'a, T, S, X> {
+        assert!(max_chunk_size > 0, "max_chunk_size must be positive");
+        FragmentPullerJaggedRef::new(self, max_chunk_size)
+    }
 }
 
 impl<'a, T, S, X> ConcurrentIter for ConIterJaggedRef<'a, T, S, X>
@@ -122,3 +216,18 @@ where
         self.jagged.len().saturating_sub(num_taken)
     }
 }
+
+impl<'a, T, S, X> ResettableConcurrentIter for ConIterJaggedRef<'a, T, S, X>
+where
+    T: Sync,
+    X: JaggedIndexer + Send + Sync,
+    S: Slices<'a, T> + Send + Sync,
+{
+    fn reset(&mut self) {
+        *self.counter.get_mut() = 0;
+    }
+
+    fn restarted(&self) -> Self {
+        Self::new(self.jagged.clone(), 0)
+    }
+}
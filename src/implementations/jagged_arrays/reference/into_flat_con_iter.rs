@@ -0,0 +1,46 @@
+use super::{con_iter::ConIterJaggedRef, raw_jagged_ref::RawJaggedRef};
+use crate::{IntoFlatConcurrentIter, implementations::jagged_arrays::PrefixSumIndexer};
+use alloc::vec::Vec;
+
+impl<'a, T> IntoFlatConcurrentIter for &'a [Vec<T>]
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterJaggedRef<'a, T, &'a [Vec<T>], PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        let indexer = PrefixSumIndexer::new(self.iter().map(|x| x.len()));
+        let total_len = indexer.total_len();
+        ConIterJaggedRef::new(RawJaggedRef::new(self, indexer, Some(total_len)), 0)
+    }
+}
+
+impl<'a, T> IntoFlatConcurrentIter for &'a Vec<Vec<T>>
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterJaggedRef<'a, T, &'a [Vec<T>], PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        self.as_slice().into_flat_con_iter()
+    }
+}
+
+impl<'a, T> IntoFlatConcurrentIter for &'a [&'a [T]]
+where
+    T: Sync,
+{
+    type Item = &'a T;
+
+    type IntoIter = ConIterJaggedRef<'a, T, &'a [&'a [T]], PrefixSumIndexer>;
+
+    fn into_flat_con_iter(self) -> Self::IntoIter {
+        let indexer = PrefixSumIndexer::new(self.iter().map(|x| x.len()));
+        let total_len = indexer.total_len();
+        ConIterJaggedRef::new(RawJaggedRef::new(self, indexer, Some(total_len)), 0)
+    }
+}
@@ -3,10 +3,13 @@ mod tests;
 
 mod chunk_puller;
 mod con_iter;
+mod fragment_puller;
 mod into_con_iter;
+mod into_flat_con_iter;
 mod raw_jagged_ref;
 mod slice;
 mod slice_iter;
 
 pub use con_iter::ConIterJaggedRef;
+pub use fragment_puller::FragmentPullerJaggedRef;
 pub use raw_jagged_ref::RawJaggedRef;
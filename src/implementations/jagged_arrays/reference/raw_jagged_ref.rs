@@ -58,7 +58,16 @@ where
     S: Slices<'a, T>,
 {
     /// Creates a new raw jagged array of references.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `indexer` is not valid for the lengths of the `arrays`; see
+    /// [`JaggedIndexer::is_valid_for`].
     pub fn new(arrays: S, indexer: X, total_len: Option<usize>) -> Self {
+        assert!(
+            indexer.is_valid_for(arrays.lengths()),
+            "indexer is not valid for the lengths of the arrays"
+        );
         let len = total_len.unwrap_or_else(|| arrays.lengths().sum());
         Self {
             arrays,
@@ -77,7 +86,7 @@ where
     }
 
     pub(super) fn len_of(&self, f: usize) -> Option<usize> {
-        self.arrays.slice_at(f).map(|x| x.as_slice().len())
+        self.arrays.slice_at(f).map(|x| AsSlice::as_slice(&x).len())
     }
 
     /// Returns the [`JaggedIndex`] of the element at the given `flat_index` position of the flattened
@@ -116,7 +125,7 @@ where
 
     pub(super) fn slice(&self, f: usize, begin_within_slice: usize, len: usize) -> Option<&'a [T]> {
         self.arrays.slice_at(f).and_then(|array| {
-            let array = array.as_slice();
+            let array = AsSlice::as_slice(&array);
             (begin_within_slice < array.len()).then(|| {
                 let ptr = unsafe { array.as_ptr().add(begin_within_slice) };
                 unsafe { core::slice::from_raw_parts(ptr, len) }
@@ -142,9 +151,14 @@ where
     }
 
     pub(super) fn get(&self, flat_index: usize) -> Option<&'a T> {
+        self.get_with_jagged_index(flat_index).map(|(_, x)| x)
+    }
+
+    pub(super) fn get_with_jagged_index(&self, flat_index: usize) -> Option<(JaggedIndex, &'a T)> {
         self.jagged_index(flat_index).map(|x| {
             // SAFETY: both f and i are in bounds
-            unsafe { self.arrays.slice_at_unchecked(x.f).get_unchecked(x.i) }
+            let value = unsafe { self.arrays.slice_at_unchecked(x.f).get_unchecked(x.i) };
+            (x, value)
         })
     }
 }
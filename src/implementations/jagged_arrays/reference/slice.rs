@@ -98,10 +98,11 @@ where
                     },
                 };
 
-                let len = end_exc - begin_i;
-                debug_assert!(len > 0);
-
-                self.jagged.slice(f, begin_i, len)
+                // might be empty when the jagged array contains empty arrays
+                match end_exc - begin_i {
+                    0 => Some(&[]),
+                    len => self.jagged.slice(f, begin_i, len),
+                }
             }
             None => None,
         }
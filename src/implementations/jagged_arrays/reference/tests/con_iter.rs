@@ -1,5 +1,5 @@
 use crate::{
-    ChunkPuller,
+    ChunkPuller, ResettableConcurrentIter,
     concurrent_iter::ConcurrentIter,
     exact_size_concurrent_iter::ExactSizeConcurrentIter,
     implementations::jagged_arrays::reference::{
@@ -486,3 +486,85 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix([0, 2, N], [1, 2, 4], [0, 1, 7])]
+fn jagged_idx(n: usize, nt: usize, chunk_size: usize) {
+    let arrays: Vec<Vec<String>> = (0..n)
+        .map(|f| {
+            (0..((f * 7) % 5))
+                .map(|i| alloc::format!("{f}-{i}"))
+                .collect()
+        })
+        .collect();
+    let total_len: usize = arrays.iter().map(|x| x.len()).sum();
+    let indexer =
+        crate::implementations::jagged_arrays::PrefixSumIndexer::from_slices(&arrays.as_slice());
+    let jagged = RawJaggedRef::new(arrays.as_slice(), indexer, None);
+    let iter = ConIterJaggedRef::new(jagged, 0);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk_size {
+                0 => {
+                    while let Some((idx, x)) = iter.next_with_jagged_idx() {
+                        assert_eq!(x, &alloc::format!("{}-{}", idx.f, idx.i));
+                        bag.push(x.clone());
+                    }
+                }
+                _ => {
+                    let mut puller = iter.chunk_puller(chunk_size);
+                    while let Some(chunk) = puller.pull_with_jagged_idx() {
+                        assert!(chunk.len() <= chunk_size);
+                        for (idx, x) in chunk {
+                            assert_eq!(x, &arrays[idx.f][idx.i]);
+                            bag.push(x.clone());
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = arrays.iter().flatten().cloned().collect();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected.len(), total_len);
+    assert_eq!(expected, collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn reset(n: usize, nt: usize) {
+    let matrix = get_matrix(n);
+    let jagged = RawJaggedRef::new(matrix.as_slice(), MatrixIndexer::new(n), Some(n * n));
+    let mut iter = ConIterJaggedRef::new(jagged, 0);
+    let n = n * n;
+
+    for until in [n / 2, n] {
+        let bag = ConcurrentBag::new();
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    let mut puller = iter.chunk_puller(7);
+                    while bag.len() < until {
+                        match puller.pull() {
+                            Some(chunk) => chunk.for_each(|x| _ = bag.push(x)),
+                            None => break,
+                        }
+                    }
+                });
+            }
+        });
+        assert!(bag.len() >= until);
+
+        iter.reset();
+        assert_eq!(iter.len(), n);
+        let restarted = iter.restarted();
+        assert_eq!(restarted.next(), iter.next());
+        iter.reset();
+    }
+
+    let all: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(all, matrix.iter().flatten().collect::<Vec<_>>());
+}
@@ -0,0 +1,106 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::jagged_arrays::{
+        JaggedIndex, PrefixSumIndexer,
+        reference::{con_iter::ConIterJaggedRef, raw_jagged_ref::RawJaggedRef},
+    },
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 11;
+#[cfg(not(miri))]
+const N: usize = 66;
+
+fn get_jagged(n: usize) -> Vec<Vec<String>> {
+    let mut begin = 0;
+    (0..n)
+        .map(|f| {
+            let len = (f * 7) % 5;
+            let array = (begin..(begin + len)).map(|x| x.to_string()).collect();
+            begin += len;
+            array
+        })
+        .collect()
+}
+
+#[test]
+fn fragment_puller_with_next() {
+    let arrays = vec![vec![0, 1, 2], vec![], vec![3, 4, 5, 6, 7], vec![8]];
+    let jagged = RawJaggedRef::new(
+        arrays.as_slice(),
+        PrefixSumIndexer::from_slices(&arrays.as_slice()),
+        None,
+    );
+    let iter = ConIterJaggedRef::new(jagged, 0);
+
+    let mut puller = iter.fragment_puller(2);
+    assert_eq!(puller.max_chunk_size(), 2);
+    assert_eq!(
+        puller.pull(),
+        Some((JaggedIndex::new(0, 0), [0, 1].as_slice()))
+    );
+    assert_eq!(
+        puller.pull(),
+        Some((JaggedIndex::new(0, 2), [2].as_slice()))
+    );
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(
+        puller.pull_with_idx(),
+        Some((4, JaggedIndex::new(2, 1), [4, 5].as_slice()))
+    );
+
+    let mut puller = iter.fragment_puller(usize::MAX);
+    assert_eq!(
+        puller.pull(),
+        Some((JaggedIndex::new(2, 3), [6, 7].as_slice()))
+    );
+    assert_eq!(
+        puller.pull(),
+        Some((JaggedIndex::new(3, 0), [8].as_slice()))
+    );
+    assert_eq!(puller.pull(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([0, 2, N], [1, 2, 4], [1, 3, usize::MAX])]
+fn fragment_puller(n: usize, nt: usize, max_chunk_size: usize) {
+    let arrays = get_jagged(n);
+    let total_len: usize = arrays.iter().map(|x| x.len()).sum();
+    let jagged = RawJaggedRef::new(
+        arrays.as_slice(),
+        PrefixSumIndexer::from_slices(&arrays.as_slice()),
+        None,
+    );
+    let iter = ConIterJaggedRef::new(jagged, 0);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.fragment_puller(max_chunk_size);
+                while let Some((begin_idx, idx, slice)) = puller.pull_with_idx() {
+                    assert!((1..=max_chunk_size).contains(&slice.len()));
+                    let fragment = &arrays[idx.f][idx.i..(idx.i + slice.len())];
+                    assert_eq!(fragment.as_ptr(), slice.as_ptr());
+                    for (i, x) in slice.iter().enumerate() {
+                        assert_eq!(x, &(begin_idx + i).to_string());
+                        bag.push(x.clone());
+                    }
+                }
+            });
+        }
+    });
+
+    let mut expected: Vec<_> = (0..total_len).map(|x| x.to_string()).collect();
+    expected.sort();
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected, collected);
+}
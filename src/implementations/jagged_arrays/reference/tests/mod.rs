@@ -1,3 +1,4 @@
 mod con_iter;
+mod fragment_puller;
 mod indexers;
 mod raw_jagged_iter_ref;
@@ -54,3 +54,29 @@ impl<'a, T: 'a> Slices<'a, T> for &'a [Vec<T>] {
         self[f].as_slice()
     }
 }
+
+impl<'a, T: 'a> Slices<'a, T> for &'a [&'a [T]] {
+    fn empty() -> Self {
+        Default::default()
+    }
+
+    fn num_slices(&self) -> usize {
+        self.len()
+    }
+
+    fn slices(&self) -> impl Iterator<Item = &'a [T]> {
+        self.iter().copied()
+    }
+
+    fn lengths(&self) -> impl Iterator<Item = usize> {
+        self.iter().map(|x| x.len())
+    }
+
+    fn slice_at(&self, f: usize) -> Option<&'a [T]> {
+        self.get(f).copied()
+    }
+
+    unsafe fn slice_at_unchecked(&self, f: usize) -> &'a [T] {
+        self[f]
+    }
+}
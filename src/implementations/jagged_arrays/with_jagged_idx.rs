@@ -0,0 +1,66 @@
+use super::JaggedIndex;
+
+/// Wraps an iterator over a contiguous range of elements of a flattened jagged array starting at
+/// jagged index `begin`, and yields each element together with its [`JaggedIndex`].
+///
+/// `len_of` returns the length of the `f`-th array of the jagged array; it is used to move on to the
+/// next non-empty array once all elements of the current array are visited.
+pub(super) struct WithJaggedIdx<I, L>
+where
+    I: ExactSizeIterator,
+    L: Fn(usize) -> usize,
+{
+    iter: I,
+    idx: JaggedIndex,
+    fragment_len: usize,
+    len_of: L,
+}
+
+impl<I, L> WithJaggedIdx<I, L>
+where
+    I: ExactSizeIterator,
+    L: Fn(usize) -> usize,
+{
+    pub(super) fn new(iter: I, begin: JaggedIndex, len_of: L) -> Self {
+        let fragment_len = len_of(begin.f);
+        Self {
+            iter,
+            idx: begin,
+            fragment_len,
+            len_of,
+        }
+    }
+}
+
+impl<I, L> Iterator for WithJaggedIdx<I, L>
+where
+    I: ExactSizeIterator,
+    L: Fn(usize) -> usize,
+{
+    type Item = (JaggedIndex, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        while self.idx.i >= self.fragment_len {
+            self.idx = JaggedIndex::new(self.idx.f + 1, 0);
+            self.fragment_len = (self.len_of)(self.idx.f);
+        }
+        let idx = self.idx.clone();
+        self.idx.i += 1;
+        Some((idx, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, L> ExactSizeIterator for WithJaggedIdx<I, L>
+where
+    I: ExactSizeIterator,
+    L: Fn(usize) -> usize,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -1,19 +1,56 @@
+mod arc_slice;
+mod array;
 mod array_utils;
+mod boxed_slice;
+mod btree;
 mod empty;
+mod from_fn;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
 mod iter;
 /// Generic implementations of jagged arrays or slice of slices, etc.
 pub mod jagged_arrays;
+mod nd_range;
 mod ptr_utils;
+mod queue;
 mod range;
+#[cfg(feature = "std")]
+mod receiver;
+mod repeat;
+mod shuffled;
 mod slice;
 mod slice_mut;
+mod slice_views;
+mod str;
 mod vec;
 mod vec_deque;
 mod vec_drain;
+mod vec_retain;
+mod worklist;
 
+pub use arc_slice::{ArcSliceIntoSeqIter, ConIterArcSlice};
+pub use array::{ArrayIntoIter, ConIterArray};
+pub use btree::{ConIterBTree, ConIterBTreeRef, OrderedCollection};
 pub use empty::ConIterEmpty;
+pub use from_fn::{ConIterFromFn, FromFnChunk};
+#[cfg(feature = "hashbrown")]
+pub use hashbrown::{ConIterHashTable, ConIterHashTableRef, HashTable, HashTableIntoSeqIter};
 pub use iter::ConIterOfIter;
-pub use range::ConIterRange;
+pub use nd_range::{ChunkPullerNdRange, ConIterGrid, ConIterNdRange, NdRangeChunk};
+pub use queue::{ConIterQueue, QueueIntoSeqIter, QueueNext};
+pub use range::{ConIterRange, RangeElement, RangeIter};
+#[cfg(feature = "std")]
+pub use receiver::ConIterReceiver;
+pub use repeat::{ConIterRepeat, ConIterRepeatN, RepeatChunk};
+pub use shuffled::{ConIterShuffled, Permutation, ShuffleSource, ShuffledIter};
 pub use slice::ConIterSlice;
-pub use vec::ConIterVec;
-pub use vec_deque::ConIterVecDequeRef;
+pub use slice_views::{
+    ByChunks, ByChunksExact, ByRChunks, ByWindows, ConIterSliceChunksMut, ConIterSliceViews,
+    SliceChunksMutIter, SliceViews, SliceViewsIter,
+};
+pub use str::{ByChars, ByLines, BySeparator, ConIterStr, StrChunk, StrSegments};
+pub use vec::{ConIterVec, ConIterVecPart, VecAllocation};
+pub use vec_deque::{ConIterVecDequeDrain, ConIterVecDequeMut, ConIterVecDequeRef};
+pub use vec_drain::ConIterVecDrain;
+pub use vec_retain::{ConIterVecRetain, RetainChunk, VecRetainSeqIter};
+pub use worklist::{ConIterWorklist, WorkItem};
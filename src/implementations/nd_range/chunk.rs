@@ -0,0 +1,72 @@
+use super::grid::NdGrid;
+use core::iter::FusedIterator;
+
+/// An exact-size iterator over the coordinates of a contiguous run of elements of a
+/// [`ConIterNdRange`] in its iteration order.
+///
+/// It is the type of the chunks pulled from the concurrent iterator and the sequential iterator
+/// that it is converted into.
+///
+/// [`ConIterNdRange`]: crate::implementations::ConIterNdRange
+pub struct NdRangeChunk<const D: usize> {
+    grid: NdGrid<D>,
+    coords: [usize; D],
+    next_flat_idx: usize,
+    len: usize,
+}
+
+impl<const D: usize> Default for NdRangeChunk<D> {
+    fn default() -> Self {
+        Self {
+            grid: NdGrid::empty(),
+            coords: [0; D],
+            next_flat_idx: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const D: usize> NdRangeChunk<D> {
+    pub(super) fn new(grid: NdGrid<D>, begin_idx: usize, len: usize) -> Self {
+        match len {
+            0 => Default::default(),
+            _ => Self {
+                grid,
+                coords: grid.coords_of(begin_idx),
+                next_flat_idx: begin_idx,
+                len,
+            },
+        }
+    }
+}
+
+impl<const D: usize> Iterator for NdRangeChunk<D> {
+    type Item = [usize; D];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => {
+                let value = self.coords;
+                self.len -= 1;
+                self.next_flat_idx += 1;
+                if self.len > 0 && !self.grid.increment_within_tile(&mut self.coords) {
+                    self.coords = self.grid.coords_of(self.next_flat_idx);
+                }
+                Some(value)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<const D: usize> ExactSizeIterator for NdRangeChunk<D> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<const D: usize> FusedIterator for NdRangeChunk<D> {}
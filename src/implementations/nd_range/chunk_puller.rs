@@ -0,0 +1,46 @@
+use super::{chunk::NdRangeChunk, con_iter::ConIterNdRange};
+use crate::pullers::ChunkPuller;
+
+/// Chunk puller of a [`ConIterNdRange`].
+///
+/// Pulled chunks never span multiple tiles of a tiled iterator; hence, when the chunk size
+/// is equal to the volume of a tile, as in the puller created by [`tile_puller`], each pull
+/// returns a complete rectangular tile, unless the tile is partially pulled by other means.
+///
+/// [`tile_puller`]: ConIterNdRange::tile_puller
+pub struct ChunkPullerNdRange<'i, const D: usize> {
+    con_iter: &'i ConIterNdRange<D>,
+    chunk_size: usize,
+}
+
+impl<'i, const D: usize> ChunkPullerNdRange<'i, D> {
+    pub(super) fn new(con_iter: &'i ConIterNdRange<D>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<const D: usize> ChunkPuller for ChunkPullerNdRange<'_, D> {
+    type ChunkItem = [usize; D];
+
+    type Chunk<'c>
+        = NdRangeChunk<D>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
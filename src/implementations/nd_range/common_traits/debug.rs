@@ -0,0 +1,16 @@
+use crate::{ExactSizeConcurrentIter, implementations::ConIterNdRange};
+use core::fmt::Debug;
+
+impl<const D: usize> Debug for ConIterNdRange<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterNdRange")
+            .field("shape", &self.shape())
+            .field("tile_shape", &self.tile_shape())
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
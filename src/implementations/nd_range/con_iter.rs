@@ -0,0 +1,207 @@
+use super::{chunk::NdRangeChunk, chunk_puller::ChunkPullerNdRange, grid::NdGrid};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator of a two-dimensional index space yielding `[row, col]` coordinates.
+pub type ConIterGrid = ConIterNdRange<2>;
+
+/// Concurrent iterator of a `D`-dimensional index space yielding `[usize; D]` coordinates.
+///
+/// * An iterator created by [`new`] yields the coordinates in row-major order; i.e., the last
+///   dimension changes the fastest. Pulled chunks are row-major runs of coordinates.
+/// * An iterator created by [`tiled`] splits the index space into rectangular tiles and yields the
+///   coordinates tile by tile; tiles are ordered row-major, and so are the coordinates within
+///   each tile. Pulled chunks never span multiple tiles; and the [`tile_puller`] pulls complete
+///   tiles, which is useful for cache locality of stencil computations.
+///
+/// The index of a coordinate, as returned by `next_with_idx` or `pull_with_idx`, is its position in
+/// the iteration order.
+///
+/// Similar to [`ConIterRange`], progress is tracked by a single atomic counter.
+///
+/// [`new`]: ConIterNdRange::new
+/// [`tiled`]: ConIterNdRange::tiled
+/// [`tile_puller`]: ConIterNdRange::tile_puller
+/// [`ConIterRange`]: crate::implementations::ConIterRange
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::{ConIterGrid, ConIterNdRange};
+///
+/// let con_iter = ConIterGrid::new([2, 3]);
+/// assert_eq!(con_iter.next(), Some([0, 0]));
+/// assert_eq!(con_iter.next(), Some([0, 1]));
+/// assert_eq!(con_iter.next(), Some([0, 2]));
+/// assert_eq!(con_iter.next(), Some([1, 0]));
+///
+/// // 4x4 grid in 2x2 tiles
+/// let con_iter = ConIterGrid::tiled([4, 4], [2, 2]);
+/// let mut puller = con_iter.tile_puller();
+/// let tile: Vec<_> = puller.pull().unwrap().collect();
+/// assert_eq!(tile, [[0, 0], [0, 1], [1, 0], [1, 1]]);
+/// let tile: Vec<_> = puller.pull().unwrap().collect();
+/// assert_eq!(tile, [[0, 2], [0, 3], [1, 2], [1, 3]]);
+///
+/// // 3-dimensional index space
+/// let con_iter = ConIterNdRange::new([2, 2, 2]);
+/// assert_eq!(con_iter.len(), 8);
+/// assert_eq!(con_iter.next(), Some([0, 0, 0]));
+/// assert_eq!(con_iter.next(), Some([0, 0, 1]));
+/// ```
+pub struct ConIterNdRange<const D: usize> {
+    grid: NdGrid<D>,
+    tiled: bool,
+    len: usize,
+    counter: AtomicUsize,
+}
+
+impl<const D: usize> Default for ConIterNdRange<D> {
+    fn default() -> Self {
+        Self::new([0; D])
+    }
+}
+
+impl<const D: usize> ConIterNdRange<D> {
+    /// Creates a concurrent iterator over the index space with the given `shape`, yielding the
+    /// coordinates in row-major order.
+    pub fn new(shape: [usize; D]) -> Self {
+        let grid = NdGrid::new(shape, shape);
+        Self {
+            grid,
+            tiled: false,
+            len: grid.volume(),
+            counter: 0.into(),
+        }
+    }
+
+    /// Creates a concurrent iterator over the index space with the given `shape` which is split into
+    /// tiles of the `tile` shape; tiles at the upper edges might be partial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any dimension of the `tile` is zero.
+    pub fn tiled(shape: [usize; D], tile: [usize; D]) -> Self {
+        assert!(
+            tile.iter().all(|x| *x > 0),
+            "all dimensions of the tile must be positive"
+        );
+        let grid = NdGrid::new(shape, tile);
+        Self {
+            grid,
+            tiled: true,
+            len: grid.volume(),
+            counter: 0.into(),
+        }
+    }
+
+    /// Shape of the index space.
+    pub fn shape(&self) -> [usize; D] {
+        self.grid.shape()
+    }
+
+    /// Shape of the tiles; which is equal to the shape of the index space if the iterator is not tiled.
+    pub fn tile_shape(&self) -> [usize; D] {
+        self.grid.tile()
+    }
+
+    /// Creates a chunk puller which pulls one complete tile at a time.
+    ///
+    /// If the iterator is not tiled, the entire index space is a single tile.
+    pub fn tile_puller(&self) -> ChunkPullerNdRange<'_, D> {
+        let tile_volume = self.grid.tile().iter().product();
+        ChunkPullerNdRange::new(self, tile_volume)
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    fn progress_and_get_range(&self, chunk_size: usize) -> Option<Range<usize>> {
+        match self.tiled && chunk_size > 1 {
+            false => {
+                let begin_idx = self.counter.fetch_add(chunk_size, Ordering::Relaxed);
+                (begin_idx < self.len).then(|| begin_idx..(begin_idx + chunk_size).min(self.len))
+            }
+            true => {
+                let mut begin_idx = self.counter.load(Ordering::Acquire);
+                loop {
+                    if begin_idx >= self.len {
+                        return None;
+                    }
+                    let end_idx = (begin_idx + chunk_size).min(self.grid.tile_end_of(begin_idx));
+                    match self.counter.compare_exchange_weak(
+                        begin_idx,
+                        end_idx,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    ) {
+                        Ok(_) => return Some(begin_idx..end_idx),
+                        Err(updated) => begin_idx = updated,
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, NdRangeChunk<D>)> {
+        self.progress_and_get_range(chunk_size.max(1)).map(|range| {
+            let chunk = NdRangeChunk::new(self.grid, range.start, range.len());
+            (range.start, chunk)
+        })
+    }
+}
+
+impl<const D: usize> ConcurrentIter for ConIterNdRange<D> {
+    type Item = [usize; D];
+
+    type SequentialIter = NdRangeChunk<D>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerNdRange<'i, D>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let current = self.counter.load(Ordering::Acquire).min(self.len);
+        NdRangeChunk::new(self.grid, current, self.len - current)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.len, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_range(1)
+            .map(|range| self.grid.coords_of(range.start))
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_range(1)
+            .map(|range| (range.start, self.grid.coords_of(range.start)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.len.saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<const D: usize> ExactSizeConcurrentIter for ConIterNdRange<D> {
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.len.saturating_sub(num_taken)
+    }
+}
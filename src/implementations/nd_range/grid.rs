@@ -0,0 +1,101 @@
+/// Layout of a `D`-dimensional index space with the given `shape` which is split into tiles of the
+/// given `tile` shape.
+///
+/// Elements are ordered tile-major: tiles are ordered row-major over the grid of tiles, and
+/// elements within each tile are ordered row-major. Tiles at the upper edges of the index space
+/// might be partial. When the tile shape is equal to the shape of the index space, the order is
+/// simply row-major.
+#[derive(Clone, Copy)]
+pub(super) struct NdGrid<const D: usize> {
+    shape: [usize; D],
+    tile: [usize; D],
+}
+
+impl<const D: usize> NdGrid<D> {
+    pub(super) fn new(shape: [usize; D], tile: [usize; D]) -> Self {
+        Self { shape, tile }
+    }
+
+    pub(super) fn empty() -> Self {
+        Self::new([0; D], [0; D])
+    }
+
+    pub(super) fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    pub(super) fn tile(&self) -> [usize; D] {
+        self.tile
+    }
+
+    /// Total number of elements of the index space.
+    pub(super) fn volume(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Extent of the tile at tile coordinate `tc` along dimension `d`; smaller than the tile shape
+    /// for the partial tiles at the edges.
+    fn extent(&self, d: usize, tc: usize) -> usize {
+        self.tile[d].min(self.shape[d] - tc * self.tile[d])
+    }
+
+    /// Returns the tile coordinates and extents of the tile containing the element at position
+    /// `flat_idx` of the tile-major order, together with the offset of the element within the tile.
+    ///
+    /// `flat_idx` must be less than the volume.
+    fn locate(&self, flat_idx: usize) -> ([usize; D], [usize; D], usize) {
+        let mut rem = flat_idx;
+        let mut tile_coords = [0; D];
+        let mut extents = [0; D];
+        let mut volume_of_earlier_extents = 1;
+        for d in 0..D {
+            let volume_of_later_dims: usize = self.shape[d + 1..].iter().product();
+            let slab = volume_of_earlier_extents * self.tile[d] * volume_of_later_dims;
+            tile_coords[d] = rem / slab;
+            rem -= tile_coords[d] * slab;
+            extents[d] = self.extent(d, tile_coords[d]);
+            volume_of_earlier_extents *= extents[d];
+        }
+        (tile_coords, extents, rem)
+    }
+
+    /// Returns the coordinates of the element at position `flat_idx` of the tile-major order.
+    ///
+    /// `flat_idx` must be less than the volume.
+    pub(super) fn coords_of(&self, flat_idx: usize) -> [usize; D] {
+        let (tile_coords, extents, mut offset) = self.locate(flat_idx);
+        let mut coords = [0; D];
+        for d in (0..D).rev() {
+            coords[d] = tile_coords[d] * self.tile[d] + offset % extents[d];
+            offset /= extents[d];
+        }
+        coords
+    }
+
+    /// Returns the exclusive end, in the tile-major order, of the tile containing the element at
+    /// position `flat_idx`.
+    ///
+    /// `flat_idx` must be less than the volume.
+    pub(super) fn tile_end_of(&self, flat_idx: usize) -> usize {
+        let (_, extents, offset) = self.locate(flat_idx);
+        let tile_volume: usize = extents.iter().product();
+        flat_idx - offset + tile_volume
+    }
+
+    /// Moves the `coords` to the next element within the same tile; returns false if the coordinates
+    /// are at the last element of the tile, in which case `coords` is left in an arbitrary state.
+    pub(super) fn increment_within_tile(&self, coords: &mut [usize; D]) -> bool {
+        for d in (0..D).rev() {
+            let tile_begin = (coords[d] / self.tile[d]) * self.tile[d];
+            let tile_end = (tile_begin + self.tile[d]).min(self.shape[d]);
+            match coords[d] + 1 < tile_end {
+                true => {
+                    coords[d] += 1;
+                    return true;
+                }
+                false => coords[d] = tile_begin,
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk;
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod grid;
+
+pub use chunk::NdRangeChunk;
+pub use chunk_puller::ChunkPullerNdRange;
+pub use con_iter::{ConIterGrid, ConIterNdRange};
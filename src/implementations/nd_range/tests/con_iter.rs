@@ -0,0 +1,209 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::{ConIterGrid, ConIterNdRange},
+    pullers::ChunkPuller,
+};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+/// Reference tile-major order of a two-dimensional grid.
+fn tile_major_2d(shape: [usize; 2], tile: [usize; 2]) -> Vec<[usize; 2]> {
+    let mut order = Vec::new();
+    for ti in (0..shape[0]).step_by(tile[0]) {
+        for tj in (0..shape[1]).step_by(tile[1]) {
+            for i in ti..(ti + tile[0]).min(shape[0]) {
+                for j in tj..(tj + tile[1]).min(shape[1]) {
+                    order.push([i, j]);
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Reference tile-major order of a three-dimensional grid.
+fn tile_major_3d(shape: [usize; 3], tile: [usize; 3]) -> Vec<[usize; 3]> {
+    let mut order = Vec::new();
+    for ti in (0..shape[0]).step_by(tile[0]) {
+        for tj in (0..shape[1]).step_by(tile[1]) {
+            for tk in (0..shape[2]).step_by(tile[2]) {
+                for i in ti..(ti + tile[0]).min(shape[0]) {
+                    for j in tj..(tj + tile[1]).min(shape[1]) {
+                        for k in tk..(tk + tile[2]).min(shape[2]) {
+                            order.push([i, j, k]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+fn collect_with_idx<const D: usize>(
+    iter: &ConIterNdRange<D>,
+    nt: usize,
+    chunk_size: usize,
+) -> Vec<(usize, [usize; D])> {
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        bag.push((begin_idx + i, x));
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    collected
+}
+
+#[test]
+fn row_major() {
+    let iter = ConIterGrid::new([2, 3]);
+    assert_eq!(iter.len(), 6);
+
+    assert_eq!(iter.next(), Some([0, 0]));
+    assert_eq!(iter.next_with_idx(), Some((1, [0, 1])));
+
+    let mut puller = iter.chunk_puller(3);
+    let (begin_idx, chunk) = puller.pull_with_idx().expect("");
+    assert_eq!(begin_idx, 2);
+    assert_eq!(chunk.collect::<Vec<_>>(), [[0, 2], [1, 0], [1, 1]]);
+
+    assert_eq!(iter.next(), Some([1, 2]));
+    assert_eq!(iter.next(), None);
+    assert!(puller.pull().is_none());
+}
+
+#[test]
+fn empty() {
+    let iter = ConIterGrid::new([0, 5]);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert!(iter.tile_puller().pull().is_none());
+
+    let iter = ConIterNdRange::tiled([5, 0, 3], [2, 2, 2]);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_seq_iter().len(), 0);
+}
+
+#[test_matrix([[1, 1], [7, 5], [16, 16], [33, 20]], [[1, 1], [2, 3], [4, 4], [64, 64]])]
+fn tiled_sequential(shape: [usize; 2], tile: [usize; 2]) {
+    let expected = tile_major_2d(shape, tile);
+
+    let iter = ConIterGrid::tiled(shape, tile);
+    assert_eq!(iter.len(), expected.len());
+    let mut collected = Vec::new();
+    while let Some(x) = iter.next_with_idx() {
+        collected.push(x);
+    }
+    assert_eq!(
+        collected,
+        expected.iter().copied().enumerate().collect::<Vec<_>>()
+    );
+
+    let iter = ConIterGrid::tiled(shape, tile);
+    let seq: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(seq, expected);
+}
+
+#[test_matrix(
+    [[7, 5], [33, 20]],
+    [[2, 3], [4, 4]],
+    [1, 2, 4],
+    [1, 5, 16, 100]
+)]
+fn tiled_concurrent(shape: [usize; 2], tile: [usize; 2], nt: usize, chunk_size: usize) {
+    let expected = tile_major_2d(shape, tile);
+    let iter = ConIterGrid::tiled(shape, tile);
+    let collected = collect_with_idx(&iter, nt, chunk_size);
+    assert_eq!(
+        collected,
+        expected.into_iter().enumerate().collect::<Vec<_>>()
+    );
+}
+
+#[test_matrix([[7, 5, 3], [10, 10, 10]], [[7, 5, 3], [2, 3, 2], [4, 1, 4]], [1, 4], [1, 7])]
+fn three_dimensional(shape: [usize; 3], tile: [usize; 3], nt: usize, chunk_size: usize) {
+    let expected = tile_major_3d(shape, tile);
+    let iter = ConIterNdRange::tiled(shape, tile);
+    let collected = collect_with_idx(&iter, nt, chunk_size);
+    assert_eq!(
+        collected,
+        expected.into_iter().enumerate().collect::<Vec<_>>()
+    );
+
+    let expected = tile_major_3d(shape, shape);
+    let iter = ConIterNdRange::new(shape);
+    let collected = collect_with_idx(&iter, nt, chunk_size);
+    assert_eq!(
+        collected,
+        expected.into_iter().enumerate().collect::<Vec<_>>()
+    );
+}
+
+#[test_matrix([[7, 5], [33, 20]], [[2, 3], [4, 4]], [1, 2, 4])]
+fn tile_puller(shape: [usize; 2], tile: [usize; 2], nt: usize) {
+    let iter = ConIterGrid::tiled(shape, tile);
+
+    let tiles = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.tile_puller();
+                assert_eq!(puller.chunk_size(), tile[0] * tile[1]);
+                while let Some(chunk) = puller.pull() {
+                    tiles.push(chunk.collect::<Vec<_>>());
+                }
+            });
+        }
+    });
+
+    let tiles = tiles.into_inner().to_vec();
+    let num_tiles = shape[0].div_ceil(tile[0]) * shape[1].div_ceil(tile[1]);
+    assert_eq!(tiles.len(), num_tiles);
+
+    let mut all = Vec::new();
+    for coords in tiles {
+        // each tile is a complete rectangle aligned to the tile grid
+        let [i0, j0] = coords[0];
+        assert_eq!([i0 % tile[0], j0 % tile[1]], [0, 0]);
+        let [rows, cols] = [tile[0].min(shape[0] - i0), tile[1].min(shape[1] - j0)];
+        let rectangle: Vec<_> = (i0..i0 + rows)
+            .flat_map(|i| (j0..j0 + cols).map(move |j| [i, j]))
+            .collect();
+        assert_eq!(coords, rectangle);
+        all.extend(coords);
+    }
+
+    all.sort();
+    let mut expected = tile_major_2d(shape, tile);
+    expected.sort();
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn size_hint_skip_to_end() {
+    let iter = ConIterGrid::tiled([10, 10], [3, 3]);
+    assert_eq!(iter.size_hint(), (100, Some(100)));
+
+    let _ = iter.tile_puller().pull();
+    assert_eq!(iter.len(), 91);
+
+    let _ = iter.chunk_puller(100).pull();
+    assert_eq!(iter.len(), 82);
+
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
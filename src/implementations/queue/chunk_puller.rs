@@ -0,0 +1,120 @@
+use super::con_iter::ConIterQueue;
+use crate::pullers::ChunkPuller;
+use core::iter::FusedIterator;
+
+pub struct ChunkPullerQueue<'i, T>
+where
+    T: Send,
+{
+    con_iter: &'i ConIterQueue<T>,
+    chunk_size: usize,
+}
+
+impl<'i, T> ChunkPullerQueue<'i, T>
+where
+    T: Send,
+{
+    pub(super) fn new(con_iter: &'i ConIterQueue<T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'i, T> ChunkPuller for ChunkPullerQueue<'i, T>
+where
+    T: Send,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = QueueChunk<'i, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .reserve_range_or_wait(self.chunk_size)
+            .map(|range| QueueChunk::new(self.con_iter, range.start, range.end))
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter
+            .reserve_range_or_wait(self.chunk_size)
+            .map(|range| {
+                let chunk = QueueChunk::new(self.con_iter, range.start, range.end);
+                (range.start, chunk)
+            })
+    }
+}
+
+// iter
+
+/// A chunk of positions of the queue reserved by a single consumer.
+///
+/// Elements which are not consumed by the time the chunk is dropped are dropped together with the chunk.
+pub struct QueueChunk<'i, T> {
+    con_iter: Option<&'i ConIterQueue<T>>,
+    current: usize,
+    end: usize,
+}
+
+impl<T> Default for QueueChunk<'_, T> {
+    fn default() -> Self {
+        Self {
+            con_iter: None,
+            current: 0,
+            end: 0,
+        }
+    }
+}
+
+impl<'i, T> QueueChunk<'i, T> {
+    fn new(con_iter: &'i ConIterQueue<T>, begin: usize, end: usize) -> Self {
+        Self {
+            con_iter: Some(con_iter),
+            current: begin,
+            end,
+        }
+    }
+}
+
+impl<T> Drop for QueueChunk<'_, T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<T> Iterator for QueueChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.con_iter, self.current < self.end) {
+            (Some(con_iter), true) => {
+                let idx = self.current;
+                self.current += 1;
+                // SAFETY: positions within current..end are reserved for this chunk
+                Some(unsafe { con_iter.take(idx) })
+            }
+            _ => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for QueueChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.end - self.current
+    }
+}
+
+impl<T> FusedIterator for QueueChunk<'_, T> {}
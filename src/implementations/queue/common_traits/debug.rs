@@ -0,0 +1,15 @@
+use crate::implementations::ConIterQueue;
+use core::fmt::Debug;
+
+impl<T> Debug for ConIterQueue<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_pushed = self.num_pushed();
+        let remaining = self.num_remaining();
+        f.debug_struct("ConIterQueue")
+            .field("num_pushed", &num_pushed)
+            .field("num_taken", &num_pushed.saturating_sub(remaining))
+            .field("remaining", &remaining)
+            .field("is_closed", &self.is_closed())
+            .finish()
+    }
+}
@@ -0,0 +1,341 @@
+use super::{
+    chunk_puller::ChunkPullerQueue, fragments::Fragments, into_seq_iter::QueueIntoSeqIter,
+    queue_next::QueueNext, waiter::Waiter,
+};
+use crate::concurrent_iter::ConcurrentIter;
+use core::{
+    marker::PhantomData,
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The highest bit of the number of pushed elements represents whether or not the queue is closed.
+const CLOSED: usize = 1 << (usize::BITS - 1);
+
+/// Result of an attempt to reserve a range of positions to pull.
+pub(super) enum Reservation {
+    Range(Range<usize>),
+    Pending,
+    Closed,
+}
+
+/// A concurrent iterator backed by a concurrent queue which can be extended while it is being iterated.
+///
+/// Producers push elements to the queue by [`push`] or [`extend`] through a shared reference,
+/// while the consumers concurrently pull elements through the [`ConcurrentIter`] api.
+///
+/// Since the source is not fixed, the queue might be temporarily empty while it is not yet completed:
+///
+/// * [`next_available`] never waits and distinguishes a temporarily empty queue ([`QueueNext::Pending`])
+///   from a closed and drained queue ([`QueueNext::Closed`]).
+/// * [`next`], item pullers and chunk pullers wait for new elements while the queue is empty, and return
+///   `None` only once the queue is [`close`]d and all of its elements are pulled. Chunk pullers do not wait
+///   for a complete chunk, they return whatever is available up to the chunk size.
+///
+/// Pushing and pulling elements are lock-free. The elements are stored in lazily allocated fragments
+/// with doubling capacities; therefore, pushing never moves already pushed elements.
+///
+/// Waiting consumers busy-wait by default. When the `std` feature is enabled, waiting consumers block
+/// on a condition variable and are woken up by the producers.
+///
+/// [`push`]: ConIterQueue::push
+/// [`extend`]: ConIterQueue::extend
+/// [`close`]: ConIterQueue::close
+/// [`next_available`]: ConIterQueue::next_available
+/// [`next`]: crate::ConcurrentIter::next
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::ConIterQueue;
+///
+/// let queue = ConIterQueue::new();
+///
+/// let sum: usize = std::thread::scope(|s| {
+///     let consumers: Vec<_> = (0..2)
+///         .map(|_| s.spawn(|| queue.item_puller().sum::<usize>()))
+///         .collect();
+///
+///     s.spawn(|| {
+///         for i in 0..100 {
+///             queue.push(i).unwrap();
+///         }
+///         queue.close();
+///     });
+///
+///     consumers.into_iter().map(|x| x.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(sum, (0..100).sum());
+/// ```
+pub struct ConIterQueue<T> {
+    fragments: Fragments<T>,
+    num_pushed: AtomicUsize,
+    counter: AtomicUsize,
+    waiter: Waiter,
+    phantom: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Sync for ConIterQueue<T> {}
+
+impl<T> Default for ConIterQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ConIterQueue<T> {
+    fn drop(&mut self) {
+        let num_pushed = *self.num_pushed.get_mut() & !CLOSED;
+        let num_taken = (*self.counter.get_mut()).min(num_pushed);
+        for idx in num_taken..num_pushed {
+            if let Some(slot) = self.fragments.slot_if_allocated(idx)
+                && slot.is_written()
+            {
+                // SAFETY: the element is written and not taken out
+                drop(unsafe { slot.take() });
+            }
+        }
+    }
+}
+
+impl<T> ConIterQueue<T> {
+    /// Creates a new empty queue which is open to new elements.
+    pub fn new() -> Self {
+        Self {
+            fragments: Default::default(),
+            num_pushed: 0.into(),
+            counter: 0.into(),
+            waiter: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Pushes the `value` to the end of the queue.
+    ///
+    /// Returns back the value as the error if the queue is already [`close`]d.
+    ///
+    /// [`close`]: Self::close
+    pub fn push(&self, value: T) -> Result<(), T> {
+        match self.reserve_position() {
+            Some(idx) => {
+                // SAFETY: idx is reserved only for this push
+                unsafe { self.fragments.slot(idx).write(value) };
+                self.waiter.notify();
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Pushes all elements of `values` to the end of the queue.
+    ///
+    /// Stops early and returns back the first value that could not be pushed as the error
+    /// if the queue is [`close`]d while extending.
+    ///
+    /// [`close`]: Self::close
+    pub fn extend<I>(&self, values: I) -> Result<(), T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in values {
+            self.push(value)?;
+        }
+        Ok(())
+    }
+
+    /// Closes the queue:
+    ///
+    /// * no more elements can be pushed once closed,
+    /// * the consumers can still pull all elements that are pushed before closing,
+    /// * once all elements are pulled, the concurrent iterator is completed and waiting
+    ///   consumers return `None`.
+    pub fn close(&self) {
+        self.num_pushed.fetch_or(CLOSED, Ordering::SeqCst);
+        self.waiter.notify();
+    }
+
+    /// Returns whether or not the queue is closed.
+    pub fn is_closed(&self) -> bool {
+        self.num_pushed.load(Ordering::SeqCst) & CLOSED == CLOSED
+    }
+
+    /// Returns the total number of elements pushed to the queue so far, including the ones already pulled.
+    pub fn num_pushed(&self) -> usize {
+        self.num_pushed.load(Ordering::Acquire) & !CLOSED
+    }
+
+    /// Returns the number of elements which are pushed but not yet pulled.
+    pub fn num_remaining(&self) -> usize {
+        self.num_pushed()
+            .saturating_sub(self.counter.load(Ordering::Acquire))
+    }
+
+    /// Pulls the next element if available, without waiting:
+    ///
+    /// * returns [`QueueNext::Item`] with the next element if there exists one,
+    /// * returns [`QueueNext::Pending`] if the queue is empty at the moment but not yet closed,
+    /// * returns [`QueueNext::Closed`] if the queue is closed and all elements are already pulled.
+    pub fn next_available(&self) -> QueueNext<T> {
+        match self.reserve_range(1) {
+            // SAFETY: position is reserved only for this consumer
+            Reservation::Range(range) => QueueNext::Item(unsafe { self.take(range.start) }),
+            Reservation::Pending => QueueNext::Pending,
+            Reservation::Closed => QueueNext::Closed,
+        }
+    }
+
+    /// Pulls the next element together with its index if available, without waiting.
+    ///
+    /// See [`next_available`] for details.
+    ///
+    /// [`next_available`]: Self::next_available
+    pub fn next_available_with_idx(&self) -> QueueNext<(usize, T)> {
+        match self.reserve_range(1) {
+            // SAFETY: position is reserved only for this consumer
+            Reservation::Range(range) => {
+                QueueNext::Item((range.start, unsafe { self.take(range.start) }))
+            }
+            Reservation::Pending => QueueNext::Pending,
+            Reservation::Closed => QueueNext::Closed,
+        }
+    }
+
+    fn reserve_position(&self) -> Option<usize> {
+        let mut current = self.num_pushed.load(Ordering::Relaxed);
+        loop {
+            match current & CLOSED == CLOSED {
+                true => return None,
+                false => match self.num_pushed.compare_exchange_weak(
+                    current,
+                    current + 1,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return Some(current),
+                    Err(updated) => current = updated,
+                },
+            }
+        }
+    }
+
+    /// Returns true if there exists no element to pull at the moment, while the queue is not closed.
+    fn is_pending(&self) -> bool {
+        let num_pushed = self.num_pushed.load(Ordering::SeqCst);
+        let num_taken = self.counter.load(Ordering::SeqCst);
+        num_pushed & CLOSED != CLOSED && num_taken >= num_pushed
+    }
+
+    /// Waits until there exists an element to pull or the queue is closed.
+    pub(super) fn wait(&self) {
+        self.waiter.wait_while(|| self.is_pending());
+    }
+
+    /// Reserves a range of positions with at least one and at most `chunk_size` elements to be pulled.
+    pub(super) fn reserve_range(&self, chunk_size: usize) -> Reservation {
+        let mut begin = self.counter.load(Ordering::Acquire);
+        loop {
+            let num_pushed = self.num_pushed.load(Ordering::SeqCst);
+            let len = num_pushed & !CLOSED;
+            match begin < len {
+                true => {
+                    let end = (begin + chunk_size.max(1)).min(len);
+                    match self.counter.compare_exchange_weak(
+                        begin,
+                        end,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    ) {
+                        Ok(_) => return Reservation::Range(begin..end),
+                        Err(updated) => begin = updated,
+                    }
+                }
+                false => {
+                    return match num_pushed & CLOSED == CLOSED {
+                        true => Reservation::Closed,
+                        false => Reservation::Pending,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Reserves a range of positions, waiting for new elements while the queue is pending.
+    ///
+    /// Returns None if the queue is closed and all elements are pulled.
+    pub(super) fn reserve_range_or_wait(&self, chunk_size: usize) -> Option<Range<usize>> {
+        loop {
+            match self.reserve_range(chunk_size) {
+                Reservation::Range(range) => return Some(range),
+                Reservation::Closed => return None,
+                Reservation::Pending => self.wait(),
+            }
+        }
+    }
+
+    /// Takes out the element at the `idx`-th position of the queue; waits for the producer if the position is
+    /// reserved but the value is not yet written.
+    ///
+    /// # SAFETY
+    ///
+    /// The position must be reserved by the caller by a call to [`reserve_range`]; this guarantees that each
+    /// element is taken out only once.
+    ///
+    /// [`reserve_range`]: Self::reserve_range
+    pub(super) unsafe fn take(&self, idx: usize) -> T {
+        unsafe { self.fragments.slot(idx).take() }
+    }
+}
+
+impl<T> ConcurrentIter for ConIterQueue<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = QueueIntoSeqIter<T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerQueue<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        QueueIntoSeqIter::new(self)
+    }
+
+    fn skip_to_end(&self) {
+        self.close();
+        let num_pushed = self.num_pushed();
+        let num_taken_before = self.counter.fetch_max(num_pushed, Ordering::AcqRel);
+        for idx in num_taken_before..num_pushed {
+            // SAFETY: positions within num_taken_before..num_pushed are reserved by the fetch_max
+            drop(unsafe { self.take(idx) });
+        }
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.reserve_range_or_wait(1)
+            // SAFETY: position is reserved only for this consumer
+            .map(|range| unsafe { self.take(range.start) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.reserve_range_or_wait(1)
+            // SAFETY: position is reserved only for this consumer
+            .map(|range| (range.start, unsafe { self.take(range.start) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num_remaining();
+        match self.is_closed() {
+            true => (remaining, Some(remaining)),
+            false => (remaining, None),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
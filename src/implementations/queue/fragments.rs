@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+use core::{
+    cell::UnsafeCell,
+    mem::{ManuallyDrop, MaybeUninit},
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+/// Capacity of the first fragment is `2^FIRST_FRAGMENT_CAPACITY_LOG2`; every subsequent fragment doubles the capacity.
+const FIRST_FRAGMENT_CAPACITY_LOG2: u32 = 5;
+
+/// Number of fragments which is sufficient to address every position representable by `usize`.
+const NUM_FRAGMENTS: usize = (usize::BITS - FIRST_FRAGMENT_CAPACITY_LOG2) as usize;
+
+/// A position of the queue which is written at most once by a producer and read at most once by a consumer.
+pub(super) struct Slot<T> {
+    value: UnsafeCell<MaybeUninit<T>>,
+    written: AtomicBool,
+}
+
+impl<T> Slot<T> {
+    fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+            written: false.into(),
+        }
+    }
+
+    /// Writes the value and marks the slot as written.
+    ///
+    /// # SAFETY
+    ///
+    /// Each slot must be written only once, which is guaranteed by the reservation of positions
+    /// by the producers.
+    pub(super) unsafe fn write(&self, value: T) {
+        unsafe { (*self.value.get()).write(value) };
+        self.written.store(true, Ordering::Release);
+    }
+
+    /// Returns whether or not the value of the slot is written by the producer.
+    pub(super) fn is_written(&self) -> bool {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Waits until the value is written and takes it out of the slot.
+    ///
+    /// # SAFETY
+    ///
+    /// Each slot must be taken only once, which is guaranteed by the counter of the consumers.
+    /// Further, the value of the slot must be reserved by a producer which will eventually write it.
+    pub(super) unsafe fn take(&self) -> T {
+        while !self.is_written() {
+            core::hint::spin_loop();
+        }
+        unsafe { (*self.value.get()).assume_init_read() }
+    }
+}
+
+/// Append-only storage of the queue composed of fragments with doubling capacities.
+///
+/// Fragments are allocated lazily and never moved or de-allocated until the storage is dropped.
+/// Therefore, pointers to the slots remain valid while new elements are being pushed.
+pub(super) struct Fragments<T> {
+    fragments: [AtomicPtr<Slot<T>>; NUM_FRAGMENTS],
+}
+
+impl<T> Default for Fragments<T> {
+    fn default() -> Self {
+        Self {
+            fragments: core::array::from_fn(|_| AtomicPtr::new(core::ptr::null_mut())),
+        }
+    }
+}
+
+impl<T> Drop for Fragments<T> {
+    fn drop(&mut self) {
+        for (f, ptr) in self.fragments.iter_mut().enumerate() {
+            let ptr = *ptr.get_mut();
+            if !ptr.is_null() {
+                let capacity = Self::capacity_of(f);
+                // SAFETY: the fragment is allocated with exactly this capacity, slots never drop their values
+                let _vec_to_drop = unsafe { Vec::from_raw_parts(ptr, capacity, capacity) };
+            }
+        }
+    }
+}
+
+impl<T> Fragments<T> {
+    fn capacity_of(f: usize) -> usize {
+        1 << (FIRST_FRAGMENT_CAPACITY_LOG2 as usize + f)
+    }
+
+    /// Returns the (f, i) location of the element at position `idx`: the `i`-th slot of the `f`-th fragment.
+    fn location_of(idx: usize) -> (usize, usize) {
+        let f = ((idx >> FIRST_FRAGMENT_CAPACITY_LOG2) + 1).ilog2() as usize;
+        let fragment_begin = (1 << (FIRST_FRAGMENT_CAPACITY_LOG2 as usize + f))
+            - (1 << FIRST_FRAGMENT_CAPACITY_LOG2);
+        (f, idx - fragment_begin)
+    }
+
+    /// Returns a pointer to the beginning of the `f`-th fragment; allocates the fragment if not done yet.
+    fn fragment(&self, f: usize) -> *mut Slot<T> {
+        let ptr = self.fragments[f].load(Ordering::Acquire);
+        match ptr.is_null() {
+            false => ptr,
+            true => {
+                let capacity = Self::capacity_of(f);
+                let mut vec: Vec<Slot<T>> = (0..capacity).map(|_| Slot::new()).collect();
+                let new_ptr = vec.as_mut_ptr();
+                let _ = ManuallyDrop::new(vec);
+
+                match self.fragments[f].compare_exchange(
+                    core::ptr::null_mut(),
+                    new_ptr,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => new_ptr,
+                    Err(allocated_by_another_thread) => {
+                        // SAFETY: new_ptr is not shared with any other thread
+                        let _vec_to_drop =
+                            unsafe { Vec::from_raw_parts(new_ptr, capacity, capacity) };
+                        allocated_by_another_thread
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a reference to the slot at the `idx`-th position of the queue.
+    pub(super) fn slot(&self, idx: usize) -> &Slot<T> {
+        let (f, i) = Self::location_of(idx);
+        let fragment = self.fragment(f);
+        // SAFETY: i is within the capacity of the allocated f-th fragment
+        unsafe { &*fragment.add(i) }
+    }
+
+    /// Returns the slot at the `idx`-th position of the queue if its fragment is already allocated.
+    pub(super) fn slot_if_allocated(&mut self, idx: usize) -> Option<&Slot<T>> {
+        let (f, i) = Self::location_of(idx);
+        let fragment = *self.fragments[f].get_mut();
+        // SAFETY: i is within the capacity of the allocated f-th fragment
+        (!fragment.is_null()).then(|| unsafe { &*fragment.add(i) })
+    }
+}
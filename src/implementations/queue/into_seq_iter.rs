@@ -0,0 +1,38 @@
+use super::con_iter::ConIterQueue;
+use core::iter::FusedIterator;
+
+/// Sequential iterator over the remaining elements of a [`ConIterQueue`].
+///
+/// Since the queue is owned by this iterator, no more elements can be pushed; therefore,
+/// it yields the elements that are pushed but not yet pulled and terminates.
+pub struct QueueIntoSeqIter<T> {
+    queue: ConIterQueue<T>,
+}
+
+impl<T> QueueIntoSeqIter<T> {
+    pub(super) fn new(queue: ConIterQueue<T>) -> Self {
+        queue.close();
+        Self { queue }
+    }
+}
+
+impl<T> Iterator for QueueIntoSeqIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.queue.next_available().item()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for QueueIntoSeqIter<T> {
+    fn len(&self) -> usize {
+        self.queue.num_remaining()
+    }
+}
+
+impl<T> FusedIterator for QueueIntoSeqIter<T> {}
@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod fragments;
+mod into_seq_iter;
+mod queue_next;
+mod waiter;
+
+pub(crate) use chunk_puller::{ChunkPullerQueue, QueueChunk};
+pub use con_iter::ConIterQueue;
+pub use into_seq_iter::QueueIntoSeqIter;
+pub use queue_next::QueueNext;
@@ -0,0 +1,35 @@
+/// Result of a non-blocking attempt to pull the next element from a [`ConIterQueue`].
+///
+/// Unlike `Option`, it distinguishes a queue that is only temporarily empty from a queue
+/// which is closed and completely drained.
+///
+/// [`ConIterQueue`]: crate::implementations::ConIterQueue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueNext<T> {
+    /// The next element of the queue.
+    Item(T),
+    /// The queue is currently empty; however, it is not closed yet and more elements might be pushed.
+    Pending,
+    /// The queue is closed and all of its elements are already pulled; no more elements will be yielded.
+    Closed,
+}
+
+impl<T> QueueNext<T> {
+    /// Converts into `Some(item)` if this is an [`QueueNext::Item`]; `None` otherwise.
+    pub fn item(self) -> Option<T> {
+        match self {
+            Self::Item(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is the [`QueueNext::Pending`] variant.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
+    /// Returns true if this is the [`QueueNext::Closed`] variant.
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Self::Closed)
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::{ConIterQueue, QueueNext},
+    pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+/// Pushes `n` elements to the queue from `num_producers` threads and closes the queue, while the
+/// `consume` function is called concurrently by `nt` consumer threads.
+fn produce_consume<C>(n: usize, num_producers: usize, nt: usize, consume: C) -> Vec<String>
+where
+    C: Fn(&ConIterQueue<String>, &ConcurrentBag<String>) + Sync,
+{
+    let queue = ConIterQueue::new();
+    let bag = ConcurrentBag::new();
+    let num_produced = ConcurrentBag::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| consume(&queue, &bag));
+        }
+
+        for p in 0..num_producers {
+            let queue = &queue;
+            let num_produced = &num_produced;
+            s.spawn(move || {
+                for i in (p..n).step_by(num_producers) {
+                    assert!(queue.push((i + 10).to_string()).is_ok());
+                }
+                num_produced.push(true);
+                if num_produced.len() == num_producers {
+                    queue.close();
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    collected
+}
+
+fn expected(n: usize) -> Vec<String> {
+    let mut expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    expected
+}
+
+#[test]
+fn enumeration() {
+    let queue = ConIterQueue::new();
+    assert_eq!(queue.next_available(), QueueNext::Pending);
+
+    queue.push(0).expect("");
+    queue.extend([1, 2]).expect("");
+    assert_eq!(queue.num_pushed(), 3);
+
+    assert_eq!(queue.next_available(), QueueNext::Item(0));
+    assert_eq!(queue.next_with_idx(), Some((1, 1)));
+    assert_eq!(queue.next_available_with_idx(), QueueNext::Item((2, 2)));
+    assert_eq!(queue.next_available(), QueueNext::Pending);
+
+    queue.push(3).expect("");
+    queue.close();
+    assert!(queue.is_closed());
+    assert_eq!(queue.push(4), Err(4));
+    assert_eq!(queue.extend([5, 6]), Err(5));
+
+    assert_eq!(queue.next(), Some(3));
+    assert_eq!(queue.next(), None);
+    assert_eq!(queue.next_available(), QueueNext::Closed);
+    assert_eq!(queue.next_with_idx(), None);
+}
+
+#[test]
+fn size_hint() {
+    let queue = ConIterQueue::new();
+    assert_eq!(queue.size_hint(), (0, None));
+
+    queue.extend((0..20).map(|x| x.to_string())).expect("");
+    assert_eq!(queue.size_hint(), (20, None));
+
+    let _ = queue.next();
+    assert_eq!(queue.size_hint(), (19, None));
+
+    let mut puller = queue.chunk_puller(7);
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 7);
+    drop(chunk);
+    assert_eq!(queue.size_hint(), (12, None));
+
+    queue.close();
+    assert_eq!(queue.size_hint(), (12, Some(12)));
+
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 7);
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 5);
+    assert!(puller.pull().is_none());
+    assert_eq!(queue.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn size_hint_skip_to_end() {
+    let queue = ConIterQueue::new();
+    queue.extend((0..20).map(|x| x.to_string())).expect("");
+    let _ = queue.next();
+
+    queue.skip_to_end();
+    assert!(queue.is_closed());
+    assert_eq!(queue.size_hint(), (0, Some(0)));
+    assert!(queue.push(42.to_string()).is_err());
+    assert_eq!(queue.next(), None);
+}
+
+#[test]
+fn drop_remaining() {
+    let queue = ConIterQueue::new();
+    queue.extend((0..N).map(|x| x.to_string())).expect("");
+
+    let mut puller = queue.chunk_puller(7);
+    let mut chunk = puller.pull().expect("");
+    assert_eq!(chunk.next(), Some(0.to_string()));
+    drop(chunk);
+
+    assert_eq!(queue.next(), Some(7.to_string()));
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn next(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        while let Some(x) = queue.next() {
+            _ = queue.size_hint();
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn next_available(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        loop {
+            match queue.next_available() {
+                QueueNext::Item(x) => _ = bag.push(x),
+                QueueNext::Pending => std::thread::yield_now(),
+                QueueNext::Closed => break,
+            }
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn next_with_idx(n: usize, num_producers: usize, nt: usize) {
+    let indices = ConcurrentBag::new();
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        while let Some((idx, x)) = queue.next_with_idx() {
+            indices.push(idx);
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+
+    let mut indices = indices.into_inner().to_vec();
+    indices.sort();
+    assert_eq!(indices, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn item_puller(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        for x in queue.item_puller() {
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn chunk_puller(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        let mut puller = queue.chunk_puller(7);
+        while let Some(chunk) = puller.pull() {
+            assert!(chunk.len() > 0 && chunk.len() <= 7);
+            for x in chunk {
+                bag.push(x);
+            }
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn chunk_puller_with_idx(n: usize, num_producers: usize, nt: usize) {
+    let indices = ConcurrentBag::new();
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        let mut puller = queue.chunk_puller(7);
+        while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+            for (i, x) in chunk.enumerate() {
+                indices.push(begin_idx + i);
+                bag.push(x);
+            }
+        }
+    });
+    assert_eq!(expected(n), collected);
+
+    let mut indices = indices.into_inner().to_vec();
+    indices.sort();
+    assert_eq!(indices, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |queue, bag| {
+        for x in queue.chunk_puller(7).flattened() {
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn skip_to_end(n: usize, nt: usize) {
+    let queue = ConIterQueue::new();
+    queue
+        .extend((0..n).map(|i| (i + 10).to_string()))
+        .expect("");
+    queue.close();
+    let until = n / 2;
+
+    let bag = ConcurrentBag::new();
+    let num_spawned = ConcurrentBag::new();
+    let con_num_spawned = &num_spawned;
+    let con_bag = &bag;
+    let con_iter = &queue;
+    std::thread::scope(|s| {
+        for t in 0..nt {
+            s.spawn(move || {
+                con_num_spawned.push(true);
+                while con_num_spawned.len() < nt {} // allow all threads to be spawned
+
+                match t % 2 {
+                    0 => {
+                        while let Some((idx, num)) = con_iter.next_with_idx() {
+                            match idx < until {
+                                true => _ = con_bag.push(num),
+                                false => con_iter.skip_to_end(),
+                            }
+                        }
+                    }
+                    _ => {
+                        let mut puller = con_iter.chunk_puller(7);
+                        while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                            for (i, num) in chunk.enumerate() {
+                                match begin_idx + i < until {
+                                    true => _ = con_bag.push(num),
+                                    false => con_iter.skip_to_end(),
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(expected(until), collected);
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn into_seq_iter(n: usize, until: usize) {
+    let queue = ConIterQueue::new();
+    queue
+        .extend((0..n).map(|i| (i + 10).to_string()))
+        .expect("");
+
+    let mut collected = Vec::new();
+    for _ in 0..until.min(n) {
+        collected.push(queue.next().expect(""));
+    }
+
+    let iter = queue.into_seq_iter();
+    assert_eq!(iter.len(), n - until.min(n));
+    collected.extend(iter);
+    collected.sort();
+
+    assert_eq!(expected(n), collected);
+}
@@ -0,0 +1,58 @@
+/// Strategy of consumers waiting for new elements to be pushed to the queue.
+///
+/// * Without the `std` feature, consumers busy-wait with a spin loop hint.
+/// * With the `std` feature, consumers block on a condition variable, and are notified
+///   by the producers when new elements are pushed or the queue is closed.
+#[cfg(not(any(test, feature = "std")))]
+#[derive(Default)]
+pub(super) struct Waiter;
+
+#[cfg(not(any(test, feature = "std")))]
+impl Waiter {
+    /// Waits until `is_pending` returns false.
+    pub(super) fn wait_while(&self, is_pending: impl Fn() -> bool) {
+        while is_pending() {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Notifies waiting consumers that the state of the queue has changed.
+    #[inline(always)]
+    pub(super) fn notify(&self) {}
+}
+
+#[cfg(any(test, feature = "std"))]
+#[derive(Default)]
+pub(super) struct Waiter {
+    num_waiting: core::sync::atomic::AtomicUsize,
+    mutex: std::sync::Mutex<()>,
+    condvar: std::sync::Condvar,
+}
+
+#[cfg(any(test, feature = "std"))]
+impl Waiter {
+    /// Blocks the current thread until `is_pending` returns false.
+    ///
+    /// `is_pending` is evaluated after registering as a waiting consumer, and the producers check
+    /// the number of waiting consumers after changing the state; hence, no notification is lost.
+    pub(super) fn wait_while(&self, is_pending: impl Fn() -> bool) {
+        use core::sync::atomic::Ordering;
+
+        let mut guard = self.mutex.lock().unwrap_or_else(|e| e.into_inner());
+        self.num_waiting.fetch_add(1, Ordering::SeqCst);
+        while is_pending() {
+            guard = self.condvar.wait(guard).unwrap_or_else(|e| e.into_inner());
+        }
+        self.num_waiting.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Notifies waiting consumers that the state of the queue has changed.
+    pub(super) fn notify(&self) {
+        use core::sync::atomic::Ordering;
+
+        if self.num_waiting.load(Ordering::SeqCst) > 0 {
+            let _guard = self.mutex.lock().unwrap_or_else(|e| e.into_inner());
+            self.condvar.notify_all();
+        }
+    }
+}
@@ -1,16 +1,14 @@
-use super::con_iter::ConIterRange;
+use super::{con_iter::ConIterRange, range_element::RangeElement, range_iter::RangeIter};
 use crate::pullers::ChunkPuller;
-use core::ops::Range;
 
-pub struct ChunkPullerRange<'i, T> {
+pub struct ChunkPullerRange<'i, T: RangeElement> {
     con_iter: &'i ConIterRange<T>,
     chunk_size: usize,
 }
 
 impl<'i, T> From<(&'i ConIterRange<T>, usize)> for ChunkPullerRange<'i, T>
 where
-    T: Send + From<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
+    T: RangeElement,
 {
     fn from((con_iter, chunk_size): (&'i ConIterRange<T>, usize)) -> Self {
         Self {
@@ -22,13 +20,12 @@ where
 
 impl<T> ChunkPuller for ChunkPullerRange<'_, T>
 where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
+    T: RangeElement,
 {
     type ChunkItem = T;
 
     type Chunk<'c>
-        = Range<T>
+        = RangeIter<T>
     where
         Self: 'c;
 
@@ -39,12 +36,10 @@ where
     fn pull(&mut self) -> Option<Self::Chunk<'_>> {
         self.con_iter
             .progress_and_get_range(self.chunk_size)
-            .map(|(_, a, b)| a..b)
+            .map(|(_, chunk)| chunk)
     }
 
     fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
-        self.con_iter
-            .progress_and_get_range(self.chunk_size)
-            .map(|(begin_idx, a, b)| (begin_idx, a..b))
+        self.con_iter.progress_and_get_range(self.chunk_size)
     }
 }
@@ -1,18 +1,14 @@
-use crate::{ExactSizeConcurrentIter, implementations::ConIterRange};
-use core::ops::Range;
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterRange, RangeElement},
+};
 
-impl<T> Clone for ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> Clone for ConIterRange<T> {
     fn clone(&self) -> Self {
-        let begin = self.begin();
         let remaining = self.len();
         let num_taken = self.initial_len() - remaining;
-        let first = begin + num_taken;
-        let last = first + remaining;
-        let range = T::from(first)..T::from(last);
-        ConIterRange::new(range)
+        let range_iter = self.range_iter(num_taken, remaining);
+        let (begin, step, len) = range_iter.into_parts();
+        ConIterRange::from_parts(begin, step, len)
     }
 }
@@ -1,11 +1,10 @@
-use crate::{ExactSizeConcurrentIter, implementations::ConIterRange};
-use core::{fmt::Debug, ops::Range};
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterRange, RangeElement},
+};
+use core::fmt::Debug;
 
-impl<T> Debug for ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> Debug for ConIterRange<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let remaining = self.len();
         let num_taken = self.initial_len() - remaining;
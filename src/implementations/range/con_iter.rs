@@ -1,16 +1,21 @@
-use super::chunk_puller::ChunkPullerRange;
-use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use super::{chunk_puller::ChunkPullerRange, range_element::RangeElement, range_iter::RangeIter};
+use crate::{
+    ResettableConcurrentIter, SplittableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+};
 use core::{
-    marker::PhantomData,
     ops::Range,
     sync::atomic::{AtomicUsize, Ordering},
 };
 
-/// Concurrent iterator of a [`Range`].
+/// Concurrent iterator of a range of numbers.
 ///
-/// It can be created by calling [`into_con_iter`] on a range.
+/// It can be created by calling [`into_con_iter`] on a [`Range`], a [`RangeInclusive`] or a
+/// stepped range [`StepBy`] of any primitive integer type.
 ///
 /// [`Range`]: core::ops::Range
+/// [`RangeInclusive`]: core::ops::RangeInclusive
+/// [`StepBy`]: core::iter::StepBy
 /// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
 ///
 /// # Examples
@@ -23,57 +28,76 @@ use core::{
 /// assert_eq!(con_iter.next(), Some(1));
 /// assert_eq!(con_iter.next(), Some(2));
 /// assert_eq!(con_iter.next(), None);
+///
+/// let range = -2i64..=0;
+/// let con_iter = range.into_con_iter();
+/// assert_eq!(con_iter.next(), Some(-2));
+/// assert_eq!(con_iter.next(), Some(-1));
+/// assert_eq!(con_iter.next(), Some(0));
+/// assert_eq!(con_iter.next(), None);
+///
+/// let range = (0u64..10).step_by(4);
+/// let con_iter = range.into_con_iter();
+/// assert_eq!(con_iter.next(), Some(0));
+/// assert_eq!(con_iter.next(), Some(4));
+/// assert_eq!(con_iter.next(), Some(8));
+/// assert_eq!(con_iter.next(), None);
 /// ```
-pub struct ConIterRange<T> {
-    begin: usize,
+pub struct ConIterRange<T: RangeElement> {
+    begin: T,
+    step: T::Step,
     len: usize,
+    offset: usize,
     counter: AtomicUsize,
-    phantom: PhantomData<T>,
 }
 
-unsafe impl<T> Sync for ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
-}
-
-impl<T> Default for ConIterRange<T> {
+impl<T: RangeElement> Default for ConIterRange<T> {
     fn default() -> Self {
-        Self {
-            begin: Default::default(),
-            len: Default::default(),
-            counter: Default::default(),
-            phantom: Default::default(),
-        }
+        Self::from_parts(T::default(), T::unit_step(), 0)
     }
 }
 
-impl<T> ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> ConIterRange<T> {
     pub(super) fn new(range: Range<T>) -> Self {
-        let begin: usize = range.start.into();
-        let end: usize = range.end.into();
-        let len = end.saturating_sub(begin);
+        let len = T::len_of_range(range.start, range.end);
+        Self::from_parts(range.start, T::unit_step(), len)
+    }
+
+    /// Creates the concurrent iterator over `len` elements `begin`, `begin + step`, `begin + 2 * step`, ...
+    pub(super) fn from_parts(begin: T, step: T::Step, len: usize) -> Self {
+        Self::with_offset(begin, step, len, 0)
+    }
+
+    /// Creates the iterator over a part of a source starting at position `offset`, which is added
+    /// to the reported indices.
+    fn with_offset(begin: T, step: T::Step, len: usize, offset: usize) -> Self {
         Self {
             begin,
+            step,
             len,
+            offset,
             counter: 0.into(),
-            phantom: PhantomData,
         }
     }
 
-    pub(super) fn begin(&self) -> usize {
-        self.begin
-    }
-
     pub(super) fn initial_len(&self) -> usize {
         self.len
     }
 
+    /// Returns the iterator over the elements at positions `begin_idx..begin_idx + len`.
+    pub(super) fn range_iter(&self, begin_idx: usize, len: usize) -> RangeIter<T> {
+        match len {
+            0 => Default::default(),
+            _ => RangeIter::new(self.element(begin_idx), self.step, len),
+        }
+    }
+
+    #[inline(always)]
+    fn element(&self, idx: usize) -> T {
+        // idx * step is at most the distance between the first and the last elements
+        self.begin.nth_step(idx, self.step)
+    }
+
     fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
         let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
         match begin_idx < self.len {
@@ -82,25 +106,23 @@ where
         }
     }
 
-    pub(super) fn progress_and_get_range(&self, chunk_size: usize) -> Option<(usize, T, T)> {
+    pub(super) fn progress_and_get_range(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, RangeIter<T>)> {
         self.progress_and_get_begin_idx(chunk_size)
             .map(|begin_idx| {
                 let end_idx = (begin_idx + chunk_size).min(self.len).max(begin_idx);
-                let begin = self.begin + begin_idx;
-                let end = self.begin + end_idx;
-                (begin_idx, begin.into(), end.into())
+                let range_iter = self.range_iter(begin_idx, end_idx - begin_idx);
+                (self.offset + begin_idx, range_iter)
             })
     }
 }
 
-impl<T> ConcurrentIter for ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> ConcurrentIter for ConIterRange<T> {
     type Item = T;
 
-    type SequentialIter = Range<T>;
+    type SequentialIter = RangeIter<T>;
 
     type ChunkPuller<'i>
         = ChunkPullerRange<'i, Self::Item>
@@ -108,10 +130,8 @@ where
         Self: 'i;
 
     fn into_seq_iter(self) -> Self::SequentialIter {
-        let current = self.counter.load(Ordering::Acquire);
-        let begin = T::from(self.begin + current);
-        let end = T::from(self.begin + self.len);
-        begin..end
+        let current = self.counter.load(Ordering::Acquire).min(self.len);
+        self.range_iter(current, self.len - current)
     }
 
     fn skip_to_end(&self) {
@@ -120,12 +140,12 @@ where
 
     fn next(&self) -> Option<Self::Item> {
         self.progress_and_get_begin_idx(1)
-            .map(|idx| T::from(self.begin + idx))
+            .map(|idx| self.element(idx))
     }
 
     fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
         self.progress_and_get_begin_idx(1)
-            .map(|idx| (idx, T::from(self.begin + idx)))
+            .map(|idx| (self.offset + idx, self.element(idx)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -139,13 +159,37 @@ where
     }
 }
 
-impl<T> ExactSizeConcurrentIter for ConIterRange<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> ExactSizeConcurrentIter for ConIterRange<T> {
     fn len(&self) -> usize {
         let num_taken = self.counter.load(Ordering::Acquire);
         self.len.saturating_sub(num_taken)
     }
 }
+
+impl<T: RangeElement> SplittableConcurrentIter for ConIterRange<T> {
+    type Part = Self;
+
+    fn split_at(self, mid: usize) -> (Self::Part, Self::Part) {
+        let num_taken = self.counter.load(Ordering::Acquire).min(self.len);
+        let remaining = self.len - num_taken;
+        assert!(mid <= remaining, "mid must not exceed the remaining length");
+        let part = |begin_idx: usize, len: usize| {
+            let offset = self.offset + begin_idx;
+            match len {
+                0 => Self::with_offset(T::default(), T::unit_step(), 0, offset),
+                _ => Self::with_offset(self.element(begin_idx), self.step, len, offset),
+            }
+        };
+        (part(num_taken, mid), part(num_taken + mid, remaining - mid))
+    }
+}
+
+impl<T: RangeElement> ResettableConcurrentIter for ConIterRange<T> {
+    fn reset(&mut self) {
+        *self.counter.get_mut() = 0;
+    }
+
+    fn restarted(&self) -> Self {
+        Self::with_offset(self.begin, self.step, self.len, self.offset)
+    }
+}
@@ -1,17 +1,23 @@
-use super::con_iter::ConIterRange;
-use crate::concurrent_iterable::ConcurrentIterable;
-use core::ops::Range;
+use super::{con_iter::ConIterRange, range_element::RangeElement};
+use crate::{concurrent_iterable::ConcurrentIterable, into_concurrent_iter::IntoConcurrentIter};
+use core::ops::{Range, RangeInclusive};
 
-impl<T> ConcurrentIterable for Range<T>
-where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+impl<T: RangeElement> ConcurrentIterable for Range<T> {
     type Item = T;
 
     type Iter = ConIterRange<T>;
 
     fn con_iter(&self) -> Self::Iter {
-        Self::Iter::new(self.clone())
+        self.clone().into_con_iter()
+    }
+}
+
+impl<T: RangeElement> ConcurrentIterable for RangeInclusive<T> {
+    type Item = T;
+
+    type Iter = ConIterRange<T>;
+
+    fn con_iter(&self) -> Self::Iter {
+        self.clone().into_con_iter()
     }
 }
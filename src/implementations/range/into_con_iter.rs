@@ -1,17 +1,127 @@
-use super::con_iter::ConIterRange;
+use super::{con_iter::ConIterRange, range_element::RangeElement};
 use crate::into_concurrent_iter::IntoConcurrentIter;
-use core::ops::Range;
+use core::{
+    iter::StepBy,
+    ops::{Range, RangeInclusive},
+};
 
-impl<T> IntoConcurrentIter for Range<T>
+impl<T: RangeElement> IntoConcurrentIter for Range<T> {
+    type Item = T;
+
+    type IntoIter = ConIterRange<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<T: RangeElement> IntoConcurrentIter for RangeInclusive<T> {
+    type Item = T;
+
+    type IntoIter = ConIterRange<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        let (start, end) = (*self.start(), *self.end());
+        let len = match self.is_empty() {
+            true => 0,
+            false => T::len_of_range(start, end)
+                .checked_add(1)
+                .expect("number of elements of the range must fit in usize"),
+        };
+        Self::IntoIter::from_parts(start, T::unit_step(), len)
+    }
+}
+
+impl<T: RangeElement> IntoConcurrentIter for StepBy<Range<T>>
 where
-    T: Send + From<usize> + Into<usize>,
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
+    Self: Iterator<Item = T> + Clone,
 {
     type Item = T;
 
     type IntoIter = ConIterRange<T>;
 
     fn into_con_iter(self) -> Self::IntoIter {
-        Self::IntoIter::new(self)
+        con_iter_of_step_by(self)
+    }
+}
+
+impl<T: RangeElement> IntoConcurrentIter for StepBy<RangeInclusive<T>>
+where
+    Self: Iterator<Item = T> + Clone,
+{
+    type Item = T;
+
+    type IntoIter = ConIterRange<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        con_iter_of_step_by(self)
+    }
+}
+
+/// Creates the concurrent iterator of a stepped range; the first element and the step are
+/// observed from the first two elements of the range.
+///
+/// The length is read from the size hint when it is exact. Otherwise, which is the case for ranges
+/// spanning more than `usize::MAX` values, the length is searched by calls to `nth` starting from the
+/// lower bound of the size hint; see [`len_of_step_by`].
+fn con_iter_of_step_by<T, I>(iter: I) -> ConIterRange<T>
+where
+    T: RangeElement,
+    I: Iterator<Item = T> + Clone,
+{
+    let mut elements = iter.clone().take(2);
+    match (elements.next(), elements.next()) {
+        (Some(first), Some(second)) => {
+            let step = first.step_to(second);
+            let len = match iter.size_hint() {
+                (lower, Some(upper)) if lower == upper => lower,
+                (lower, _) => len_of_step_by(iter, lower.max(2), first.max_num_steps(step)),
+            };
+            ConIterRange::from_parts(first, step, len)
+        }
+        (Some(first), None) => ConIterRange::from_parts(first, T::unit_step(), 1),
+        _ => ConIterRange::from_parts(T::default(), T::unit_step(), 0),
+    }
+}
+
+/// Returns the length of the `iter` which is known to be within `lower..=upper`.
+///
+/// The length is found by an exponential search followed by a binary search; hence, `nth` is called
+/// `O(log(len))` times, and it is never called with a position more than twice the length.
+///
+/// # Panics
+///
+/// Panics if the length of the iterator does not fit in `usize`.
+fn len_of_step_by<I: Iterator + Clone>(iter: I, lower: usize, upper: usize) -> usize {
+    let has_len = |len: usize| iter.clone().nth(len - 1).is_some();
+
+    // all lengths up to `lower` are known to exist
+    let (mut lower, mut upper) = (lower, upper.max(lower));
+    let mut probe = lower.saturating_mul(2).min(upper);
+    while probe > lower {
+        match has_len(probe) {
+            true => {
+                lower = probe;
+                probe = probe.saturating_mul(2).min(upper);
+            }
+            false => {
+                upper = probe - 1;
+                break;
+            }
+        }
     }
+
+    while lower < upper {
+        let mid = lower + (upper - lower).div_ceil(2);
+        match has_len(mid) {
+            true => lower = mid,
+            false => upper = mid - 1,
+        }
+    }
+
+    assert!(
+        lower < usize::MAX || iter.clone().nth(usize::MAX).is_none(),
+        "number of elements of the range must fit in usize"
+    );
+    lower
 }
@@ -6,5 +6,9 @@ mod common_traits;
 mod con_iter;
 mod con_iterable;
 mod into_con_iter;
+mod range_element;
+mod range_iter;
 
 pub use con_iter::ConIterRange;
+pub use range_element::RangeElement;
+pub use range_iter::RangeIter;
@@ -0,0 +1,111 @@
+/// A numeric type which can be the element of a range that is iterated concurrently.
+///
+/// It is implemented for all primitive integer types.
+///
+/// Concurrent range iterators hold the first element of the range and compute every element by its
+/// offset from the first one; therefore, signed ranges and ranges of integers wider than `usize` are
+/// supported as long as the number of elements of the range fits in `usize`.
+pub trait RangeElement: Copy + Default + Send + Sync + PartialOrd {
+    /// Type of the distance between two consecutive elements of a stepped range, which is the unsigned
+    /// integer type of the same width.
+    type Step: Copy + Send + Sync + core::fmt::Debug;
+
+    /// Returns the step of one between consecutive elements of a range.
+    fn unit_step() -> Self::Step;
+
+    /// Returns the number of elements of the range `begin..end`; zero if `end <= begin`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements of the range does not fit in `usize`.
+    fn len_of_range(begin: Self, end: Self) -> usize;
+
+    /// Returns the distance `other - self` as the step between the two elements, where `other`
+    /// is greater than `self`.
+    fn step_to(self, other: Self) -> Self::Step;
+
+    /// Returns the number of elements `self`, `self + step`, `self + 2 * step`, ... which are within the
+    /// bounds of the type; saturates at `usize::MAX`.
+    fn max_num_steps(self, step: Self::Step) -> usize;
+
+    /// Returns the element `self + idx * step`, computed in the width of the type.
+    ///
+    /// The caller guarantees that the result is within the bounds of the type.
+    fn nth_step(self, idx: usize, step: Self::Step) -> Self;
+
+    /// Returns the element which is `offset` positions after `self`.
+    ///
+    /// The caller guarantees that the result is within the bounds of the type.
+    fn add_offset(self, offset: usize) -> Self;
+}
+
+macro_rules! impl_range_element {
+    ($($t:ty => $unsigned:ty),* $(,)?) => {
+        $(
+            impl RangeElement for $t {
+                type Step = $unsigned;
+
+                #[inline(always)]
+                fn unit_step() -> Self::Step {
+                    1
+                }
+
+                #[inline(always)]
+                fn len_of_range(begin: Self, end: Self) -> usize {
+                    match begin < end {
+                        true => {
+                            // difference of the same-width unsigned type is exact when end > begin
+                            let len = end.wrapping_sub(begin) as $unsigned as u128;
+                            assert!(
+                                len <= usize::MAX as u128,
+                                "number of elements of the range must fit in usize"
+                            );
+                            len as usize
+                        }
+                        false => 0,
+                    }
+                }
+
+                #[inline(always)]
+                fn step_to(self, other: Self) -> Self::Step {
+                    other.wrapping_sub(self) as $unsigned
+                }
+
+                fn max_num_steps(self, step: Self::Step) -> usize {
+                    let num_steps = (<$t>::MAX.wrapping_sub(self) as $unsigned / step) as u128;
+                    match num_steps < usize::MAX as u128 {
+                        true => num_steps as usize + 1,
+                        false => usize::MAX,
+                    }
+                }
+
+                #[inline(always)]
+                fn nth_step(self, idx: usize, step: Self::Step) -> Self {
+                    // wrapping arithmetic in the width of the type is exact when the result is in bounds
+                    self.wrapping_add((idx as $unsigned).wrapping_mul(step) as $t)
+                }
+
+                #[inline(always)]
+                fn add_offset(self, offset: usize) -> Self {
+                    // wrapping addition of the truncated offset is exact when the result is in bounds
+                    self.wrapping_add(offset as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_element!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
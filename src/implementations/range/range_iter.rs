@@ -0,0 +1,68 @@
+use super::range_element::RangeElement;
+use core::iter::FusedIterator;
+
+/// An exact-size sequential iterator over the elements `begin`, `begin + step`, `begin + 2 * step`, ...
+/// of a range of numbers.
+///
+/// It is the type of the chunks pulled from a [`ConIterRange`] and the sequential iterator that
+/// it is converted into.
+///
+/// Note that these types used to be `core::ops::Range<T>`, which cannot represent stepped ranges and
+/// does not implement [`ExactSizeIterator`] for all integer types, such as `u64` or `i64`. Code which
+/// relies on the chunks being ranges can convert them with `chunk.collect()`, or can use the first
+/// element and the length of the chunk.
+///
+/// [`ConIterRange`]: crate::implementations::ConIterRange
+#[derive(Clone, Copy, Debug)]
+pub struct RangeIter<T: RangeElement> {
+    begin: T,
+    step: T::Step,
+    len: usize,
+}
+
+impl<T: RangeElement> Default for RangeIter<T> {
+    fn default() -> Self {
+        Self::new(T::default(), T::unit_step(), 0)
+    }
+}
+
+impl<T: RangeElement> RangeIter<T> {
+    pub(super) fn new(begin: T, step: T::Step, len: usize) -> Self {
+        Self { begin, step, len }
+    }
+
+    pub(super) fn into_parts(self) -> (T, T::Step, usize) {
+        (self.begin, self.step, self.len)
+    }
+}
+
+impl<T: RangeElement> Iterator for RangeIter<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => {
+                let value = self.begin;
+                self.len -= 1;
+                if self.len > 0 {
+                    self.begin = self.begin.nth_step(1, self.step);
+                }
+                Some(value)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: RangeElement> ExactSizeIterator for RangeIter<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: RangeElement> FusedIterator for RangeIter<T> {}
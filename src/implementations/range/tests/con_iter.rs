@@ -1,5 +1,6 @@
 use crate::{
-    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    ResettableConcurrentIter, SplittableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
     implementations::range::con_iter::ConIterRange, into_concurrent_iter::IntoConcurrentIter,
     pullers::ChunkPuller,
 };
@@ -437,3 +438,86 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix([0, 1, N], [0, 1, 3, 7], [1, 2, 4, N + 1])]
+fn split_into(n: usize, num_taken: usize, k: usize) {
+    let range: Range<usize> = 10..(10 + n);
+    let iter = range.into_con_iter();
+    let taken: Vec<_> = (0..num_taken).filter_map(|_| iter.next()).collect();
+
+    let parts = iter.split_into(k);
+    assert_eq!(parts.len(), k);
+    let lengths: Vec<_> = parts.iter().map(|p| p.len()).collect();
+    assert_eq!(lengths.iter().sum::<usize>(), n.saturating_sub(num_taken));
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for part in &parts {
+            let bag = &bag;
+            s.spawn(move || {
+                let mut puller = part.chunk_puller(5);
+                while let Some(chunk) = puller.pull() {
+                    chunk.for_each(|x| _ = bag.push(x));
+                }
+            });
+        }
+    });
+
+    let mut all = taken;
+    all.extend(bag.into_inner());
+    all.sort();
+    let expected: Vec<_> = (10..(10 + n)).collect();
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn split_at() {
+    let range: Range<usize> = 0..6;
+    let iter = range.into_con_iter();
+    assert_eq!(iter.next(), Some(0));
+
+    let (left, right) = iter.split_at(2);
+    assert_eq!(left.next_with_idx(), Some((1, 1)));
+    assert_eq!(right.next_with_idx(), Some((3, 3)));
+    assert_eq!(left.len(), 1);
+    assert_eq!(right.len(), 2);
+
+    let (empty, rest) = right.split_at(0);
+    assert_eq!(empty.next(), None);
+    let (begin_idx, chunk) = rest.chunk_puller(1).pull_with_idx().expect("has elements");
+    assert_eq!((begin_idx, chunk.collect::<Vec<_>>()), (4, vec![4]));
+    assert_eq!(rest.into_seq_iter().collect::<Vec<_>>(), [5]);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn reset(n: usize, nt: usize) {
+    let range: Range<usize> = 10..(10 + n);
+    let mut iter = range.into_con_iter();
+
+    for until in [n / 2, n] {
+        let bag = ConcurrentBag::new();
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    let mut puller = iter.chunk_puller(7);
+                    while bag.len() < until {
+                        match puller.pull() {
+                            Some(chunk) => chunk.for_each(|x| _ = bag.push(x)),
+                            None => break,
+                        }
+                    }
+                });
+            }
+        });
+        assert!(bag.len() >= until);
+
+        iter.reset();
+        assert_eq!(iter.len(), n);
+        let restarted = iter.restarted();
+        assert_eq!(restarted.next(), iter.next());
+        iter.reset();
+    }
+
+    let all: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(all, (10..(10 + n)).collect::<Vec<_>>());
+}
@@ -1,4 +1,5 @@
 mod con_iter;
 mod into;
+mod numeric;
 mod trait_bounds;
 mod transformations;
@@ -0,0 +1,205 @@
+use crate::{
+    SplittableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter, implementations::RangeElement,
+    into_concurrent_iter::IntoConcurrentIter, pullers::ChunkPuller,
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+fn collect_concurrently<T, I>(iter: I, nt: usize, chunk_size: usize) -> Vec<(usize, T)>
+where
+    T: RangeElement,
+    I: ConcurrentIter<Item = T>,
+{
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        bag.push((begin_idx + i, x));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    collected
+}
+
+fn assert_same<T, I>(con_iter: I, seq_iter: impl Iterator<Item = T> + Clone, nt: usize)
+where
+    T: RangeElement + Debug + PartialEq,
+    I: ConcurrentIter<Item = T> + ExactSizeConcurrentIter,
+{
+    let expected: Vec<_> = seq_iter.enumerate().collect();
+    assert_eq!(con_iter.len(), expected.len());
+    assert_eq!(collect_concurrently(con_iter, nt, 7), expected);
+}
+
+#[test_matrix([1, 4])]
+fn signed(nt: usize) {
+    assert_same((-300i32..200).into_con_iter(), -300i32..200, nt);
+    assert_same((-300i64..=-200).into_con_iter(), -300i64..=-200, nt);
+    assert_same((i8::MIN..=i8::MAX).into_con_iter(), i8::MIN..=i8::MAX, nt);
+    assert_same(
+        (i64::MAX - 100..i64::MAX).into_con_iter(),
+        i64::MAX - 100..i64::MAX,
+        nt,
+    );
+}
+
+#[test_matrix([1, 4])]
+fn unsigned(nt: usize) {
+    assert_same((u8::MIN..=u8::MAX).into_con_iter(), u8::MIN..=u8::MAX, nt);
+    let begin = u64::MAX - 1000;
+    assert_same((begin..=u64::MAX).into_con_iter(), begin..=u64::MAX, nt);
+    let begin = u128::MAX - 1000;
+    assert_same((begin..u128::MAX).into_con_iter(), begin..u128::MAX, nt);
+}
+
+#[test_matrix([1, 4], [1, 2, 3, 17, 1000])]
+fn stepped(nt: usize, step: usize) {
+    assert_same(
+        (-500i64..500).step_by(step).into_con_iter(),
+        (-500i64..500).step_by(step),
+        nt,
+    );
+    assert_same(
+        (0u8..=255).step_by(step).into_con_iter(),
+        (0u8..=255).step_by(step),
+        nt,
+    );
+    assert_same(
+        (10usize..20).step_by(step).into_con_iter(),
+        (10usize..20).step_by(step),
+        nt,
+    );
+}
+
+#[test]
+fn empty() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let iter = (5i32..=4).into_con_iter();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+
+    let mut range = 3u64..=3;
+    assert_eq!(range.next(), Some(3));
+    let iter = range.into_con_iter();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+
+    let iter = (7i16..7).step_by(3).into_con_iter();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn into_seq_iter_and_clone() {
+    let iter = (-10i64..=10).step_by(5).into_con_iter();
+    assert_eq!(iter.next(), Some(-10));
+
+    let clone = iter.clone();
+    assert_eq!(clone.len(), 4);
+
+    let seq: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(seq, [-5, 0, 5, 10]);
+
+    let seq: Vec<_> = clone.into_seq_iter().collect();
+    assert_eq!(seq, [-5, 0, 5, 10]);
+}
+
+#[test]
+#[should_panic]
+fn too_long() {
+    let _iter = (0u128..u128::MAX).into_con_iter();
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test_matrix([1, 4])]
+fn stepped_near_type_limits(nt: usize) {
+    let begin = u64::MAX - 10_000;
+    assert_same(
+        (begin..=u64::MAX).step_by(7).into_con_iter(),
+        (begin..=u64::MAX).step_by(7),
+        nt,
+    );
+    assert_same(
+        (0u64..=u64::MAX).step_by(1 << 52).into_con_iter(),
+        (0u64..=u64::MAX).step_by(1 << 52),
+        nt,
+    );
+    assert_same(
+        (i64::MIN..i64::MAX).step_by(1 << 53).into_con_iter(),
+        (i64::MIN..i64::MAX).step_by(1 << 53),
+        nt,
+    );
+    assert_same(
+        (i64::MAX - 1000..=i64::MAX).step_by(3).into_con_iter(),
+        (i64::MAX - 1000..=i64::MAX).step_by(3),
+        nt,
+    );
+    let begin = u128::MAX - (1 << 70);
+    assert_same(
+        (begin..=u128::MAX).step_by(1 << 60).into_con_iter(),
+        (begin..=u128::MAX).step_by(1 << 60),
+        nt,
+    );
+    let end = i128::MIN + (1 << 70);
+    assert_same(
+        (i128::MIN..end).step_by(1 << 60).into_con_iter(),
+        (i128::MIN..end).step_by(1 << 60),
+        nt,
+    );
+    let begin = i128::MAX - (1 << 70);
+    assert_same(
+        (begin..=i128::MAX).step_by((1 << 60) + 1).into_con_iter(),
+        (begin..=i128::MAX).step_by((1 << 60) + 1),
+        nt,
+    );
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn stepped_wide_range() {
+    let iter = (0u128..1 << 80).step_by(1 << 40).into_con_iter();
+    assert_eq!(iter.len(), 1 << 40);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_with_idx(), Some((1, 1 << 40)));
+
+    let mut puller = iter.chunk_puller(3);
+    let (begin_idx, chunk) = puller.pull_with_idx().expect("has elements");
+    assert_eq!(begin_idx, 2);
+    assert_eq!(chunk.collect::<Vec<_>>(), [2 << 40, 3 << 40, 4 << 40]);
+
+    iter.skip_to_end();
+    let (_, tail) = (0u128..1 << 80)
+        .step_by(1 << 40)
+        .into_con_iter()
+        .split_at((1 << 40) - 2);
+    assert_eq!(
+        tail.into_seq_iter().collect::<Vec<_>>(),
+        [(1 << 80) - (2 << 40), (1 << 80) - (1 << 40)]
+    );
+
+    let iter = (0u128..=1 << 80).step_by(1 << 40).into_con_iter();
+    assert_eq!(iter.len(), (1 << 40) + 1);
+
+    let iter = (u128::MAX - 5..=u128::MAX)
+        .step_by(usize::MAX)
+        .into_con_iter();
+    assert_eq!(iter.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn stepped_too_long() {
+    let _iter = (0u128..u128::MAX).step_by(2).into_con_iter();
+}
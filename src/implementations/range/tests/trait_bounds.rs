@@ -1,23 +1,27 @@
-use core::ops::Range;
+use crate::implementations::RangeElement;
+use core::ops::{Range, RangeInclusive};
 
-fn into_con_iter<T: Send + Sync + From<usize> + Into<usize>>(range: Range<T>)
-where
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+fn into_con_iter<T: RangeElement>(range: Range<T>) {
     use crate::IntoConcurrentIter;
     let _con_iter = range.into_con_iter();
 }
 
-fn concurrent_iterable<T: Send + Sync + From<usize> + Into<usize>>(range: Range<T>)
-where
-    Range<T>: Default + Clone + ExactSizeIterator<Item = T>,
-{
+fn concurrent_iterable<T: RangeElement>(range: Range<T>) {
     use crate::ConcurrentIterable;
     let _con_iter = range.con_iter();
 }
 
+fn inclusive_into_con_iter<T: RangeElement>(range: RangeInclusive<T>) {
+    use crate::IntoConcurrentIter;
+    let _con_iter = range.into_con_iter();
+}
+
 #[test]
 fn range_con_iter_trait_bounds() {
-    into_con_iter(0..1);
-    concurrent_iterable(0..1);
+    into_con_iter(0usize..1);
+    into_con_iter(-1i64..1);
+    into_con_iter(0u128..1);
+    concurrent_iterable(0usize..1);
+    concurrent_iterable(0i8..1);
+    inclusive_into_con_iter(0u16..=1);
 }
@@ -0,0 +1,87 @@
+use super::con_iter::ConIterReceiver;
+use crate::pullers::ChunkPuller;
+use alloc::vec::{Drain, Vec};
+use core::iter::FusedIterator;
+
+pub struct ChunkPullerReceiver<'i, T>
+where
+    T: Send,
+{
+    con_iter: &'i ConIterReceiver<T>,
+    buffer: Vec<T>,
+    chunk_size: usize,
+}
+
+impl<'i, T> ChunkPullerReceiver<'i, T>
+where
+    T: Send,
+{
+    pub(super) fn new(con_iter: &'i ConIterReceiver<T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            buffer: Vec::with_capacity(chunk_size),
+            chunk_size,
+        }
+    }
+}
+
+impl<T> ChunkPuller for ChunkPullerReceiver<'_, T>
+where
+    T: Send,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = ReceiverChunk<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.buffer.clear();
+        self.con_iter
+            .next_chunk_to_buffer(&mut self.buffer, self.chunk_size)
+            .map(|_| ReceiverChunk(Some(self.buffer.drain(..))))
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.buffer.clear();
+        self.con_iter
+            .next_chunk_to_buffer(&mut self.buffer, self.chunk_size)
+            .map(|(begin_idx, _)| (begin_idx, ReceiverChunk(Some(self.buffer.drain(..)))))
+    }
+}
+
+// iter
+
+pub struct ReceiverChunk<'c, T>(Option<Drain<'c, T>>);
+
+impl<T> Default for ReceiverChunk<'_, T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Iterator for ReceiverChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.as_mut().and_then(|x| x.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for ReceiverChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.0.as_ref().map(|x| x.len()).unwrap_or(0)
+    }
+}
+
+impl<T> FusedIterator for ReceiverChunk<'_, T> {}
@@ -0,0 +1,13 @@
+use crate::{ConcurrentIter, implementations::ConIterReceiver};
+use core::fmt::Debug;
+
+impl<T> Debug for ConIterReceiver<T>
+where
+    T: Send,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConIterReceiver")
+            .field("size_hint", &self.size_hint())
+            .finish()
+    }
+}
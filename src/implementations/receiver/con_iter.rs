@@ -0,0 +1,266 @@
+use super::chunk_puller::ChunkPullerReceiver;
+use crate::concurrent_iter::ConcurrentIter;
+use alloc::vec::Vec;
+use core::{
+    iter::Flatten,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+use std::{
+    sync::{
+        Condvar, Mutex, MutexGuard,
+        mpsc::{Receiver, RecvError, RecvTimeoutError, TryRecvError},
+    },
+    time::Duration,
+};
+
+/// Maximum duration that a thread blocked on the empty channel waits before checking whether the
+/// iterator is skipped to its end.
+const RECV_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Concurrent iterator of the receiving half of a [`std::sync::mpsc`] channel.
+///
+/// It can be created by calling [`into_con_iter`] on a `Receiver<T>`; it is available only
+/// when the `std` feature is enabled.
+///
+/// This allows multiple threads to share one inbound channel through the standard puller api:
+///
+/// * `next` blocks until an element is received, and returns `None` once all senders are dropped
+///   and the channel is drained;
+/// * chunk pullers block only until the first element of the chunk is received, and then drain
+///   whatever is already available in the channel up to the chunk size without waiting for a full chunk.
+///
+/// Index of an element is the order in which it is received by any of the pulling threads.
+///
+/// The standard receiver can be used by only one thread at a time. Pulling threads lock it only for
+/// non-blocking receive attempts. When the channel is empty, one of the threads takes the receiver out
+/// and blocks on it without holding the lock, while the other pulling threads wait on a condition
+/// variable. They are woken up as soon as the receiver is put back, or when the iterator is skipped
+/// to its end. For a multi-producer multi-consumer alternative where the consumers do not wait for each
+/// other, see [`ConIterQueue`].
+///
+/// The thread blocked on the receiver waits with a short timeout and rechecks whether the iterator is
+/// skipped to its end; hence, `skip_to_end` releases it shortly even if no element arrives. An element
+/// which is already received when the iterator is skipped to its end is still returned to the pulling
+/// thread; the elements which remain in the channel are dropped together with the receiver.
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`ConIterQueue`]: crate::implementations::ConIterQueue
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::sync::mpsc::channel;
+///
+/// let (sender, receiver) = channel();
+/// let con_iter = receiver.into_con_iter();
+///
+/// let sum: usize = std::thread::scope(|s| {
+///     let consumers: Vec<_> = (0..2)
+///         .map(|_| {
+///             s.spawn(|| {
+///                 let mut sum = 0;
+///                 let mut puller = con_iter.chunk_puller(16);
+///                 while let Some(chunk) = puller.pull() {
+///                     sum += chunk.sum::<usize>();
+///                 }
+///                 sum
+///             })
+///         })
+///         .collect();
+///
+///     s.spawn(move || {
+///         for i in 0..100 {
+///             sender.send(i).unwrap();
+///         }
+///         // channel is disconnected when the sender is dropped
+///     });
+///
+///     consumers.into_iter().map(|x| x.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(sum, (0..100).sum());
+/// ```
+pub struct ConIterReceiver<T>
+where
+    T: Send,
+{
+    receiver: Mutex<Option<Receiver<T>>>,
+    receiver_returned: Condvar,
+    counter: AtomicUsize,
+    completed: AtomicBool,
+}
+
+impl<T> ConIterReceiver<T>
+where
+    T: Send,
+{
+    pub(super) fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            receiver: Some(receiver).into(),
+            receiver_returned: Condvar::new(),
+            counter: 0.into(),
+            completed: false.into(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Receiver<T>>> {
+        self.receiver.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn complete(&self) {
+        self.completed.store(true, Ordering::Release);
+        let _guard = self.lock();
+        self.receiver_returned.notify_all();
+    }
+
+    /// Receives the next element, waiting for it if the channel is empty.
+    ///
+    /// The lock is held only while attempting to receive without blocking. When the channel is empty,
+    /// the receiver is taken out and this thread blocks on it without holding the lock; meanwhile,
+    /// other threads wait until it is put back.
+    fn receive(&self) -> Option<T> {
+        let mut guard = self.lock();
+        loop {
+            if self.completed.load(Ordering::Acquire) {
+                return None;
+            }
+
+            let received = match guard.take() {
+                None => {
+                    // another thread is blocked on the receiver
+                    guard = self
+                        .receiver_returned
+                        .wait(guard)
+                        .unwrap_or_else(|e| e.into_inner());
+                    continue;
+                }
+                Some(receiver) => match receiver.try_recv() {
+                    Ok(value) => {
+                        *guard = Some(receiver);
+                        Ok(value)
+                    }
+                    Err(TryRecvError::Disconnected) => {
+                        *guard = Some(receiver);
+                        Err(RecvError)
+                    }
+                    Err(TryRecvError::Empty) => {
+                        drop(guard);
+                        let received = self.wait_for_next(&receiver);
+                        guard = self.lock();
+                        *guard = Some(receiver);
+                        self.receiver_returned.notify_all();
+                        match received {
+                            Some(received) => received,
+                            None => continue, // completed while waiting
+                        }
+                    }
+                },
+            };
+
+            return match received {
+                Ok(value) => Some(value),
+                Err(RecvError) => {
+                    drop(guard);
+                    self.complete();
+                    None
+                }
+            };
+        }
+    }
+
+    /// Blocks on the `receiver` until the next element is received or the channel is disconnected;
+    /// returns None if the iterator is skipped to its end in the meantime.
+    fn wait_for_next(&self, receiver: &Receiver<T>) -> Option<Result<T, RecvError>> {
+        loop {
+            match receiver.recv_timeout(RECV_TIMEOUT) {
+                Ok(value) => return Some(Ok(value)),
+                Err(RecvTimeoutError::Disconnected) => return Some(Err(RecvError)),
+                Err(RecvTimeoutError::Timeout) => {
+                    if self.completed.load(Ordering::Acquire) {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Waits for the first element and then moves the elements that are already available in the channel
+    /// into the `buffer`, up to `chunk_size` elements in total.
+    ///
+    /// Returns the index of the first element together with the number of received elements.
+    pub(super) fn next_chunk_to_buffer(
+        &self,
+        buffer: &mut Vec<T>,
+        chunk_size: usize,
+    ) -> Option<(usize, usize)> {
+        let first = self.receive()?;
+        buffer.push(first);
+        if buffer.len() < chunk_size {
+            let guard = self.lock();
+            // if the receiver is taken, the channel was empty a moment ago
+            if let Some(receiver) = guard.as_ref() {
+                while buffer.len() < chunk_size {
+                    match receiver.try_recv() {
+                        Ok(value) => buffer.push(value),
+                        Err(_) => break,
+                    }
+                }
+            }
+        }
+        let begin_idx = self.counter.fetch_add(buffer.len(), Ordering::Relaxed);
+        Some((begin_idx, buffer.len()))
+    }
+}
+
+impl<T> ConcurrentIter for ConIterReceiver<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = Flatten<core::option::IntoIter<Receiver<T>>>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerReceiver<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let receiver = match self.completed.into_inner() {
+            true => None,
+            false => self
+                .receiver
+                .into_inner()
+                .unwrap_or_else(|e| e.into_inner()),
+        };
+        receiver.into_iter().flatten()
+    }
+
+    fn skip_to_end(&self) {
+        self.complete();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        let value = self.receive()?;
+        self.counter.fetch_add(1, Ordering::Relaxed);
+        Some(value)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        let value = self.receive()?;
+        let idx = self.counter.fetch_add(1, Ordering::Relaxed);
+        Some((idx, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.completed.load(Ordering::Acquire) {
+            true => (0, Some(0)),
+            false => (0, None),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
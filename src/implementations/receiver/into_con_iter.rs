@@ -0,0 +1,16 @@
+use super::con_iter::ConIterReceiver;
+use crate::into_concurrent_iter::IntoConcurrentIter;
+use std::sync::mpsc::Receiver;
+
+impl<T> IntoConcurrentIter for Receiver<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type IntoIter = ConIterReceiver<T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
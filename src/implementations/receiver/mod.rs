@@ -0,0 +1,9 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod into_con_iter;
+
+pub use con_iter::ConIterReceiver;
@@ -0,0 +1,261 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, implementations::ConIterReceiver,
+    into_concurrent_iter::IntoConcurrentIter, pullers::ChunkPuller,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use std::sync::mpsc::{Sender, channel, sync_channel};
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+/// Sends `n` elements from `num_producers` threads while `consume` is concurrently called by
+/// `nt` consumer threads; the channel is disconnected once all producers complete.
+fn produce_consume<C>(n: usize, num_producers: usize, nt: usize, consume: C) -> Vec<String>
+where
+    C: Fn(&ConIterReceiver<String>, &ConcurrentBag<String>) + Sync,
+{
+    let (sender, receiver) = channel();
+    let con_iter = receiver.into_con_iter();
+    let bag = ConcurrentBag::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| consume(&con_iter, &bag));
+        }
+
+        for p in 0..num_producers {
+            let sender: Sender<_> = sender.clone();
+            s.spawn(move || {
+                for i in (p..n).step_by(num_producers) {
+                    sender.send((i + 10).to_string()).expect("");
+                }
+            });
+        }
+        drop(sender);
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    collected
+}
+
+fn expected(n: usize) -> Vec<String> {
+    let mut expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    expected.sort();
+    expected
+}
+
+#[test]
+fn enumeration() {
+    let (sender, receiver) = channel();
+    let iter = receiver.into_con_iter();
+    for i in 0..6 {
+        sender.send(i).expect("");
+    }
+    drop(sender);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_with_idx(), Some((1, 1)));
+    assert_eq!(iter.size_hint(), (0, None));
+
+    let mut puller = iter.chunk_puller(3);
+    let (begin_idx, chunk) = puller.pull_with_idx().expect("");
+    assert_eq!(begin_idx, 2);
+    assert_eq!(chunk.collect::<Vec<_>>(), [2, 3, 4]);
+
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 1);
+    assert_eq!(chunk.collect::<Vec<_>>(), [5]);
+
+    assert!(puller.pull().is_none());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn chunk_does_not_wait_for_full_chunk() {
+    let (sender, receiver) = sync_channel(4);
+    let iter = receiver.into_con_iter();
+    sender.send(0).expect("");
+    sender.send(1).expect("");
+
+    let mut puller = iter.chunk_puller(64);
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.collect::<Vec<_>>(), [0, 1]);
+
+    sender.send(2).expect("");
+    let chunk = puller.pull().expect("");
+    assert_eq!(chunk.collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn skip_to_end() {
+    let (sender, receiver) = channel();
+    let iter = receiver.into_con_iter();
+    for i in 0..6 {
+        sender.send(i).expect("");
+    }
+
+    assert_eq!(iter.next(), Some(0));
+    iter.skip_to_end();
+    assert_eq!(iter.next(), None);
+    assert!(iter.chunk_puller(4).pull().is_none());
+    assert_eq!(iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn skip_to_end_while_another_puller_is_waiting() {
+    let (sender, receiver) = channel::<usize>();
+    let iter = receiver.into_con_iter();
+
+    std::thread::scope(|s| {
+        let waiting = s.spawn(|| iter.next());
+        std::thread::sleep(std::time::Duration::from_millis(20));
+
+        // neither the receiver lock nor the waiting puller blocks the other pullers
+        iter.skip_to_end();
+        assert_eq!(iter.next(), None);
+        assert!(iter.chunk_puller(4).pull().is_none());
+
+        // the puller blocked on the empty channel is released while the sender is still alive
+        assert_eq!(waiting.join().expect("no panic"), None);
+        drop(sender);
+    });
+}
+
+#[test]
+fn skip_to_end_releases_all_waiting_pullers() {
+    let (sender, receiver) = channel::<usize>();
+    let iter = receiver.into_con_iter();
+
+    std::thread::scope(|s| {
+        let waiting: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| iter.chunk_puller(3).pull().map(|x| x.len())))
+            .collect();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+
+        iter.skip_to_end();
+        for handle in waiting {
+            assert_eq!(handle.join().expect("no panic"), None);
+        }
+
+        sender.send(42).expect("");
+        assert_eq!(iter.next(), None);
+    });
+}
+
+#[test]
+fn chunk_pullers_waiting_for_the_receiver() {
+    let (sender, receiver) = channel();
+    let iter = receiver.into_con_iter();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(3);
+                while let Some(chunk) = puller.pull() {
+                    chunk.for_each(|x| _ = bag.push(x));
+                }
+            });
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        for i in 0..100 {
+            sender.send(i).expect("");
+        }
+        drop(sender);
+    });
+
+    let mut values = bag.into_inner().to_vec();
+    values.sort();
+    assert_eq!(values, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn into_seq_iter() {
+    let (sender, receiver) = channel();
+    let iter = receiver.into_con_iter();
+    for i in 0..6 {
+        sender.send(i).expect("");
+    }
+    drop(sender);
+
+    assert_eq!(iter.next(), Some(0));
+    let remaining: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(remaining, [1, 2, 3, 4, 5]);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn next(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |iter, bag| {
+        while let Some(x) = iter.next() {
+            _ = iter.size_hint();
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn next_with_idx(n: usize, num_producers: usize, nt: usize) {
+    let indices = ConcurrentBag::new();
+    let collected = produce_consume(n, num_producers, nt, |iter, bag| {
+        while let Some((idx, x)) = iter.next_with_idx() {
+            indices.push(idx);
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+
+    let mut indices = indices.into_inner().to_vec();
+    indices.sort();
+    assert_eq!(indices, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn item_puller(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |iter, bag| {
+        for x in iter.item_puller() {
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn chunk_puller_with_idx(n: usize, num_producers: usize, nt: usize) {
+    let indices = ConcurrentBag::new();
+    let collected = produce_consume(n, num_producers, nt, |iter, bag| {
+        let mut puller = iter.chunk_puller(7);
+        while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+            assert!(chunk.len() > 0 && chunk.len() <= 7);
+            for (i, x) in chunk.enumerate() {
+                indices.push(begin_idx + i);
+                bag.push(x);
+            }
+        }
+    });
+    assert_eq!(expected(n), collected);
+
+    let mut indices = indices.into_inner().to_vec();
+    indices.sort();
+    assert_eq!(indices, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, num_producers: usize, nt: usize) {
+    let collected = produce_consume(n, num_producers, nt, |iter, bag| {
+        for x in iter.chunk_puller(7).flattened() {
+            bag.push(x);
+        }
+    });
+    assert_eq!(expected(n), collected);
+}
@@ -0,0 +1,56 @@
+use core::iter::FusedIterator;
+
+/// An exact-size iterator yielding clones of a value a fixed number of times.
+///
+/// It is the type of the chunks pulled from [`ConIterRepeat`] and [`ConIterRepeatN`].
+///
+/// [`ConIterRepeat`]: crate::implementations::ConIterRepeat
+/// [`ConIterRepeatN`]: crate::implementations::ConIterRepeatN
+pub struct RepeatChunk<'c, T> {
+    value: Option<&'c T>,
+    len: usize,
+}
+
+impl<T> Default for RepeatChunk<'_, T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            len: 0,
+        }
+    }
+}
+
+impl<'c, T> RepeatChunk<'c, T> {
+    pub(super) fn new(value: &'c T, len: usize) -> Self {
+        Self {
+            value: Some(value),
+            len,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for RepeatChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => {
+                self.len -= 1;
+                self.value.cloned()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for RepeatChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: Clone> FusedIterator for RepeatChunk<'_, T> {}
@@ -0,0 +1,42 @@
+use super::{chunk::RepeatChunk, con_iter::ConIterRepeatN};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerRepeat<'i, T> {
+    con_iter: &'i ConIterRepeatN<T>,
+    chunk_size: usize,
+}
+
+impl<'i, T> ChunkPullerRepeat<'i, T> {
+    pub(super) fn new(con_iter: &'i ConIterRepeatN<T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'i, T> ChunkPuller for ChunkPullerRepeat<'i, T>
+where
+    T: Clone + Send,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = RepeatChunk<'i, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterRepeat, ConIterRepeatN},
+};
+use core::fmt::Debug;
+
+impl<T> Debug for ConIterRepeatN<T>
+where
+    T: Clone + Send + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterRepeatN")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+impl<T> Debug for ConIterRepeat<T>
+where
+    T: Clone + Send + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConIterRepeat")
+            .field("is_exhausted", &self.is_exhausted())
+            .finish()
+    }
+}
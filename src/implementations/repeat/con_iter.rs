@@ -0,0 +1,202 @@
+use super::{chunk::RepeatChunk, chunk_puller::ChunkPullerRepeat};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    iter::RepeatN,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A concurrent iterator yielding clones of a value exactly `n` times.
+///
+/// It can be created by [`iter::repeat_n`].
+///
+/// [`iter::repeat_n`]: crate::iter::repeat_n
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::repeat_n('x', 3);
+/// assert_eq!(con_iter.len(), 3);
+/// assert_eq!(con_iter.next(), Some('x'));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 'x')));
+/// assert_eq!(con_iter.next(), Some('x'));
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub struct ConIterRepeatN<T> {
+    value: T,
+    len: usize,
+    counter: AtomicUsize,
+}
+
+impl<T> ConIterRepeatN<T> {
+    pub(crate) fn new(value: T, len: usize) -> Self {
+        Self {
+            value,
+            len,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.len {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, RepeatChunk<'_, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size).min(self.len).max(begin_idx);
+                (
+                    begin_idx,
+                    RepeatChunk::new(&self.value, end_idx - begin_idx),
+                )
+            })
+    }
+
+    fn remaining(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.len.saturating_sub(num_taken)
+    }
+}
+
+impl<T> ConcurrentIter for ConIterRepeatN<T>
+where
+    T: Clone + Send + Sync,
+{
+    type Item = T;
+
+    type SequentialIter = RepeatN<T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerRepeat<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let remaining = self.remaining();
+        core::iter::repeat_n(self.value, remaining)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.len, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1)
+            .map(|_| self.value.clone())
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, self.value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterRepeatN<T>
+where
+    T: Clone + Send + Sync,
+{
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
+/// A concurrent iterator endlessly yielding clones of a value.
+///
+/// It can be created by [`iter::repeat`].
+///
+/// The iterator never ends unless it is skipped to the end, such as by an early exit or an
+/// explicit `skip_to_end` call.
+///
+/// [`iter::repeat`]: crate::iter::repeat
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::repeat(42);
+/// assert_eq!(con_iter.next(), Some(42));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 42)));
+///
+/// con_iter.skip_to_end();
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub struct ConIterRepeat<T> {
+    inner: ConIterRepeatN<T>,
+}
+
+impl<T> ConIterRepeat<T> {
+    pub(crate) fn new(value: T) -> Self {
+        // practically unbounded, while leaving room for the counter to keep increasing
+        // without overflowing after the iterator is skipped to the end
+        Self {
+            inner: ConIterRepeatN::new(value, isize::MAX as usize),
+        }
+    }
+
+    pub(super) fn is_exhausted(&self) -> bool {
+        self.inner.remaining() == 0
+    }
+}
+
+impl<T> ConcurrentIter for ConIterRepeat<T>
+where
+    T: Clone + Send + Sync,
+{
+    type Item = T;
+
+    type SequentialIter = RepeatN<T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerRepeat<'i, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        self.inner.into_seq_iter()
+    }
+
+    fn skip_to_end(&self) {
+        self.inner.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.inner.next_with_idx()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.is_exhausted() {
+            true => (0, Some(0)),
+            false => (usize::MAX, None),
+        }
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        self.inner.chunk_puller(chunk_size)
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk;
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+
+pub use chunk::RepeatChunk;
+pub use con_iter::{ConIterRepeat, ConIterRepeatN};
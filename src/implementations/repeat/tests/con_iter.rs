@@ -0,0 +1,90 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter, iter,
+    pullers::ChunkPuller,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn enumeration() {
+    let con_iter = iter::repeat_n('a', 3);
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next(), Some('a'));
+    assert_eq!(con_iter.next_with_idx(), Some((1, 'a')));
+    assert_eq!(con_iter.len(), 1);
+    assert_eq!(con_iter.next(), Some('a'));
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.next_with_idx(), None);
+    assert_eq!(con_iter.len(), 0);
+}
+
+#[test]
+fn repeat_until_skipped() {
+    let con_iter = iter::repeat(3);
+    assert_eq!(con_iter.size_hint(), (usize::MAX, None));
+    for i in 0..100 {
+        assert_eq!(con_iter.next_with_idx(), Some((i, 3)));
+    }
+    let mut puller = con_iter.chunk_puller(10);
+    assert_eq!(
+        puller.pull_with_idx().map(|(i, c)| (i, c.len())),
+        Some((100, 10))
+    );
+
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.size_hint(), (0, Some(0)));
+    assert_eq!(con_iter.next(), None);
+    assert!(puller.pull().is_none());
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn repeat_chain() {
+    let con_iter = iter::repeat_n(1, 2).chain_inexact(iter::repeat(0));
+    let values: Vec<_> = con_iter.item_puller().take(5).collect();
+    assert_eq!(values, [1, 1, 0, 0, 0]);
+}
+
+#[test]
+fn into_seq_iter() {
+    let con_iter = iter::repeat_n(2, 10);
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(4).pull();
+    assert_eq!(con_iter.into_seq_iter().collect::<Vec<_>>(), [2; 5]);
+}
+
+#[test_matrix([0, 1, N], [1, 4], [0, 1, 64])]
+fn repeat_n(n: usize, nt: usize, chunk: usize) {
+    let con_iter = iter::repeat_n("xy".to_string(), n);
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                        for (i, x) in chunk.enumerate() {
+                            bag.push((begin_idx + i, x));
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    let expected: Vec<(usize, String)> = (0..n).map(|i| (i, "xy".to_string())).collect();
+    assert_eq!(collected, expected);
+}
@@ -0,0 +1,48 @@
+use super::{con_iter::ConIterShuffled, shuffled_iter::ShuffledIter, source::ShuffleSource};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerShuffled<'i, S>
+where
+    S: ShuffleSource,
+{
+    con_iter: &'i ConIterShuffled<S>,
+    chunk_size: usize,
+}
+
+impl<'i, S> ChunkPullerShuffled<'i, S>
+where
+    S: ShuffleSource,
+{
+    pub(super) fn new(con_iter: &'i ConIterShuffled<S>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<S> ChunkPuller for ChunkPullerShuffled<'_, S>
+where
+    S: ShuffleSource,
+{
+    type ChunkItem = S::Item;
+
+    type Chunk<'c>
+        = ShuffledIter<S>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterShuffled, ShuffleSource},
+};
+use core::fmt::Debug;
+
+impl<S> Debug for ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterShuffled")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
@@ -0,0 +1,162 @@
+use super::{
+    chunk_puller::ChunkPullerShuffled, permutation::Permutation, shuffled_iter::ShuffledIter,
+    source::ShuffleSource,
+};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Concurrent iterator visiting the elements of a slice or a range in a pseudo-random order which
+/// is deterministic for a given seed.
+///
+/// It can be created by [`iter::shuffled`].
+///
+/// The atomic counter progresses over the positions `0..n` as usual, and each position is mapped
+/// to the index of the element to visit by a [`Permutation`] of `0..n`. Therefore, every element is
+/// visited exactly once without materializing a shuffled index vector.
+///
+/// # Indices
+///
+/// `next_with_idx` reports the original index of the element in the source. Similarly,
+/// `pull_with_idx` returns the original index of the first element of the chunk; original indices
+/// of all elements can be obtained by [`ShuffledIter::with_indices`]. Note that indices of the
+/// elements of a chunk are not consecutive; hence, the indices provided by `flattened_with_idx`
+/// and `enumerate` are not meaningful for this iterator.
+///
+/// [`iter::shuffled`]: crate::iter::shuffled
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let values: Vec<_> = (0..8).map(|i| i * 10).collect();
+///
+/// let con_iter = iter::shuffled(values.as_slice(), 42);
+/// let mut visited = vec![];
+/// while let Some((idx, value)) = con_iter.next_with_idx() {
+///     assert_eq!(*value, values[idx]);
+///     visited.push(idx);
+/// }
+///
+/// assert_ne!(visited, [0, 1, 2, 3, 4, 5, 6, 7]);
+/// visited.sort();
+/// assert_eq!(visited, [0, 1, 2, 3, 4, 5, 6, 7]);
+///
+/// // deterministic for a given seed
+/// let a: Vec<_> = iter::shuffled(0..100, 7).into_seq_iter().collect();
+/// let b: Vec<_> = iter::shuffled(0..100, 7).into_seq_iter().collect();
+/// assert_eq!(a, b);
+/// ```
+pub struct ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    source: S,
+    permutation: Permutation,
+    counter: AtomicUsize,
+}
+
+impl<S> ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    /// Creates a concurrent iterator visiting the elements of the `source` in the pseudo-random
+    /// order defined by the `seed`.
+    pub fn new(source: S, seed: u64) -> Self {
+        Self {
+            permutation: Permutation::new(source.num_elements(), seed),
+            source,
+            counter: 0.into(),
+        }
+    }
+
+    /// Returns the permutation that the positions of the iterator are mapped through.
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.permutation.len()
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.permutation.len() {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    fn get(&self, position: usize) -> (usize, S::Item) {
+        let idx = self.permutation.get(position);
+        (idx, self.source.get(idx))
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, ShuffledIter<S>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let len = self.permutation.len();
+                let end_idx = (begin_idx + chunk_size).min(len).max(begin_idx);
+                let chunk = ShuffledIter::new(&self.source, &self.permutation, begin_idx..end_idx);
+                (self.permutation.get(begin_idx), chunk)
+            })
+    }
+}
+
+impl<S> ConcurrentIter for ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    type Item = S::Item;
+
+    type SequentialIter = ShuffledIter<S>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerShuffled<'i, S>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let len = self.permutation.len();
+        let current = self.counter.load(Ordering::Acquire).min(len);
+        ShuffledIter::new(&self.source, &self.permutation, current..len)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self
+            .counter
+            .fetch_max(self.permutation.len(), Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1)
+            .map(|position| self.get(position).1)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|position| self.get(position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<S> ExactSizeConcurrentIter for ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.permutation.len().saturating_sub(num_taken)
+    }
+}
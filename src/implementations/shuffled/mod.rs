@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod permutation;
+mod shuffled_iter;
+mod source;
+
+pub use con_iter::ConIterShuffled;
+pub use permutation::Permutation;
+pub use shuffled_iter::ShuffledIter;
+pub use source::ShuffleSource;
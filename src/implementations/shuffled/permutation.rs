@@ -0,0 +1,96 @@
+const NUM_ROUNDS: usize = 4;
+
+/// A pseudo-random bijection of the positions `0..len` to the indices `0..len`, which is fully
+/// determined by the length and a seed.
+///
+/// The permutation is computed on demand and in constant expected time for each position by a
+/// balanced Feistel network over the smallest power-of-four domain containing `0..len`, followed
+/// by cycle walking back into `0..len`. Therefore, no shuffled index vector is materialized.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::implementations::Permutation;
+///
+/// let permutation = Permutation::new(10, 42);
+///
+/// let mut indices: Vec<_> = (0..10).map(|position| permutation.get(position)).collect();
+/// assert_ne!(indices, (0..10).collect::<Vec<_>>());
+///
+/// indices.sort();
+/// assert_eq!(indices, (0..10).collect::<Vec<_>>());
+///
+/// // deterministic for a given seed
+/// assert_eq!(Permutation::new(10, 42).get(3), permutation.get(3));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Permutation {
+    len: usize,
+    half_bits: u32,
+    keys: [u64; NUM_ROUNDS],
+}
+
+impl Permutation {
+    /// Creates the permutation of `0..len` defined by the `seed`.
+    pub fn new(len: usize, seed: u64) -> Self {
+        let num_bits = usize::BITS - len.saturating_sub(1).leading_zeros();
+        let half_bits = num_bits.div_ceil(2).max(1);
+
+        let mut state = seed;
+        let keys = core::array::from_fn(|_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            mix(state)
+        });
+
+        Self {
+            len,
+            half_bits,
+            keys,
+        }
+    }
+
+    /// Length of the permuted range.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the permuted range is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the index that the `position` is mapped to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is not less than the length of the permutation.
+    pub fn get(&self, position: usize) -> usize {
+        assert!(position < self.len, "position must be within 0..len");
+        // the feistel network is a bijection on the domain; hence, walking the cycle starting at
+        // position returns to 0..len, which makes the walk a bijection on 0..len as well
+        let mut x = position as u64;
+        loop {
+            x = self.feistel(x);
+            if x < self.len as u64 {
+                return x as usize;
+            }
+        }
+    }
+
+    fn feistel(&self, x: u64) -> u64 {
+        let mask = (1u64 << self.half_bits) - 1;
+        let (mut left, mut right) = (x >> self.half_bits, x & mask);
+        for key in &self.keys {
+            let f = mix(right ^ key) & mask;
+            (left, right) = (right, left ^ f);
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+/// Finalizer of splitmix64.
+fn mix(x: u64) -> u64 {
+    let x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    let x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,89 @@
+use super::{permutation::Permutation, source::ShuffleSource};
+use core::{iter::FusedIterator, ops::Range};
+
+/// An exact-size iterator over the elements of a source at a range of positions of a permutation.
+///
+/// It is the type of the chunks pulled from a [`ConIterShuffled`] and the sequential iterator that
+/// it is converted into.
+///
+/// [`ConIterShuffled`]: crate::implementations::ConIterShuffled
+pub struct ShuffledIter<S>
+where
+    S: ShuffleSource,
+{
+    source: Option<S>,
+    permutation: Permutation,
+    positions: Range<usize>,
+}
+
+impl<S> Default for ShuffledIter<S>
+where
+    S: ShuffleSource,
+{
+    fn default() -> Self {
+        Self {
+            source: None,
+            permutation: Default::default(),
+            positions: 0..0,
+        }
+    }
+}
+
+impl<S> ShuffledIter<S>
+where
+    S: ShuffleSource,
+{
+    pub(super) fn new(source: &S, permutation: &Permutation, positions: Range<usize>) -> Self {
+        Self {
+            source: Some(source.clone()),
+            permutation: permutation.clone(),
+            positions,
+        }
+    }
+
+    /// Converts the iterator into one which yields the elements together with their original
+    /// indices in the source.
+    pub fn with_indices(self) -> impl ExactSizeIterator<Item = (usize, S::Item)> {
+        let mut iter = self;
+        (0..iter.positions.len()).map(move |_| iter.next_present_with_idx())
+    }
+
+    fn next_present_with_idx(&mut self) -> (usize, S::Item) {
+        self.next_with_idx()
+            .expect("called only while there are remaining positions")
+    }
+
+    fn next_with_idx(&mut self) -> Option<(usize, S::Item)> {
+        let source = self.source.as_ref()?;
+        self.positions.next().map(|position| {
+            let idx = self.permutation.get(position);
+            (idx, source.get(idx))
+        })
+    }
+}
+
+impl<S> Iterator for ShuffledIter<S>
+where
+    S: ShuffleSource,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_idx().map(|x| x.1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<S> ExactSizeIterator for ShuffledIter<S>
+where
+    S: ShuffleSource,
+{
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+impl<S> FusedIterator for ShuffledIter<S> where S: ShuffleSource {}
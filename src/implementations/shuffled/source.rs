@@ -0,0 +1,46 @@
+use crate::implementations::RangeElement;
+use core::ops::Range;
+
+/// A random access source of elements which can be iterated in a shuffled order by
+/// [`ConIterShuffled`].
+///
+/// It is implemented for slices, yielding references to the elements, and for ranges of integers,
+/// yielding the integers.
+///
+/// [`ConIterShuffled`]: crate::implementations::ConIterShuffled
+pub trait ShuffleSource: Clone + Sync {
+    /// Type of the elements of the source.
+    type Item: Send;
+
+    /// Number of elements of the source.
+    fn num_elements(&self) -> usize;
+
+    /// Returns the element at the given `idx` which is less than [`num_elements`].
+    ///
+    /// [`num_elements`]: ShuffleSource::num_elements
+    fn get(&self, idx: usize) -> Self::Item;
+}
+
+impl<'a, T: Sync> ShuffleSource for &'a [T] {
+    type Item = &'a T;
+
+    fn num_elements(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, idx: usize) -> Self::Item {
+        &self[idx]
+    }
+}
+
+impl<T: RangeElement> ShuffleSource for Range<T> {
+    type Item = T;
+
+    fn num_elements(&self) -> usize {
+        T::len_of_range(self.start, self.end)
+    }
+
+    fn get(&self, idx: usize) -> Self::Item {
+        self.start.add_offset(idx)
+    }
+}
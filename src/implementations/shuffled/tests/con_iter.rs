@@ -0,0 +1,87 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::shuffled::ConIterShuffled, iter, pullers::ChunkPuller,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn enumeration() {
+    let values = ['a', 'b', 'c', 'd'];
+    let con_iter = iter::shuffled(values.as_slice(), 3);
+    let permutation = con_iter.permutation().clone();
+    assert_eq!(con_iter.len(), 4);
+    for position in 0..4 {
+        let idx = permutation.get(position);
+        assert_eq!(con_iter.next_with_idx(), Some((idx, &values[idx])));
+    }
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.len(), 0);
+}
+
+#[test]
+fn signed_range() {
+    let con_iter = ConIterShuffled::new(-5i32..5, 11);
+    let mut values: Vec<_> = con_iter.into_seq_iter().collect();
+    values.sort();
+    assert_eq!(values, (-5..5).collect::<Vec<_>>());
+}
+
+#[test]
+fn skip_to_end() {
+    let con_iter = iter::shuffled(0..100, 0);
+    let _ = con_iter.next();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn into_seq_iter() {
+    let all: Vec<_> = iter::shuffled(0..50, 9).into_seq_iter().collect();
+    let con_iter = iter::shuffled(0..50, 9);
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(5).pull();
+    let rest: Vec<_> = con_iter.into_seq_iter().collect();
+    assert_eq!(rest, all[6..]);
+}
+
+#[test_matrix([0, 1, 100, N], [1, 4], [0, 1, 64])]
+fn shuffled(n: usize, nt: usize, chunk: usize) {
+    let values: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+    let con_iter = iter::shuffled(values.as_slice(), n as u64);
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some((first_idx, chunk)) = puller.pull_with_idx() {
+                        for (i, (idx, x)) in chunk.with_indices().enumerate() {
+                            if i == 0 {
+                                assert_eq!(idx, first_idx);
+                            }
+                            bag.push((idx, x));
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    let expected: Vec<_> = values.iter().enumerate().collect();
+    assert_eq!(collected, expected);
+}
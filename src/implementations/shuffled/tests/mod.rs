@@ -0,0 +1,2 @@
+mod con_iter;
+mod permutation;
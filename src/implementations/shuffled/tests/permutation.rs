@@ -0,0 +1,31 @@
+use crate::implementations::shuffled::Permutation;
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[test_matrix([0, 1, 2, 3, 4, 5, 17, 64, 100, 1000, 4735], [0, 1, 42])]
+fn bijection(len: usize, seed: u64) {
+    let permutation = Permutation::new(len, seed);
+    assert_eq!(permutation.len(), len);
+    let mut indices: Vec<_> = (0..len).map(|p| permutation.get(p)).collect();
+    indices.sort();
+    assert_eq!(indices, (0..len).collect::<Vec<_>>());
+}
+
+#[test]
+fn deterministic() {
+    let a = Permutation::new(1000, 7);
+    let b = Permutation::new(1000, 7);
+    let c = Permutation::new(1000, 8);
+    let a: Vec<_> = (0..1000).map(|p| a.get(p)).collect();
+    let b: Vec<_> = (0..1000).map(|p| b.get(p)).collect();
+    let c: Vec<_> = (0..1000).map(|p| c.get(p)).collect();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(a, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let _ = Permutation::new(10, 0).get(10);
+}
@@ -1,5 +1,8 @@
 use super::chunk_puller::ChunkPullerSlice;
-use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use crate::{
+    ResettableConcurrentIter, SplittableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+};
 use core::{
     iter::Skip,
     sync::atomic::{AtomicUsize, Ordering},
@@ -37,6 +40,7 @@ use core::{
 /// ```
 pub struct ConIterSlice<'a, T> {
     slice: &'a [T],
+    offset: usize,
     counter: AtomicUsize,
 }
 
@@ -48,8 +52,15 @@ impl<T> Default for ConIterSlice<'_, T> {
 
 impl<'a, T> ConIterSlice<'a, T> {
     pub(crate) fn new(slice: &'a [T]) -> Self {
+        Self::with_offset(slice, 0)
+    }
+
+    /// Creates the iterator over a part of a source starting at position `offset`, which is added
+    /// to the reported indices.
+    fn with_offset(slice: &'a [T], offset: usize) -> Self {
         Self {
             slice,
+            offset,
             counter: 0.into(),
         }
     }
@@ -72,7 +83,7 @@ impl<'a, T> ConIterSlice<'a, T> {
                 let end_idx = (begin_idx + chunk_size)
                     .min(self.slice.len())
                     .max(begin_idx);
-                (begin_idx, &self.slice[begin_idx..end_idx])
+                (self.offset + begin_idx, &self.slice[begin_idx..end_idx])
             })
     }
 }
@@ -106,7 +117,7 @@ where
 
     fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
         self.progress_and_get_begin_idx(1)
-            .map(|idx| (idx, &self.slice[idx]))
+            .map(|idx| (self.offset + idx, &self.slice[idx]))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -129,3 +140,38 @@ where
         self.slice.len().saturating_sub(num_taken)
     }
 }
+
+impl<T> SplittableConcurrentIter for ConIterSlice<'_, T>
+where
+    T: Sync,
+{
+    type Part = Self;
+
+    fn split_at(self, mid: usize) -> (Self::Part, Self::Part) {
+        let num_taken = self.counter.load(Ordering::Acquire).min(self.slice.len());
+        let remaining = &self.slice[num_taken..];
+        assert!(
+            mid <= remaining.len(),
+            "mid must not exceed the remaining length"
+        );
+        let (left, right) = remaining.split_at(mid);
+        let offset = self.offset + num_taken;
+        (
+            Self::with_offset(left, offset),
+            Self::with_offset(right, offset + mid),
+        )
+    }
+}
+
+impl<T> ResettableConcurrentIter for ConIterSlice<'_, T>
+where
+    T: Sync,
+{
+    fn reset(&mut self) {
+        *self.counter.get_mut() = 0;
+    }
+
+    fn restarted(&self) -> Self {
+        Self::with_offset(self.slice, self.offset)
+    }
+}
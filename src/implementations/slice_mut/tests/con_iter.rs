@@ -162,7 +162,7 @@ fn next_with_idx(n: usize, nt: usize) {
 
                 while let Some(x) = iter.next_with_idx() {
                     _ = iter.size_hint();
-                    assert_eq!(x.0 + 10, x.1.parse::<usize>().unwrap());
+                    assert_eq!(x.0 + 10, x.1.parse::<usize>().expect(""));
                     x.1.push('!');
                 }
             });
@@ -213,7 +213,7 @@ fn item_puller_with_idx(n: usize, nt: usize) {
 
                 for x in iter.item_puller_with_idx() {
                     _ = iter.size_hint();
-                    assert_eq!(x.0 + 10, x.1.parse::<usize>().unwrap());
+                    assert_eq!(x.0 + 10, x.1.parse::<usize>().expect(""));
                     x.1.push('!');
                 }
             });
@@ -271,7 +271,7 @@ fn chunk_puller_with_idx(n: usize, nt: usize) {
                 while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
                     assert!(chunk.len() <= 7);
                     for (i, x) in chunk.enumerate() {
-                        assert_eq!(begin_idx + i + 10, x.parse::<usize>().unwrap());
+                        assert_eq!(begin_idx + i + 10, x.parse::<usize>().expect(""));
                         x.push('!');
                     }
                 }
@@ -321,7 +321,7 @@ fn flattened_chunk_puller_with_idx(n: usize, nt: usize) {
                 while num_spawned.len() < nt {} // allow all threads to be spawned
 
                 for x in iter.chunk_puller(7).flattened_with_idx() {
-                    assert_eq!(x.0 + 10, x.1.parse::<usize>().unwrap());
+                    assert_eq!(x.0 + 10, x.1.parse::<usize>().expect(""));
                     x.1.push('!');
                 }
             });
@@ -353,7 +353,7 @@ fn skip_to_end(n: usize, nt: usize) {
                     0 => {
                         while let Some(num) = con_iter.next() {
                             match num.parse::<usize>().expect("") < until + 10 {
-                                true => _ = num.push('!'),
+                                true => num.push('!'),
                                 false => con_iter.skip_to_end(),
                             }
                         }
@@ -361,7 +361,7 @@ fn skip_to_end(n: usize, nt: usize) {
                     _ => {
                         for num in con_iter.chunk_puller(7).flattened() {
                             match num.parse::<usize>().expect("") < until + 10 {
-                                true => _ = num.push('!'),
+                                true => num.push('!'),
                                 false => con_iter.skip_to_end(),
                             }
                         }
@@ -0,0 +1,48 @@
+use super::{con_iter::ConIterSliceViews, views::SliceViews, views_iter::SliceViewsIter};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerSliceViews<'i, 'a, T, V>
+where
+    V: SliceViews,
+{
+    con_iter: &'i ConIterSliceViews<'a, T, V>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T, V> ChunkPullerSliceViews<'i, 'a, T, V>
+where
+    V: SliceViews,
+{
+    pub(super) fn new(con_iter: &'i ConIterSliceViews<'a, T, V>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, T, V> ChunkPuller for ChunkPullerSliceViews<'_, 'a, T, V>
+where
+    V: SliceViews,
+{
+    type ChunkItem = &'a [T];
+
+    type Chunk<'c>
+        = SliceViewsIter<'a, T, V>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
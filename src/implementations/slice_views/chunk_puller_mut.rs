@@ -0,0 +1,38 @@
+use super::{chunks_mut_iter::SliceChunksMutIter, con_iter_mut::ConIterSliceChunksMut};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerSliceChunksMut<'i, 'a, T> {
+    con_iter: &'i ConIterSliceChunksMut<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T> ChunkPullerSliceChunksMut<'i, 'a, T> {
+    pub(super) fn new(con_iter: &'i ConIterSliceChunksMut<'a, T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, T> ChunkPuller for ChunkPullerSliceChunksMut<'_, 'a, T> {
+    type ChunkItem = &'a mut [T];
+
+    type Chunk<'c>
+        = SliceChunksMutIter<'a, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        let chunk = unsafe { self.con_iter.progress_and_get_chunk(self.chunk_size) };
+        chunk.map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        unsafe { self.con_iter.progress_and_get_chunk(self.chunk_size) }
+    }
+}
@@ -0,0 +1,46 @@
+use core::{iter::FusedIterator, slice::ChunksMut};
+
+/// An exact-size iterator over mutable chunks of a slice.
+///
+/// It is the type of the chunks pulled from a [`ConIterSliceChunksMut`] and the sequential
+/// iterator that it is converted into.
+///
+/// [`ConIterSliceChunksMut`]: crate::implementations::ConIterSliceChunksMut
+pub struct SliceChunksMutIter<'a, T> {
+    chunks: Option<ChunksMut<'a, T>>,
+}
+
+impl<T> Default for SliceChunksMutIter<'_, T> {
+    fn default() -> Self {
+        Self { chunks: None }
+    }
+}
+
+impl<'a, T> SliceChunksMutIter<'a, T> {
+    pub(super) fn new(slice: &'a mut [T], chunk_size: usize) -> Self {
+        Self {
+            chunks: Some(slice.chunks_mut(chunk_size)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for SliceChunksMutIter<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.as_mut().and_then(|x| x.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for SliceChunksMutIter<'_, T> {
+    fn len(&self) -> usize {
+        self.chunks.as_ref().map(|x| x.len()).unwrap_or(0)
+    }
+}
+
+impl<T> FusedIterator for SliceChunksMutIter<'_, T> {}
@@ -0,0 +1,36 @@
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterSliceChunksMut, ConIterSliceViews, SliceViews},
+};
+use core::fmt::Debug;
+
+impl<T, V> Debug for ConIterSliceViews<'_, T, V>
+where
+    T: Sync,
+    V: SliceViews,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.num_views() - remaining;
+        f.debug_struct("ConIterSliceViews")
+            .field("initial_len", &self.num_views())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+impl<T> Debug for ConIterSliceChunksMut<'_, T>
+where
+    T: Send,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.num_chunks() - remaining;
+        f.debug_struct("ConIterSliceChunksMut")
+            .field("initial_len", &self.num_chunks())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
@@ -0,0 +1,212 @@
+use super::{
+    chunk_puller::ChunkPullerSliceViews,
+    views::{ByChunks, ByChunksExact, ByRChunks, ByWindows, SliceViews},
+    views_iter::SliceViewsIter,
+};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator over subslices, or views, of a slice; such as its [`chunks`],
+/// [`chunks_exact`], [`windows`] or [`rchunks`].
+///
+/// Each view is identified by its position, which is used as the index of the view. Ranges of the
+/// views are computed from their indices; therefore, pulling one or more views requires a single
+/// atomic fetch, exactly as [`ConIterSlice`] does for elements.
+///
+/// [`chunks`]: ConIterSliceViews::chunks
+/// [`chunks_exact`]: ConIterSliceViews::chunks_exact
+/// [`windows`]: ConIterSliceViews::windows
+/// [`rchunks`]: ConIterSliceViews::rchunks
+/// [`ConIterSlice`]: crate::implementations::ConIterSlice
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::ConIterSliceViews;
+///
+/// let values = [1, 2, 3, 4, 5];
+///
+/// let con_iter = ConIterSliceViews::chunks(&values, 2);
+/// assert_eq!(con_iter.next(), Some(&values[0..2]));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, &values[2..4])));
+/// assert_eq!(con_iter.next(), Some(&values[4..5]));
+/// assert_eq!(con_iter.next(), None);
+///
+/// let con_iter = ConIterSliceViews::windows(&values, 3);
+/// let sums: Vec<i32> = con_iter.chunk_puller(2).flattened().map(|w| w.iter().sum()).collect();
+/// assert_eq!(sums, [6, 9, 12]);
+///
+/// let con_iter = ConIterSliceViews::rchunks(&values, 2);
+/// let chunks: Vec<_> = con_iter.into_seq_iter().collect();
+/// assert_eq!(chunks, [&[4, 5][..], &[2, 3], &[1]]);
+/// ```
+pub struct ConIterSliceViews<'a, T, V>
+where
+    V: SliceViews,
+{
+    slice: &'a [T],
+    views: V,
+    num_views: usize,
+    counter: AtomicUsize,
+}
+
+impl<'a, T> ConIterSliceViews<'a, T, ByChunks> {
+    /// Creates a concurrent iterator over the chunks of `chunk_size` elements of the `slice`, as
+    /// in [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks(slice: &'a [T], chunk_size: usize) -> Self {
+        Self::new(slice, ByChunks::new(chunk_size))
+    }
+}
+
+impl<'a, T> ConIterSliceViews<'a, T, ByChunksExact> {
+    /// Creates a concurrent iterator over the chunks of exactly `chunk_size` elements of the
+    /// `slice`, as in [`slice::chunks_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks_exact(slice: &'a [T], chunk_size: usize) -> Self {
+        Self::new(slice, ByChunksExact::new(chunk_size))
+    }
+
+    /// Returns the elements at the end of the slice which are not yielded since they do not fill
+    /// a chunk.
+    pub fn remainder(&self) -> &'a [T] {
+        let begin = self.num_views * self.views.size();
+        &self.slice[begin..]
+    }
+}
+
+impl<'a, T> ConIterSliceViews<'a, T, ByWindows> {
+    /// Creates a concurrent iterator over the overlapping windows of `window_size` elements of the
+    /// `slice`, as in [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is zero.
+    pub fn windows(slice: &'a [T], window_size: usize) -> Self {
+        Self::new(slice, ByWindows::new(window_size))
+    }
+}
+
+impl<'a, T> ConIterSliceViews<'a, T, ByRChunks> {
+    /// Creates a concurrent iterator over the chunks of `chunk_size` elements of the `slice`
+    /// starting from its end, as in [`slice::rchunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn rchunks(slice: &'a [T], chunk_size: usize) -> Self {
+        Self::new(slice, ByRChunks::new(chunk_size))
+    }
+}
+
+impl<'a, T, V> ConIterSliceViews<'a, T, V>
+where
+    V: SliceViews,
+{
+    /// Creates a concurrent iterator over the views of the `slice` defined by `views`.
+    pub fn new(slice: &'a [T], views: V) -> Self {
+        Self {
+            num_views: views.num_views(slice.len()),
+            slice,
+            views,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn num_views(&self) -> usize {
+        self.num_views
+    }
+
+    fn view(&self, idx: usize) -> &'a [T] {
+        &self.slice[self.views.range_of(self.slice.len(), idx)]
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.num_views {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    fn indices(&self, begin_idx: usize, chunk_size: usize) -> Range<usize> {
+        let end_idx = (begin_idx + chunk_size).min(self.num_views).max(begin_idx);
+        begin_idx..end_idx
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, SliceViewsIter<'a, T, V>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let indices = self.indices(begin_idx, chunk_size);
+                let chunk = SliceViewsIter::new(self.slice, &self.views, indices);
+                (begin_idx, chunk)
+            })
+    }
+}
+
+impl<'a, T, V> ConcurrentIter for ConIterSliceViews<'a, T, V>
+where
+    T: Sync,
+    V: SliceViews,
+{
+    type Item = &'a [T];
+
+    type SequentialIter = SliceViewsIter<'a, T, V>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerSliceViews<'i, 'a, T, V>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let current = self.counter.load(Ordering::Acquire).min(self.num_views);
+        SliceViewsIter::new(self.slice, &self.views, current..self.num_views)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.num_views, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1).map(|idx| self.view(idx))
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, self.view(idx)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.num_views.saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T, V> ExactSizeConcurrentIter for ConIterSliceViews<'_, T, V>
+where
+    T: Sync,
+    V: SliceViews,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.num_views.saturating_sub(num_taken)
+    }
+}
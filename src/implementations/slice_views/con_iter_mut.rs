@@ -0,0 +1,165 @@
+use super::{chunk_puller_mut::ChunkPullerSliceChunksMut, chunks_mut_iter::SliceChunksMutIter};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator over the mutable chunks of a slice, as in [`slice::chunks_mut`].
+///
+/// Each chunk is identified by its position, which is used as the index of the chunk. Pulling one
+/// or more chunks requires a single atomic fetch, exactly as the concurrent iterator of a mutable
+/// slice does for its elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::ConIterSliceChunksMut;
+///
+/// let mut values = vec![1, 2, 3, 4, 5];
+///
+/// let con_iter = ConIterSliceChunksMut::new(&mut values, 2);
+/// std::thread::scope(|s| {
+///     for _ in 0..2 {
+///         s.spawn(|| {
+///             while let Some((idx, chunk)) = con_iter.next_with_idx() {
+///                 chunk.iter_mut().for_each(|x| *x *= 10 * (idx + 1));
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(values, [10, 20, 60, 80, 150]);
+/// ```
+pub struct ConIterSliceChunksMut<'a, T>
+where
+    T: 'a,
+{
+    _slice: PhantomData<&'a ()>,
+    slice_len: usize,
+    p: *mut T,
+    chunk_size: usize,
+    num_chunks: usize,
+    counter: AtomicUsize,
+}
+
+unsafe impl<'a, T: Send + 'a> Sync for ConIterSliceChunksMut<'a, T> {}
+
+unsafe impl<'a, T: Send + 'a> Send for ConIterSliceChunksMut<'a, T> {}
+
+impl<'a, T: 'a> ConIterSliceChunksMut<'a, T> {
+    /// Creates a concurrent iterator over the mutable chunks of `chunk_size` elements of the
+    /// `slice`; the last chunk might be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new(slice: &'a mut [T], chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "size of slice views must be positive");
+        Self {
+            p: slice.as_mut_ptr(),
+            slice_len: slice.len(),
+            chunk_size,
+            num_chunks: slice.len().div_ceil(chunk_size),
+            _slice: PhantomData,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn num_chunks(&self) -> usize {
+        self.num_chunks
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.num_chunks {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    /// # SAFETY
+    ///
+    /// The chunks `begin_idx..end_idx` must not be handed out more than once.
+    unsafe fn chunks(&self, begin_idx: usize, end_idx: usize) -> &'a mut [T] {
+        let begin = (begin_idx * self.chunk_size).min(self.slice_len);
+        let end = (end_idx * self.chunk_size).min(self.slice_len);
+        let ptr = unsafe { self.p.add(begin) };
+        unsafe { core::slice::from_raw_parts_mut(ptr, end - begin) }
+    }
+
+    /// Reserves the next `chunk_size` chunks by the atomic counter and returns them as a single mutable
+    /// slice with the lifetime `'a` of the underlying slice, together with the index of the first chunk.
+    ///
+    /// # SAFETY
+    ///
+    /// The returned slice is not tied to the borrow of `self`. The caller must guarantee that the chunks
+    /// reserved by the counter are not handed out again by other means, such as by resetting or
+    /// decreasing the counter, while the returned slice is alive.
+    pub(super) unsafe fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, SliceChunksMutIter<'a, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size).min(self.num_chunks);
+                let slice = unsafe { self.chunks(begin_idx, end_idx) };
+                (begin_idx, SliceChunksMutIter::new(slice, self.chunk_size))
+            })
+    }
+}
+
+impl<'a, T: 'a> ConcurrentIter for ConIterSliceChunksMut<'a, T>
+where
+    T: Send,
+{
+    type Item = &'a mut [T];
+
+    type SequentialIter = SliceChunksMutIter<'a, T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerSliceChunksMut<'i, 'a, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let current = self.counter.load(Ordering::Acquire).min(self.num_chunks);
+        let slice = unsafe { self.chunks(current, self.num_chunks) };
+        SliceChunksMutIter::new(slice, self.chunk_size)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.num_chunks, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| unsafe { self.chunks(idx, idx + 1) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, unsafe { self.chunks(idx, idx + 1) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.num_chunks.saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterSliceChunksMut<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.num_chunks.saturating_sub(num_taken)
+    }
+}
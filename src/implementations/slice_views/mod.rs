@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod chunk_puller_mut;
+mod chunks_mut_iter;
+mod common_traits;
+mod con_iter;
+mod con_iter_mut;
+mod views;
+mod views_iter;
+
+pub use chunks_mut_iter::SliceChunksMutIter;
+pub use con_iter::ConIterSliceViews;
+pub use con_iter_mut::ConIterSliceChunksMut;
+pub use views::{ByChunks, ByChunksExact, ByRChunks, ByWindows, SliceViews};
+pub use views_iter::SliceViewsIter;
@@ -0,0 +1,151 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::slice_views::{ConIterSliceViews, SliceViews},
+    pullers::ChunkPuller,
+};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn collect<'a, V>(
+    con_iter: &ConIterSliceViews<'a, usize, V>,
+    nt: usize,
+    chunk: usize,
+) -> Vec<&'a [usize]>
+where
+    V: SliceViews,
+{
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                        for (i, x) in chunk.enumerate() {
+                            bag.push((begin_idx + i, x));
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    collected
+        .into_iter()
+        .enumerate()
+        .map(|(i, (idx, x))| {
+            assert_eq!(i, idx);
+            x
+        })
+        .collect()
+}
+
+#[test]
+fn enumeration() {
+    let values: Vec<_> = (0..7).collect();
+    let con_iter = ConIterSliceViews::chunks(&values, 3);
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next(), Some(&values[0..3]));
+    assert_eq!(con_iter.next_with_idx(), Some((1, &values[3..6])));
+    assert_eq!(con_iter.next(), Some(&values[6..]));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = ConIterSliceViews::chunks_exact(&values, 3);
+    assert_eq!(con_iter.remainder(), &[6]);
+    assert_eq!(con_iter.next(), Some(&values[0..3]));
+    assert_eq!(con_iter.next_with_idx(), Some((1, &values[3..6])));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = ConIterSliceViews::rchunks(&values, 3);
+    assert_eq!(con_iter.next(), Some(&values[4..7]));
+    assert_eq!(con_iter.next_with_idx(), Some((1, &values[1..4])));
+    assert_eq!(con_iter.next(), Some(&values[0..1]));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = ConIterSliceViews::windows(&values, 6);
+    assert_eq!(con_iter.next(), Some(&values[0..6]));
+    assert_eq!(con_iter.next_with_idx(), Some((1, &values[1..7])));
+    assert_eq!(con_iter.next(), None);
+}
+
+#[test]
+fn short_slices() {
+    let values = [1, 2];
+    assert_eq!(ConIterSliceViews::windows(&values, 3).len(), 0);
+    assert_eq!(ConIterSliceViews::chunks_exact(&values, 3).len(), 0);
+    assert_eq!(ConIterSliceViews::chunks(&values, 3).len(), 1);
+    assert_eq!(ConIterSliceViews::rchunks(&values, 3).len(), 1);
+
+    let empty: [i32; 0] = [];
+    assert_eq!(ConIterSliceViews::windows(&empty, 1).next(), None);
+    assert_eq!(ConIterSliceViews::chunks(&empty, 1).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn zero_size() {
+    let _ = ConIterSliceViews::chunks(&[1, 2], 0);
+}
+
+#[test]
+fn skip_to_end() {
+    let values: Vec<_> = (0..100).collect();
+    let con_iter = ConIterSliceViews::windows(&values, 4);
+    let _ = con_iter.next();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.len(), 0);
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn into_seq_iter() {
+    let values: Vec<_> = (0..100).collect();
+    let con_iter = ConIterSliceViews::chunks(&values, 7);
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(3).pull();
+    let rest: Vec<_> = con_iter.into_seq_iter().collect();
+    let expected: Vec<_> = values.chunks(7).skip(4).collect();
+    assert_eq!(rest, expected);
+}
+
+#[test_matrix([0, 1, 100, N], [1, 4], [0, 1, 64], [1, 3, 64])]
+fn views(n: usize, nt: usize, chunk: usize, size: usize) {
+    let values: Vec<_> = (0..n).collect();
+
+    let con_iter = ConIterSliceViews::chunks(&values, size);
+    assert_eq!(
+        collect(&con_iter, nt, chunk),
+        values.chunks(size).collect::<Vec<_>>()
+    );
+
+    let con_iter = ConIterSliceViews::chunks_exact(&values, size);
+    let expected: Vec<_> = values.chunks_exact(size).collect();
+    assert_eq!(con_iter.remainder(), values.chunks_exact(size).remainder());
+    assert_eq!(collect(&con_iter, nt, chunk), expected);
+
+    let con_iter = ConIterSliceViews::windows(&values, size);
+    assert_eq!(
+        collect(&con_iter, nt, chunk),
+        values.windows(size).collect::<Vec<_>>()
+    );
+
+    let con_iter = ConIterSliceViews::rchunks(&values, size);
+    assert_eq!(
+        collect(&con_iter, nt, chunk),
+        values.rchunks(size).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,73 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::slice_views::ConIterSliceChunksMut, pullers::ChunkPuller,
+};
+use alloc::vec::Vec;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn enumeration() {
+    let mut values: Vec<_> = (0..5).collect();
+    let con_iter = ConIterSliceChunksMut::new(&mut values, 2);
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next().map(|x| x.to_vec()), Some(alloc::vec![0, 1]));
+    let (idx, chunk) = con_iter.next_with_idx().expect("");
+    assert_eq!(idx, 1);
+    chunk[0] = 42;
+    assert_eq!(con_iter.next().map(|x| x.len()), Some(1));
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(values, [0, 1, 42, 3, 4]);
+}
+
+#[test]
+fn into_seq_iter() {
+    let mut values: Vec<_> = (0..10).collect();
+    let con_iter = ConIterSliceChunksMut::new(&mut values, 3);
+    let _ = con_iter.next();
+    for chunk in con_iter.into_seq_iter() {
+        chunk.iter_mut().for_each(|x| *x = 0);
+    }
+    assert_eq!(values, [0, 1, 2, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn skip_to_end() {
+    let mut values: Vec<_> = (0..10).collect();
+    let con_iter = ConIterSliceChunksMut::new(&mut values, 3);
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test_matrix([0, 1, 100, N], [1, 4], [0, 1, 64], [1, 3, 64])]
+fn chunks_mut(n: usize, nt: usize, chunk: usize, size: usize) {
+    let mut values: Vec<_> = (0..n).collect();
+    let con_iter = ConIterSliceChunksMut::new(&mut values, size);
+    let mark = |idx: usize, chunk: &mut [usize]| chunk.iter_mut().for_each(|x| *x += idx * 100_000);
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some((idx, chunk)) = con_iter.next_with_idx() {
+                        mark(idx, chunk);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                        for (i, x) in chunk.enumerate() {
+                            mark(begin_idx + i, x);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let expected: Vec<_> = (0..n).map(|i| i + (i / size) * 100_000).collect();
+    assert_eq!(values, expected);
+}
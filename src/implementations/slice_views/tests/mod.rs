@@ -0,0 +1,2 @@
+mod con_iter;
+mod con_iter_mut;
@@ -0,0 +1,116 @@
+use core::ops::Range;
+
+/// Defines how a slice is split into subslices, or views, which are yielded by a
+/// [`ConIterSliceViews`].
+///
+/// The views are identified by their positions `0..num_views(len)`, which makes it possible to
+/// compute the range of any view from its index without scanning the slice.
+///
+/// [`ConIterSliceViews`]: crate::implementations::ConIterSliceViews
+pub trait SliceViews: Clone + Send + Sync {
+    /// Number of views of a slice with `len` elements.
+    fn num_views(&self, len: usize) -> usize;
+
+    /// Range of elements of the `idx`-th view of a slice with `len` elements where
+    /// `idx < self.num_views(len)`.
+    fn range_of(&self, len: usize, idx: usize) -> Range<usize>;
+}
+
+macro_rules! view_size {
+    ($name:ident) => {
+        impl $name {
+            /// Creates the view definition with the given `size`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `size` is zero.
+            pub fn new(size: usize) -> Self {
+                assert!(size > 0, "size of slice views must be positive");
+                Self { size }
+            }
+
+            /// Size of the views.
+            pub fn size(&self) -> usize {
+                self.size
+            }
+        }
+    };
+}
+
+/// Splits a slice into chunks of `size` elements, as in [`slice::chunks`]; the last chunk might be
+/// shorter.
+#[derive(Clone, Copy, Debug)]
+pub struct ByChunks {
+    size: usize,
+}
+
+view_size!(ByChunks);
+
+impl SliceViews for ByChunks {
+    fn num_views(&self, len: usize) -> usize {
+        len.div_ceil(self.size)
+    }
+
+    fn range_of(&self, len: usize, idx: usize) -> Range<usize> {
+        let begin = idx * self.size;
+        begin..(begin + self.size).min(len)
+    }
+}
+
+/// Splits a slice into chunks of exactly `size` elements, as in [`slice::chunks_exact`]; the
+/// remaining elements which do not fill a chunk are not yielded.
+#[derive(Clone, Copy, Debug)]
+pub struct ByChunksExact {
+    size: usize,
+}
+
+view_size!(ByChunksExact);
+
+impl SliceViews for ByChunksExact {
+    fn num_views(&self, len: usize) -> usize {
+        len / self.size
+    }
+
+    fn range_of(&self, _: usize, idx: usize) -> Range<usize> {
+        let begin = idx * self.size;
+        begin..(begin + self.size)
+    }
+}
+
+/// Splits a slice into overlapping windows of `size` elements, as in [`slice::windows`].
+#[derive(Clone, Copy, Debug)]
+pub struct ByWindows {
+    size: usize,
+}
+
+view_size!(ByWindows);
+
+impl SliceViews for ByWindows {
+    fn num_views(&self, len: usize) -> usize {
+        (len + 1).saturating_sub(self.size)
+    }
+
+    fn range_of(&self, _: usize, idx: usize) -> Range<usize> {
+        idx..(idx + self.size)
+    }
+}
+
+/// Splits a slice into chunks of `size` elements starting from the end of the slice, as in
+/// [`slice::rchunks`]; the last chunk, which is the beginning of the slice, might be shorter.
+#[derive(Clone, Copy, Debug)]
+pub struct ByRChunks {
+    size: usize,
+}
+
+view_size!(ByRChunks);
+
+impl SliceViews for ByRChunks {
+    fn num_views(&self, len: usize) -> usize {
+        len.div_ceil(self.size)
+    }
+
+    fn range_of(&self, len: usize, idx: usize) -> Range<usize> {
+        let end = len - idx * self.size;
+        end.saturating_sub(self.size)..end
+    }
+}
@@ -0,0 +1,85 @@
+use super::views::SliceViews;
+use core::{iter::FusedIterator, ops::Range};
+
+/// An exact-size iterator over a range of views of a slice.
+///
+/// It is the type of the chunks pulled from a [`ConIterSliceViews`] and the sequential iterator
+/// that it is converted into.
+///
+/// [`ConIterSliceViews`]: crate::implementations::ConIterSliceViews
+pub struct SliceViewsIter<'a, T, V>
+where
+    V: SliceViews,
+{
+    slice: &'a [T],
+    views: Option<V>,
+    indices: Range<usize>,
+}
+
+impl<T, V> Default for SliceViewsIter<'_, T, V>
+where
+    V: SliceViews,
+{
+    fn default() -> Self {
+        Self {
+            slice: &[],
+            views: None,
+            indices: 0..0,
+        }
+    }
+}
+
+impl<'a, T, V> SliceViewsIter<'a, T, V>
+where
+    V: SliceViews,
+{
+    pub(super) fn new(slice: &'a [T], views: &V, indices: Range<usize>) -> Self {
+        Self {
+            slice,
+            views: Some(views.clone()),
+            indices,
+        }
+    }
+
+    fn view(&self, idx: usize) -> Option<&'a [T]> {
+        let slice = self.slice;
+        self.views
+            .as_ref()
+            .map(|views| &slice[views.range_of(slice.len(), idx)])
+    }
+}
+
+impl<'a, T, V> Iterator for SliceViewsIter<'a, T, V>
+where
+    V: SliceViews,
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().and_then(|idx| self.view(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T, V> DoubleEndedIterator for SliceViewsIter<'_, T, V>
+where
+    V: SliceViews,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().and_then(|idx| self.view(idx))
+    }
+}
+
+impl<T, V> ExactSizeIterator for SliceViewsIter<'_, T, V>
+where
+    V: SliceViews,
+{
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<T, V> FusedIterator for SliceViewsIter<'_, T, V> where V: SliceViews {}
@@ -0,0 +1,105 @@
+use super::segments::StrSegments;
+use core::iter::FusedIterator;
+
+/// An exact-size iterator over the consecutive segments of a string starting within a byte range.
+///
+/// It is the type of the chunks pulled from a [`ConIterStr`] and the sequential iterator that it
+/// is converted into.
+///
+/// [`ConIterStr`]: crate::implementations::ConIterStr
+pub struct StrChunk<'a, K>
+where
+    K: StrSegments,
+{
+    s: &'a str,
+    segments: Option<K>,
+    current: usize,
+    len: usize,
+}
+
+impl<K> Default for StrChunk<'_, K>
+where
+    K: StrSegments,
+{
+    fn default() -> Self {
+        Self {
+            s: "",
+            segments: None,
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<'a, K> StrChunk<'a, K>
+where
+    K: StrSegments,
+{
+    /// Creates the iterator over the segments starting within `begin..end` where both are
+    /// segment boundaries.
+    pub(super) fn new(s: &'a str, segments: &K, begin: usize, end: usize) -> Self {
+        Self {
+            s,
+            len: segments.count(s, begin, end),
+            segments: Some(segments.clone()),
+            current: begin,
+        }
+    }
+
+    /// Byte offset of the next segment to be yielded by the iterator; meaningful only if the
+    /// iterator is not yet consumed.
+    pub fn next_byte_offset(&self) -> usize {
+        self.current
+    }
+
+    /// Converts the iterator into one which yields the segments together with their byte offsets
+    /// in the string.
+    pub fn with_byte_offsets(self) -> impl ExactSizeIterator<Item = (usize, K::Item<'a>)> {
+        let mut iter = self;
+        (0..iter.len).map(move |_| {
+            let offset = iter.current;
+            let item = iter.next_item();
+            (offset, item)
+        })
+    }
+
+    fn next_item(&mut self) -> K::Item<'a> {
+        let segments = self
+            .segments
+            .as_ref()
+            .expect("non-empty chunk has segments");
+        let (item, next) = segments.segment(self.s, self.current);
+        self.current = next;
+        self.len -= 1;
+        item
+    }
+}
+
+impl<'a, K> Iterator for StrChunk<'a, K>
+where
+    K: StrSegments,
+{
+    type Item = K::Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.len {
+            0 => None,
+            _ => Some(self.next_item()),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K> ExactSizeIterator for StrChunk<'_, K>
+where
+    K: StrSegments,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<K> FusedIterator for StrChunk<'_, K> where K: StrSegments {}
@@ -0,0 +1,48 @@
+use super::{chunk::StrChunk, con_iter::ConIterStr, segments::StrSegments};
+use crate::pullers::ChunkPuller;
+
+pub struct ChunkPullerStr<'i, 'a, K>
+where
+    K: StrSegments,
+{
+    con_iter: &'i ConIterStr<'a, K>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, K> ChunkPullerStr<'i, 'a, K>
+where
+    K: StrSegments,
+{
+    pub(super) fn new(con_iter: &'i ConIterStr<'a, K>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, K> ChunkPuller for ChunkPullerStr<'_, 'a, K>
+where
+    K: StrSegments,
+{
+    type ChunkItem = K::Item<'a>;
+
+    type Chunk<'c>
+        = StrChunk<'a, K>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::implementations::{ConIterStr, StrSegments};
+use core::fmt::Debug;
+
+impl<K> Debug for ConIterStr<'_, K>
+where
+    K: StrSegments,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConIterStr")
+            .field("num_bytes", &self.num_bytes())
+            .field("byte_position", &self.byte_position())
+            .finish()
+    }
+}
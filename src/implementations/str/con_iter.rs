@@ -0,0 +1,191 @@
+use super::{
+    chunk::StrChunk,
+    chunk_puller::ChunkPullerStr,
+    segments::{ByChars, ByLines, BySeparator, StrSegments},
+};
+use crate::concurrent_iter::ConcurrentIter;
+use core::{
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator over the segments of a string; i.e., its [`chars`], [`lines`] or the
+/// segments [`split`] by a separator.
+///
+/// The byte range of the string is split into chunks, and each chunk boundary is moved forward to
+/// the start of the next segment; i.e., the next UTF-8 char boundary, the beginning of the next
+/// line or the position after the next separator. Therefore, the segments are never collected
+/// before the iteration.
+///
+/// # Indices and chunk sizes
+///
+/// Since the ordinal of a segment cannot be known without scanning the string up to the segment,
+/// the index reported by `next_with_idx` is the **byte offset** of the segment within the string,
+/// as in [`str::char_indices`]. Similarly, `pull_with_idx` returns the byte offset of the first
+/// segment of the chunk; byte offsets of the remaining segments can be obtained by
+/// [`StrChunk::with_byte_offsets`]. Note that indices of the elements of a chunk are not consecutive;
+/// hence, the indices provided by `flattened_with_idx` and `enumerate` are not meaningful for this
+/// iterator.
+///
+/// The chunk size of a chunk puller is the number of bytes that a chunk spans, before moving its end
+/// to the next segment boundary. Therefore, a chunk contains at least one segment, and a chunk of
+/// size one contains exactly one segment.
+///
+/// [`chars`]: ConIterStr::chars
+/// [`lines`]: ConIterStr::lines
+/// [`split`]: ConIterStr::split
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::ConIterStr;
+///
+/// let text = "first line\nsecond line\r\nthird line";
+///
+/// let con_iter = ConIterStr::lines(text);
+/// assert_eq!(con_iter.next(), Some("first line"));
+/// assert_eq!(con_iter.next_with_idx(), Some((11, "second line")));
+/// assert_eq!(con_iter.next(), Some("third line"));
+/// assert_eq!(con_iter.next(), None);
+///
+/// let con_iter = ConIterStr::split("a,bc,,d", ',');
+/// let segments: Vec<_> = con_iter.chunk_puller(2).flattened().collect();
+/// assert_eq!(segments, ["a", "bc", "", "d"]);
+///
+/// let con_iter = ConIterStr::chars("xöy");
+/// assert_eq!(con_iter.next_with_idx(), Some((0, 'x')));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 'ö')));
+/// assert_eq!(con_iter.next_with_idx(), Some((3, 'y')));
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub struct ConIterStr<'a, K>
+where
+    K: StrSegments,
+{
+    s: &'a str,
+    segments: K,
+    end: usize,
+    counter: AtomicUsize,
+}
+
+impl<'a> ConIterStr<'a, ByChars> {
+    /// Creates a concurrent iterator over the `char`s of the string `s`.
+    pub fn chars(s: &'a str) -> Self {
+        Self::new(s, ByChars)
+    }
+}
+
+impl<'a> ConIterStr<'a, ByLines> {
+    /// Creates a concurrent iterator over the lines of the string `s`, as in [`str::lines`].
+    pub fn lines(s: &'a str) -> Self {
+        Self::new(s, ByLines)
+    }
+}
+
+impl<'a> ConIterStr<'a, BySeparator> {
+    /// Creates a concurrent iterator over the segments of the string `s` which are separated by
+    /// `separator`, as in [`str::split`].
+    pub fn split(s: &'a str, separator: char) -> Self {
+        Self::new(s, BySeparator(separator))
+    }
+}
+
+impl<'a, K> ConIterStr<'a, K>
+where
+    K: StrSegments,
+{
+    /// Creates a concurrent iterator over the segments of the string `s` defined by `segments`.
+    pub fn new(s: &'a str, segments: K) -> Self {
+        Self {
+            s,
+            end: segments.end_of(s),
+            segments,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn num_bytes(&self) -> usize {
+        self.s.len()
+    }
+
+    pub(super) fn byte_position(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.end)
+    }
+
+    /// Reserves the segments starting within a range of at least `num_bytes` bytes, unless the end is
+    /// reached; returns None if all segments are already reserved.
+    fn progress_and_get_range(&self, num_bytes: usize) -> Option<Range<usize>> {
+        let mut begin = self.counter.load(Ordering::Acquire);
+        loop {
+            if begin >= self.end {
+                return None;
+            }
+            let end = match begin + num_bytes.max(1) {
+                x if x >= self.s.len() => self.end,
+                x => self.segments.align(self.s, x),
+            };
+            match self.counter.compare_exchange_weak(
+                begin,
+                end,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Some(begin..end),
+                Err(updated) => begin = updated,
+            }
+        }
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        num_bytes: usize,
+    ) -> Option<(usize, StrChunk<'a, K>)> {
+        self.progress_and_get_range(num_bytes).map(|range| {
+            let chunk = StrChunk::new(self.s, &self.segments, range.start, range.end);
+            (range.start, chunk)
+        })
+    }
+}
+
+impl<'a, K> ConcurrentIter for ConIterStr<'a, K>
+where
+    K: StrSegments,
+{
+    type Item = K::Item<'a>;
+
+    type SequentialIter = StrChunk<'a, K>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerStr<'i, 'a, K>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        StrChunk::new(self.s, &self.segments, self.byte_position(), self.end)
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.end, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_range(1)
+            .map(|range| self.segments.segment(self.s, range.start).0)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_range(1)
+            .map(|range| (range.start, self.segments.segment(self.s, range.start).0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // each remaining segment starts at a distinct remaining position
+        let remaining = self.end - self.byte_position();
+        (usize::from(remaining > 0), Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk;
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod segments;
+
+pub use chunk::StrChunk;
+pub use con_iter::ConIterStr;
+pub use segments::{ByChars, ByLines, BySeparator, StrSegments};
@@ -0,0 +1,168 @@
+/// Defines how a string is split into segments which are yielded by a [`ConIterStr`].
+///
+/// Each segment is identified by the byte position at which it starts. The concurrent iterator
+/// splits the byte range of the string into chunks and moves each chunk boundary forward to the
+/// start of the next segment using [`align`].
+///
+/// [`ConIterStr`]: crate::implementations::ConIterStr
+/// [`align`]: StrSegments::align
+pub trait StrSegments: Clone + Send + Sync {
+    /// Type of the segments.
+    type Item<'a>: Send + Sync;
+
+    /// Exclusive upper bound of the positions at which the segments of `s` start.
+    fn end_of(&self, s: &str) -> usize;
+
+    /// Returns the smallest position greater than or equal to `pos` at which a segment of `s` starts;
+    /// or [`end_of`] if there is no such segment.
+    ///
+    /// `pos` is positive and less than or equal to `s.len()`.
+    ///
+    /// [`end_of`]: StrSegments::end_of
+    fn align(&self, s: &str, pos: usize) -> usize;
+
+    /// Returns the number of segments starting within `begin..end` where both `begin` and `end`
+    /// are positions returned by [`align`] or [`end_of`].
+    ///
+    /// [`align`]: StrSegments::align
+    /// [`end_of`]: StrSegments::end_of
+    fn count(&self, s: &str, begin: usize, end: usize) -> usize;
+
+    /// Returns the segment starting at position `begin` together with the start of the next segment.
+    fn segment<'a>(&self, s: &'a str, begin: usize) -> (Self::Item<'a>, usize);
+}
+
+/// Splits a string into its `char`s, as in [`str::chars`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByChars;
+
+impl StrSegments for ByChars {
+    type Item<'a> = char;
+
+    fn end_of(&self, s: &str) -> usize {
+        s.len()
+    }
+
+    fn align(&self, s: &str, pos: usize) -> usize {
+        (pos..s.len())
+            .find(|i| s.is_char_boundary(*i))
+            .unwrap_or(s.len())
+    }
+
+    fn count(&self, s: &str, begin: usize, end: usize) -> usize {
+        // every byte except the utf-8 continuation bytes starts a char
+        let bytes = &s.as_bytes()[begin..end.min(s.len())];
+        bytes.iter().filter(|b| (**b as i8) >= -0x40).count()
+    }
+
+    fn segment<'a>(&self, s: &'a str, begin: usize) -> (Self::Item<'a>, usize) {
+        let c = s[begin..].chars().next().unwrap_or_default();
+        (c, begin + c.len_utf8())
+    }
+}
+
+/// Splits a string into its lines, as in [`str::lines`].
+///
+/// Lines are ended by `\n` or `\r\n`, which are not included in the yielded lines; and the final
+/// line ending is optional.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByLines;
+
+impl StrSegments for ByLines {
+    type Item<'a> = &'a str;
+
+    fn end_of(&self, s: &str) -> usize {
+        s.len()
+    }
+
+    fn align(&self, s: &str, pos: usize) -> usize {
+        match s.as_bytes()[pos - 1..].iter().position(|b| *b == b'\n') {
+            Some(i) => pos + i,
+            None => s.len(),
+        }
+    }
+
+    fn count(&self, s: &str, begin: usize, end: usize) -> usize {
+        match begin < end {
+            true => {
+                let last = (end - 1).min(s.len().saturating_sub(1));
+                1 + s.as_bytes()[begin..last]
+                    .iter()
+                    .filter(|b| **b == b'\n')
+                    .count()
+            }
+            false => 0,
+        }
+    }
+
+    fn segment<'a>(&self, s: &'a str, begin: usize) -> (Self::Item<'a>, usize) {
+        let rest = &s[begin..];
+        match rest.as_bytes().iter().position(|b| *b == b'\n') {
+            Some(i) => {
+                let line = &rest[..i];
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                (line, begin + i + 1)
+            }
+            None => (rest, s.len()),
+        }
+    }
+}
+
+/// Splits a string into the segments separated by the given separator, as in [`str::split`]
+/// with a `char` pattern.
+///
+/// Note that, similar to `str::split`, an empty string consists of a single empty segment, and a
+/// string ending with the separator has an empty segment at the end.
+#[derive(Clone, Copy, Debug)]
+pub struct BySeparator(pub char);
+
+impl Default for BySeparator {
+    fn default() -> Self {
+        Self(',')
+    }
+}
+
+impl BySeparator {
+    fn find(&self, s: &str, from: usize) -> Option<usize> {
+        s[from..].find(self.0).map(|i| from + i)
+    }
+}
+
+impl StrSegments for BySeparator {
+    type Item<'a> = &'a str;
+
+    fn end_of(&self, s: &str) -> usize {
+        // the last segment, which might be empty, starts after the last separator
+        s.len() + 1
+    }
+
+    fn align(&self, s: &str, pos: usize) -> usize {
+        // the segment starts right after the first separator which ends at or after pos
+        let from = ByChars.align(s, pos.saturating_sub(self.0.len_utf8()));
+        match self.find(s, from) {
+            Some(i) => i + self.0.len_utf8(),
+            None => self.end_of(s),
+        }
+    }
+
+    fn count(&self, s: &str, begin: usize, end: usize) -> usize {
+        match begin < end {
+            true => {
+                // segments after begin start right after the separators ending before end
+                let last = match end > s.len() {
+                    true => s.len(),
+                    false => end - self.0.len_utf8(),
+                };
+                1 + s[begin..last].matches(self.0).count()
+            }
+            false => 0,
+        }
+    }
+
+    fn segment<'a>(&self, s: &'a str, begin: usize) -> (Self::Item<'a>, usize) {
+        match self.find(s, begin) {
+            Some(i) => (&s[begin..i], i + self.0.len_utf8()),
+            None => (&s[begin..], self.end_of(s)),
+        }
+    }
+}
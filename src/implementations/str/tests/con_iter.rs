@@ -0,0 +1,165 @@
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::str::{ByChars, ByLines, BySeparator, ConIterStr, StrSegments},
+    pullers::ChunkPuller,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn text(n: usize) -> String {
+    let words = ["alpha", "ößü", "", "日本語", "x", "γδ,ε"];
+    let mut s = String::new();
+    for i in 0..n {
+        s.push_str(words[i % words.len()]);
+        match i % 7 {
+            0 => s.push_str("\r\n"),
+            1 | 4 => s.push('\n'),
+            2 => s.push(','),
+            3 => s.push_str(",\n"),
+            _ => s.push_str(&format!("{i}")),
+        }
+    }
+    s
+}
+
+fn collect<'a, K>(
+    con_iter: &ConIterStr<'a, K>,
+    nt: usize,
+    chunk: usize,
+) -> Vec<(usize, K::Item<'a>)>
+where
+    K: StrSegments,
+{
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    let mut puller = con_iter.chunk_puller(chunk);
+                    while let Some(chunk) = puller.pull() {
+                        let begin = chunk.next_byte_offset();
+                        assert!(chunk.len() > 0);
+                        for (i, x) in chunk.with_byte_offsets() {
+                            assert!(i >= begin);
+                            bag.push((i, x));
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    collected
+}
+
+#[test]
+fn enumeration() {
+    let con_iter = ConIterStr::chars("aé€b");
+    assert_eq!(con_iter.next_with_idx(), Some((0, 'a')));
+    assert_eq!(con_iter.next_with_idx(), Some((1, 'é')));
+    assert_eq!(con_iter.next_with_idx(), Some((3, '€')));
+    assert_eq!(con_iter.next(), Some('b'));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = ConIterStr::lines("a\r\n\nbc\n");
+    assert_eq!(con_iter.next_with_idx(), Some((0, "a")));
+    assert_eq!(con_iter.next_with_idx(), Some((3, "")));
+    assert_eq!(con_iter.next_with_idx(), Some((4, "bc")));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = ConIterStr::split(",a,", ',');
+    assert_eq!(con_iter.next_with_idx(), Some((0, "")));
+    assert_eq!(con_iter.next_with_idx(), Some((1, "a")));
+    assert_eq!(con_iter.next_with_idx(), Some((3, "")));
+    assert_eq!(con_iter.next(), None);
+}
+
+#[test]
+fn empty_str() {
+    assert_eq!(ConIterStr::chars("").next(), None);
+    assert_eq!(ConIterStr::lines("").next(), None);
+
+    let con_iter = ConIterStr::split("", ';');
+    assert_eq!(con_iter.next(), Some(""));
+    assert_eq!(con_iter.next(), None);
+}
+
+#[test]
+fn size_hint() {
+    let con_iter = ConIterStr::lines("ab\ncd");
+    assert_eq!(con_iter.size_hint(), (1, Some(5)));
+    let _ = con_iter.next();
+    assert_eq!(con_iter.size_hint(), (1, Some(2)));
+    let _ = con_iter.next();
+    assert_eq!(con_iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn skip_to_end() {
+    let s = text(100);
+    let con_iter = ConIterStr::split(&s, ',');
+    let _ = con_iter.next();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.chunk_puller(5).pull().map(|x| x.len()), None);
+    assert_eq!(con_iter.into_seq_iter().next(), None);
+}
+
+#[test]
+fn into_seq_iter() {
+    let s = text(100);
+    let con_iter = ConIterStr::lines(&s);
+    let first = con_iter.next();
+    let _ = con_iter.chunk_puller(10).pull();
+    let rest: Vec<_> = con_iter.into_seq_iter().collect();
+    let expected: Vec<_> = s.lines().collect();
+    assert_eq!(first, Some(expected[0]));
+    assert_eq!(rest, expected[(expected.len() - rest.len())..]);
+}
+
+#[test_matrix([0, 100, N], [1, 4], [0, 1, 7, 64])]
+fn chars(n: usize, nt: usize, chunk: usize) {
+    let s = text(n);
+    let expected: Vec<_> = s.char_indices().collect();
+    let con_iter = ConIterStr::new(&s, ByChars);
+    assert_eq!(collect(&con_iter, nt, chunk), expected);
+}
+
+#[test_matrix([0, 100, N], [1, 4], [0, 1, 7, 64])]
+fn lines(n: usize, nt: usize, chunk: usize) {
+    let s = text(n);
+    let expected: Vec<_> = s.lines().collect();
+    let con_iter = ConIterStr::new(&s, ByLines);
+    let collected: Vec<_> = collect(&con_iter, nt, chunk)
+        .into_iter()
+        .map(|x| x.1)
+        .collect();
+    assert_eq!(collected, expected);
+}
+
+#[test_matrix([0, 100, N], [1, 4], [0, 1, 7, 64], [',', 'ö', '\n'])]
+fn split(n: usize, nt: usize, chunk: usize, separator: char) {
+    let s = text(n);
+    let expected: Vec<_> = s.split(separator).collect();
+    let con_iter = ConIterStr::new(&s, BySeparator(separator));
+    let collected = collect(&con_iter, nt, chunk);
+    for (offset, x) in &collected {
+        assert!(s[*offset..].starts_with(x));
+    }
+    let collected: Vec<_> = collected.into_iter().map(|x| x.1).collect();
+    assert_eq!(collected, expected);
+}
@@ -1,4 +1,7 @@
-use crate::{ExactSizeConcurrentIter, implementations::ConIterVec};
+use crate::{
+    ExactSizeConcurrentIter,
+    implementations::{ConIterVec, ConIterVecPart},
+};
 use core::fmt::Debug;
 
 impl<T> Debug for ConIterVec<T>
@@ -15,3 +18,18 @@ where
             .finish()
     }
 }
+
+impl<T> Debug for ConIterVecPart<T>
+where
+    T: Send + Sync,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let remaining = self.len();
+        let num_taken = self.initial_len() - remaining;
+        f.debug_struct("ConIterVecPart")
+            .field("initial_len", &self.initial_len())
+            .field("num_taken", &num_taken)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
@@ -1,4 +1,6 @@
+use super::part::{ConIterVecPart, VecAllocation};
 use crate::{
+    SplittableConcurrentIter,
     concurrent_iter::ConcurrentIter,
     exact_size_concurrent_iter::ExactSizeConcurrentIter,
     implementations::{
@@ -6,7 +8,7 @@ use crate::{
         ptr_utils::take,
     },
 };
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use core::{
     mem::ManuallyDrop,
     sync::atomic::{AtomicUsize, Ordering},
@@ -74,6 +76,49 @@ impl<T> ConIterVec<T> {
         }
     }
 
+    /// Converts the concurrent iterator back into a vector containing the remaining elements which
+    /// are not yet taken, in their original order.
+    ///
+    /// The original allocation is reused: the remaining elements are moved to the front of the buffer
+    /// and the vector is rebuilt with the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let vec: Vec<_> = (0..10).collect();
+    /// let capacity = vec.capacity();
+    ///
+    /// let con_iter = vec.into_con_iter();
+    /// let _ = con_iter.next();
+    /// let _ = con_iter.chunk_puller(3).pull();
+    ///
+    /// let vec = con_iter.into_remaining_vec();
+    /// assert_eq!(vec, [4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(vec.capacity(), capacity);
+    /// ```
+    pub fn into_remaining_vec(mut self) -> Vec<T> {
+        match self.ptr.is_null() {
+            true => Vec::new(),
+            false => {
+                let num_taken = self.counter.load(Ordering::Acquire).min(self.vec_len);
+                let remaining = self.vec_len - num_taken;
+                let ptr = self.ptr as *mut T;
+                if num_taken > 0 && remaining > 0 {
+                    // SAFETY: both ranges are within bounds; and the remaining elements are not taken
+                    unsafe { core::ptr::copy(ptr.add(num_taken), ptr, remaining) };
+                }
+
+                // SAFETY: null ptr indicates that the data is already taken out of this iterator
+                self.ptr = core::ptr::null();
+
+                // SAFETY: the first `remaining` elements of the allocation are initialized
+                unsafe { Vec::from_raw_parts(ptr, remaining, self.vec_cap) }
+            }
+        }
+    }
+
     fn remaining_into_seq_iter(&mut self) -> ArrayIntoSeqIter<T, ()> {
         // # SAFETY
         // null ptr indicates that the data is already taken out of this iterator
@@ -182,3 +227,27 @@ where
         self.vec_len.saturating_sub(num_taken)
     }
 }
+
+impl<T> SplittableConcurrentIter for ConIterVec<T>
+where
+    T: Send,
+{
+    type Part = ConIterVecPart<T>;
+
+    fn split_at(mut self, mid: usize) -> (Self::Part, Self::Part) {
+        let num_taken = self.counter.load(Ordering::Acquire).min(self.vec_len);
+        let remaining_len = self.vec_len - num_taken;
+        assert!(
+            mid <= remaining_len,
+            "mid must not exceed the remaining length"
+        );
+
+        // SAFETY: the allocation is moved into the parts and this iterator will not release it
+        let allocation = Arc::new(unsafe { VecAllocation::new(self.ptr, self.vec_cap) });
+        // SAFETY: ptr + num_taken is at most the exclusive end of the vector
+        let remaining = unsafe { self.ptr.add(num_taken) };
+        // SAFETY: null ptr indicates that the data is already taken out of this iterator
+        self.ptr = core::ptr::null();
+        ConIterVecPart::split_remaining(&allocation, remaining, remaining_len, num_taken, mid)
+    }
+}
@@ -4,5 +4,7 @@ mod tests;
 mod common_traits;
 mod con_iter;
 mod into_con_iter;
+mod part;
 
 pub use con_iter::ConIterVec;
+pub use part::{ConIterVecPart, VecAllocation};
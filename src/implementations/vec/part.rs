@@ -0,0 +1,275 @@
+use crate::{
+    SplittableConcurrentIter,
+    concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::{
+        array_utils::{ArrayChunkPuller, ArrayConIter, ArrayIntoSeqIter, ChunkPointers},
+        ptr_utils::take,
+    },
+};
+use alloc::{sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocation of a vector shared by the parts of a split [`ConIterVec`].
+///
+/// It is only responsible for releasing the allocation, not for dropping the elements;
+/// elements are dropped by the parts.
+///
+/// [`ConIterVec`]: crate::implementations::ConIterVec
+pub struct VecAllocation<T> {
+    ptr: *const T,
+    capacity: usize,
+}
+
+unsafe impl<T: Send> Send for VecAllocation<T> {}
+
+unsafe impl<T: Send> Sync for VecAllocation<T> {}
+
+impl<T> VecAllocation<T> {
+    /// Creates the allocation of the vector starting at `ptr` with the given `capacity`.
+    ///
+    /// # SAFETY
+    ///
+    /// The allocation must not be released by anyone else.
+    pub(super) unsafe fn new(ptr: *const T, capacity: usize) -> Self {
+        Self { ptr, capacity }
+    }
+}
+
+impl<T> Drop for VecAllocation<T> {
+    fn drop(&mut self) {
+        // SAFETY: all elements are already taken out or dropped by the parts; hence, len is zero
+        let _vec = unsafe { Vec::from_raw_parts(self.ptr as *mut T, 0, self.capacity) };
+    }
+}
+
+/// Concurrent iterator over a contiguous part of the elements of a [`ConIterVec`], created by
+/// [`split_at`] or [`split_into`].
+///
+/// Parts own their elements and share the allocation of the vector which is released once
+/// all parts are dropped. Indices reported by a part are positions in the original vector.
+///
+/// [`ConIterVec`]: crate::implementations::ConIterVec
+/// [`split_at`]: crate::SplittableConcurrentIter::split_at
+/// [`split_into`]: crate::SplittableConcurrentIter::split_into
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let vec: Vec<_> = (0..5).map(|x| x.to_string()).collect();
+/// let (left, right) = vec.into_con_iter().split_at(2);
+///
+/// assert_eq!(left.next(), Some(0.to_string()));
+/// assert_eq!(right.next_with_idx(), Some((2, 2.to_string())));
+/// drop(right);
+/// assert_eq!(left.next(), Some(1.to_string()));
+/// ```
+pub struct ConIterVecPart<T> {
+    allocation: Arc<VecAllocation<T>>,
+    ptr: *const T,
+    len: usize,
+    offset: usize,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send> Sync for ConIterVecPart<T> {}
+
+unsafe impl<T: Send> Send for ConIterVecPart<T> {}
+
+impl<T> Drop for ConIterVecPart<T> {
+    fn drop(&mut self) {
+        let _iter = self.remaining_into_seq_iter();
+    }
+}
+
+impl<T> ConIterVecPart<T> {
+    /// Creates the part owning the `len` elements starting at `ptr` within the shared `allocation`;
+    /// `offset` is the position of the first element in the original vector.
+    ///
+    /// # SAFETY
+    ///
+    /// The `len` elements must be initialized and must not be accessed or dropped by anyone else.
+    pub(super) unsafe fn new(
+        allocation: Arc<VecAllocation<T>>,
+        ptr: *const T,
+        len: usize,
+        offset: usize,
+    ) -> Self {
+        Self {
+            allocation,
+            ptr,
+            len,
+            offset,
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn initial_len(&self) -> usize {
+        self.len
+    }
+
+    fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.len)
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.len {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    fn remaining_into_seq_iter(&mut self) -> ArrayIntoSeqIter<T, Arc<VecAllocation<T>>> {
+        // # SAFETY
+        // null ptr indicates that the elements are already moved out of this part
+        // by a consuming method such as `into_seq_iter` or `split_at`
+        match self.ptr.is_null() {
+            true => ArrayIntoSeqIter::new(
+                core::ptr::null(),
+                core::ptr::null(),
+                None,
+                self.allocation.clone(),
+            ),
+            false => {
+                let iter = self.slice_into_seq_iter(self.num_taken());
+                self.ptr = core::ptr::null();
+                iter
+            }
+        }
+    }
+
+    fn slice_into_seq_iter(&self, num_taken: usize) -> ArrayIntoSeqIter<T, Arc<VecAllocation<T>>> {
+        let (first, last) = match num_taken == self.len {
+            true => (core::ptr::null(), core::ptr::null()),
+            // SAFETY: both positions are in bounds since num_taken < len
+            false => unsafe { (self.ptr.add(num_taken), self.ptr.add(self.len - 1)) },
+        };
+        ArrayIntoSeqIter::new(first, last, None, self.allocation.clone())
+    }
+
+    /// Creates two parts sharing the same allocation over the `remaining_len` elements starting at
+    /// `remaining`, split at `mid`; `offset` is the position of `remaining` in the original vector.
+    ///
+    /// The caller must have checked that `mid <= remaining_len`, and must not access or drop the
+    /// remaining elements afterwards.
+    pub(super) fn split_remaining(
+        allocation: &Arc<VecAllocation<T>>,
+        remaining: *const T,
+        remaining_len: usize,
+        offset: usize,
+        mid: usize,
+    ) -> (Self, Self) {
+        debug_assert!(mid <= remaining_len);
+        // SAFETY: the remaining elements are moved out and each is owned by exactly one of the parts
+        unsafe {
+            let right = remaining.add(mid);
+            (
+                Self::new(allocation.clone(), remaining, mid, offset),
+                Self::new(allocation.clone(), right, remaining_len - mid, offset + mid),
+            )
+        }
+    }
+}
+
+impl<T> ArrayConIter for ConIterVecPart<T> {
+    type Item = T;
+
+    fn progress_and_get_chunk_pointers(
+        &self,
+        chunk_size: usize,
+    ) -> Option<ChunkPointers<Self::Item>> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size).min(self.len).max(begin_idx);
+                let first = unsafe { self.ptr.add(begin_idx) }; // ptr + begin_idx is in bounds
+                let last = unsafe { self.ptr.add(end_idx - 1) }; // ptr + end_idx - 1 is in bounds
+                ChunkPointers {
+                    begin_idx: self.offset + begin_idx,
+                    first,
+                    last,
+                }
+            })
+    }
+}
+
+impl<T> ConcurrentIter for ConIterVecPart<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = ArrayIntoSeqIter<T, Arc<VecAllocation<T>>>;
+
+    type ChunkPuller<'i>
+        = ArrayChunkPuller<'i, Self>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(mut self) -> Self::SequentialIter {
+        self.remaining_into_seq_iter()
+    }
+
+    fn skip_to_end(&self) {
+        let current = self.counter.fetch_max(self.len, Ordering::Acquire);
+        let num_taken_before = current.min(self.len);
+        let _iter = self.slice_into_seq_iter(num_taken_before);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1) // ptr + idx is in-bounds
+            .map(|idx| unsafe { take(self.ptr.add(idx) as *mut T) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1) // ptr + idx is in-bounds
+            .map(|idx| {
+                (self.offset + idx, unsafe {
+                    take(self.ptr.add(idx) as *mut T)
+                })
+            })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.num_taken();
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterVecPart<T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        self.len - self.num_taken()
+    }
+}
+
+impl<T> SplittableConcurrentIter for ConIterVecPart<T>
+where
+    T: Send,
+{
+    type Part = Self;
+
+    fn split_at(mut self, mid: usize) -> (Self::Part, Self::Part) {
+        let num_taken = self.num_taken();
+        let remaining_len = self.len - num_taken;
+        assert!(
+            mid <= remaining_len,
+            "mid must not exceed the remaining length"
+        );
+
+        // SAFETY: ptr + num_taken is at most the exclusive end of the part
+        let remaining = unsafe { self.ptr.add(num_taken) };
+        // SAFETY: the remaining elements are moved into the parts
+        self.ptr = core::ptr::null();
+        let offset = self.offset + num_taken;
+        Self::split_remaining(&self.allocation, remaining, remaining_len, offset, mid)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    SplittableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
     implementations::vec::con_iter::ConIterVec, pullers::ChunkPuller,
 };
 use alloc::{
@@ -461,3 +462,153 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix([0, 1, N], [1, 2, 4], [0, N / 2, N])]
+fn into_remaining_vec(n: usize, nt: usize, until: usize) {
+    let vec = new_vec(n, |x| (x + 10).to_string());
+    let (capacity, ptr) = (vec.capacity(), vec.as_ptr());
+    let iter = ConIterVec::new(vec);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(7);
+                while bag.len() < until {
+                    match puller.pull() {
+                        Some(chunk) => chunk.for_each(|x| _ = bag.push(x)),
+                        None => break,
+                    }
+                }
+            });
+        }
+    });
+
+    let num_taken = bag.len();
+    let remaining = iter.into_remaining_vec();
+    assert_eq!(remaining.capacity(), capacity);
+    assert_eq!(remaining.as_ptr(), ptr);
+
+    let expected: Vec<_> = (num_taken..n).map(|i| (i + 10).to_string()).collect();
+    assert_eq!(remaining, expected);
+}
+
+#[test]
+fn into_remaining_vec_after_skip_to_end() {
+    let vec = new_vec(10, |x| x.to_string());
+    let iter = ConIterVec::new(vec);
+    let _ = iter.next();
+    iter.skip_to_end();
+    assert!(iter.into_remaining_vec().is_empty());
+}
+
+#[test_matrix([0, 1, N], [0, 1, 3, 7], [1, 2, 4, N + 1])]
+fn split_into(n: usize, num_taken: usize, k: usize) {
+    let vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = ConIterVec::new(vec);
+    let taken: Vec<_> = (0..num_taken).filter_map(|_| iter.next()).collect();
+
+    let parts = iter.split_into(k);
+    assert_eq!(parts.len(), k);
+    let remaining = n.saturating_sub(num_taken);
+    let lengths: Vec<_> = parts.iter().map(|p| p.len()).collect();
+    assert_eq!(lengths.iter().sum::<usize>(), remaining);
+    assert!(lengths.windows(2).all(|w| w[0] >= w[1] && w[0] - w[1] <= 1));
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for part in &parts {
+            let bag = &bag;
+            s.spawn(move || {
+                let mut puller = part.chunk_puller(5);
+                while let Some(chunk) = puller.pull() {
+                    chunk.for_each(|x| _ = bag.push(x));
+                }
+            });
+        }
+    });
+
+    let mut all = taken;
+    all.extend(bag.into_inner());
+    all.sort_by_key(|x| x.parse::<usize>().expect("is a number"));
+    let expected: Vec<_> = (0..n).map(|i| (i + 10).to_string()).collect();
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn split_at_nested_and_dropped() {
+    let vec = new_vec(10, |x| x.to_string());
+    let iter = ConIterVec::new(vec);
+    assert_eq!(iter.next(), Some(0.to_string()));
+
+    let (left, right) = iter.split_at(4);
+    assert_eq!(left.len(), 4);
+    assert_eq!(right.len(), 5);
+
+    assert_eq!(right.next_with_idx(), Some((5, 5.to_string())));
+    let (right_left, right_right) = right.split_at(1);
+    assert_eq!(right_left.next_with_idx(), Some((6, 6.to_string())));
+    assert_eq!(right_left.next(), None);
+    drop(right_left);
+
+    assert_eq!(left.next(), Some(1.to_string()));
+    drop(left);
+
+    let (begin_idx, chunk) = right_right
+        .chunk_puller(1)
+        .pull_with_idx()
+        .expect("has elements");
+    assert_eq!(
+        (begin_idx, chunk.collect::<Vec<_>>()),
+        (7, vec![7.to_string()])
+    );
+    let rest: Vec<_> = right_right.into_seq_iter().collect();
+    assert_eq!(rest, [8, 9].map(|x| x.to_string()));
+}
+
+#[test]
+fn split_at_unused_parts() {
+    let vec = new_vec(10, |x| x.to_string());
+    let (left, right) = ConIterVec::new(vec).split_at(3);
+    let _ = right.next();
+    right.skip_to_end();
+    drop(right);
+    let _ = left.chunk_puller(2).pull();
+}
+
+#[test]
+fn split_at_out_of_bounds_panics_without_double_free() {
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Tracked(Arc<AtomicUsize>, #[allow(dead_code)] String);
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let vec: Vec<_> = (0..5)
+        .map(|x| Tracked(num_dropped.clone(), x.to_string()))
+        .collect();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let iter = ConIterVec::new(vec);
+        let _ = iter.next();
+        let _parts = iter.split_at(5);
+    }));
+    assert!(result.is_err());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 5);
+
+    let vec: Vec<_> = (0..5)
+        .map(|x| Tracked(num_dropped.clone(), x.to_string()))
+        .collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let (left, _right) = ConIterVec::new(vec).split_at(2);
+        let _ = left.next();
+        let _parts = left.split_at(2);
+    }));
+    assert!(result.is_err());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 10);
+}
@@ -0,0 +1,118 @@
+use super::con_iter_drain::ConIterVecDequeDrain;
+use crate::{implementations::ptr_utils::take, pullers::ChunkPuller};
+use core::marker::PhantomData;
+
+/// Iterator taking out a contiguous range of elements of a `VecDeque` being drained, which might
+/// span both halves of the ring buffer.
+///
+/// Elements which are not yet yielded are dropped together with the chunk.
+pub struct VecDequeDrainChunk<'c, T, M = ()> {
+    halves: [(*mut T, usize); 2],
+    current: usize,
+    phantom: PhantomData<&'c mut T>,
+    _moved_into: M,
+}
+
+unsafe impl<T: Send, M: Send> Send for VecDequeDrainChunk<'_, T, M> {}
+
+impl<T, M> Drop for VecDequeDrainChunk<'_, T, M> {
+    fn drop(&mut self) {
+        for half in self.current..2 {
+            let (ptr, len) = self.halves[half];
+            // SAFETY: remaining elements of the chunk are not yielded and belong to the chunk
+            unsafe { core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(ptr, len)) };
+        }
+    }
+}
+
+impl<T> Default for VecDequeDrainChunk<'_, T> {
+    fn default() -> Self {
+        let empty = (core::ptr::NonNull::dangling().as_ptr(), 0);
+        Self::new(empty, empty, ())
+    }
+}
+
+impl<T, M> VecDequeDrainChunk<'_, T, M> {
+    pub(super) fn new(first: (*mut T, usize), second: (*mut T, usize), moved_into: M) -> Self {
+        Self {
+            halves: [first, second],
+            current: 0,
+            phantom: PhantomData,
+            _moved_into: moved_into,
+        }
+    }
+}
+
+impl<T, M> Iterator for VecDequeDrainChunk<'_, T, M> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current < 2 {
+            let (ptr, len) = &mut self.halves[self.current];
+            match *len {
+                0 => self.current += 1,
+                _ => {
+                    // SAFETY: ptr points to the first remaining element of this half
+                    let value = unsafe { take(*ptr) };
+                    // SAFETY: ptr + 1 is in bounds or one past the end of this half
+                    *ptr = unsafe { ptr.add(1) };
+                    *len -= 1;
+                    return Some(value);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, M> ExactSizeIterator for VecDequeDrainChunk<'_, T, M> {
+    fn len(&self) -> usize {
+        self.halves[self.current.min(2)..].iter().map(|x| x.1).sum()
+    }
+}
+
+pub struct ChunkPullerVecDequeDrain<'i, 'a, T> {
+    con_iter: &'i ConIterVecDequeDrain<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T> ChunkPullerVecDequeDrain<'i, 'a, T> {
+    pub(super) fn new(con_iter: &'i ConIterVecDequeDrain<'a, T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<T> ChunkPuller for ChunkPullerVecDequeDrain<'_, '_, T>
+where
+    T: Send,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = VecDequeDrainChunk<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,81 @@
+use super::con_iter_mut::ConIterVecDequeMut;
+use crate::pullers::ChunkPuller;
+
+/// Iterator over mutable references to a contiguous range of elements of a `VecDeque`,
+/// which might span both halves of the ring buffer.
+pub struct VecDequeMutChunk<'a, T> {
+    first: core::slice::IterMut<'a, T>,
+    second: core::slice::IterMut<'a, T>,
+}
+
+impl<T> Default for VecDequeMutChunk<'_, T> {
+    fn default() -> Self {
+        Self::new(&mut [], &mut [])
+    }
+}
+
+impl<'a, T> VecDequeMutChunk<'a, T> {
+    pub(super) fn new(first: &'a mut [T], second: &'a mut [T]) -> Self {
+        Self {
+            first: first.iter_mut(),
+            second: second.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for VecDequeMutChunk<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for VecDequeMutChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
+pub struct ChunkPullerVecDequeMut<'i, 'a, T> {
+    con_iter: &'i ConIterVecDequeMut<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'i, 'a, T> ChunkPullerVecDequeMut<'i, 'a, T> {
+    pub(super) fn new(con_iter: &'i ConIterVecDequeMut<'a, T>, chunk_size: usize) -> Self {
+        Self {
+            con_iter,
+            chunk_size,
+        }
+    }
+}
+
+impl<'a, T> ChunkPuller for ChunkPullerVecDequeMut<'_, 'a, T> {
+    type ChunkItem = &'a mut T;
+
+    type Chunk<'c>
+        = VecDequeMutChunk<'a, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.con_iter
+            .progress_and_get_chunk(self.chunk_size)
+            .map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.con_iter.progress_and_get_chunk(self.chunk_size)
+    }
+}
@@ -0,0 +1,344 @@
+use super::chunk_puller_drain::{ChunkPullerVecDequeDrain, VecDequeDrainChunk};
+use crate::{
+    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    implementations::ptr_utils::take,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{
+    ops::{Bound, Range, RangeBounds},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent drain iterator of a [`VecDeque`]:
+///
+/// * elements are drained in place, directly from both halves of the ring buffer, without
+///   moving or re-allocating the buffer of the deque;
+/// * once the iterator is dropped, the drained range will be removed from the deque by shifting
+///   the shorter of the parts before and after the range within the ring buffer;
+/// * none, some or all elements may be traversed and returned by the concurrent iterator;
+///   regardless, all elements will be cleaned up, unless the iterator is finalized by
+///   [`keep_remaining`] which keeps the elements which are not yet drained in the deque.
+///
+/// It can be created by calling [`con_drain`] on a deque.
+///
+/// [`con_drain`]: crate::ConcurrentDrainableOverSlice::con_drain
+/// [`keep_remaining`]: Self::keep_remaining
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::collections::VecDeque;
+///
+/// let mut deque = VecDeque::new();
+/// deque.push_back(3);
+/// deque.push_back(4);
+/// deque.push_front(2);
+/// deque.push_front(1);
+///
+/// let con_iter = deque.con_drain(1..3);
+/// assert_eq!(con_iter.next(), Some(2));
+/// assert_eq!(con_iter.next(), Some(3));
+/// assert_eq!(con_iter.next(), None);
+/// drop(con_iter);
+///
+/// assert_eq!(deque, [1, 4]);
+/// ```
+pub struct ConIterVecDequeDrain<'a, T> {
+    target: &'a mut VecDeque<T>,
+    deque: VecDeque<T>,
+    range: Range<usize>,
+    first: *mut T,
+    first_len: usize,
+    second: *mut T,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send> Sync for ConIterVecDequeDrain<'_, T> {}
+
+unsafe impl<T: Send> Send for ConIterVecDequeDrain<'_, T> {}
+
+impl<T> Drop for ConIterVecDequeDrain<'_, T> {
+    fn drop(&mut self) {
+        /// Closes the gap and gives the deque back even if dropping one of the untaken elements panics.
+        struct DropGuard<'d, 'a, T>(&'d mut ConIterVecDequeDrain<'a, T>);
+
+        impl<T> Drop for DropGuard<'_, '_, T> {
+            fn drop(&mut self) {
+                self.0.close_gap();
+            }
+        }
+
+        let num_taken = self.num_taken();
+        let untaken = (self.range.start + num_taken)..self.range.end;
+        let guard = DropGuard(self);
+        // SAFETY: untaken elements of the range are not yielded and will not be accessed again
+        unsafe { guard.0.drop_in_place(untaken) };
+    }
+}
+
+impl<'a, T> ConIterVecDequeDrain<'a, T> {
+    /// Creates a new concurrent draining iterator over the `deque` for the given `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics:
+    ///
+    /// * if the starting point of the `range` is greater than the ending point; or
+    /// * if the ending point of the `range` is greater than `deque.len()`.
+    pub(super) fn new<R>(target: &'a mut VecDeque<T>, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Excluded(x) => x + 1,
+            Bound::Included(x) => *x,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Excluded(x) => *x,
+            Bound::Included(x) => x + 1,
+            Bound::Unbounded => target.len(),
+        };
+        let range = start..end;
+
+        assert!(range.start <= range.end);
+        assert!(range.end <= target.len());
+
+        // taking the deque out only moves its handle, not the buffer; this is the safeguard
+        // for if the iterator is leaked, in which case the target is left empty
+        let mut deque = core::mem::take(target);
+        let (first, second) = deque.as_mut_slices();
+        let (first, first_len, second) = (first.as_mut_ptr(), first.len(), second.as_mut_ptr());
+
+        Self {
+            target,
+            deque,
+            range,
+            first,
+            first_len,
+            second,
+            counter: 0.into(),
+        }
+    }
+
+    /// Finalizes the draining such that only the elements which are already taken by the concurrent
+    /// iterator are removed from the source deque; the remaining elements of the range which are not
+    /// yet drained are kept in the deque in their original order, rather than being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut deque: VecDeque<_> = (0..10).collect();
+    ///
+    /// let con_iter = deque.con_drain(2..8);
+    /// let _ = con_iter.next(); // 2
+    /// let _ = con_iter.chunk_puller(2).pull(); // 3, 4
+    /// con_iter.keep_remaining();
+    ///
+    /// assert_eq!(deque, [0, 1, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn keep_remaining(mut self) {
+        let num_taken = self.num_taken();
+        // the range is narrowed down to the taken elements, which leaves nothing to drop
+        self.range.end = self.range.start + num_taken;
+    }
+
+    /// Returns a pointer to the element at the logical position `idx` of the deque.
+    fn ptr_at(&self, idx: usize) -> *mut T {
+        match idx < self.first_len {
+            // SAFETY: idx is in bounds of the first half
+            true => unsafe { self.first.add(idx) },
+            // SAFETY: idx - first_len is in bounds of the second half
+            false => unsafe { self.second.add(idx - self.first_len) },
+        }
+    }
+
+    /// Returns the pointers and lengths of the parts of both halves of the ring buffer
+    /// corresponding to the logical range `begin..end` of the deque.
+    fn halves(&self, begin: usize, end: usize) -> [(*mut T, usize); 2] {
+        let first_end = end.min(self.first_len);
+        let first_begin = begin.min(first_end);
+        let second_begin = begin.max(self.first_len) - self.first_len;
+        let second_end = end.max(self.first_len) - self.first_len;
+        [
+            (self.ptr_at(first_begin), first_end - first_begin),
+            // SAFETY: second_begin is in bounds of the second half or equal to its length
+            (
+                unsafe { self.second.add(second_begin) },
+                second_end - second_begin,
+            ),
+        ]
+    }
+
+    /// Drops the elements at the logical range `range` of the deque.
+    ///
+    /// # SAFETY
+    ///
+    /// The range must be in bounds, and the elements must neither have been taken out before nor be
+    /// accessed afterwards.
+    unsafe fn drop_in_place(&self, range: Range<usize>) {
+        let [(first, first_len), (second, second_len)] = self.halves(range.start, range.end);
+        unsafe {
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(first, first_len));
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(second, second_len));
+        }
+    }
+
+    /// Removes the drained range from the deque by moving the shorter of the parts before and after
+    /// the range over the gap within the ring buffer, and gives the deque back to the target.
+    ///
+    /// The vacated positions do not hold valid elements; therefore, they are never read as values.
+    /// Once they are moved to the back of the deque, they are split off and only their memory is released.
+    fn close_gap(&mut self) {
+        let len = self.deque.len();
+        let (start, end) = (self.range.start, self.range.end);
+        let num_removed = end - start;
+
+        if num_removed > 0 {
+            match len - end <= start {
+                true => {
+                    // [front | vacated | back] -> [front | back | vacated]
+                    for idx in end..len {
+                        // SAFETY: both positions are in bounds and distinct; the destination is
+                        // either drained or already moved
+                        unsafe {
+                            core::ptr::copy_nonoverlapping(
+                                self.ptr_at(idx),
+                                self.ptr_at(idx - num_removed),
+                                1,
+                            )
+                        };
+                    }
+                }
+                false => {
+                    // [front | vacated | back] -> [vacated | front | back]
+                    for idx in (0..start).rev() {
+                        // SAFETY: both positions are in bounds and distinct; the destination is
+                        // either drained or already moved
+                        unsafe {
+                            core::ptr::copy_nonoverlapping(
+                                self.ptr_at(idx),
+                                self.ptr_at(idx + num_removed),
+                                1,
+                            )
+                        };
+                    }
+                    // [vacated | front | back] -> [front | back | vacated], rotation only copies memory
+                    self.deque.rotate_left(num_removed);
+                }
+            }
+
+            // splitting off copies the memory of the vacated positions into a new buffer, which is
+            // released without dropping any of them
+            let vacated = self.deque.split_off(len - num_removed);
+            let mut vacated = Vec::from(vacated);
+            // SAFETY: vacated positions are either taken out, dropped or leaked
+            unsafe { vacated.set_len(0) };
+        }
+
+        *self.target = core::mem::take(&mut self.deque);
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.range.len() {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    fn num_taken(&self) -> usize {
+        self.counter.load(Ordering::Acquire).min(self.range.len())
+    }
+
+    /// Returns the chunk of elements at positions `begin_idx..end_idx` of the drained range.
+    ///
+    /// # SAFETY
+    ///
+    /// The range must be in bounds and must be reserved by the caller so that no other chunk
+    /// takes out the same elements.
+    unsafe fn chunk(&self, begin_idx: usize, end_idx: usize) -> VecDequeDrainChunk<'_, T> {
+        let start = self.range.start;
+        let [first, second] = self.halves(start + begin_idx, start + end_idx);
+        VecDequeDrainChunk::new(first, second, ())
+    }
+
+    pub(super) fn progress_and_get_chunk<'c>(
+        &'c self,
+        chunk_size: usize,
+    ) -> Option<(usize, VecDequeDrainChunk<'c, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size)
+                    .min(self.range.len())
+                    .max(begin_idx);
+                // SAFETY: begin_idx..end_idx is in bounds and reserved by the counter
+                (begin_idx, unsafe { self.chunk(begin_idx, end_idx) })
+            })
+    }
+}
+
+impl<'a, T> ConcurrentIter for ConIterVecDequeDrain<'a, T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type SequentialIter = VecDequeDrainChunk<'a, T, Self>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerVecDequeDrain<'i, 'a, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let len = self.range.len();
+        let num_taken = self.num_taken();
+        let _ = self.counter.fetch_max(len, Ordering::Acquire);
+        // the iterator is consumed, remaining elements are not yielded by anyone else
+        let [first, second] = self.halves(self.range.start + num_taken, self.range.end);
+        VecDequeDrainChunk::new(first, second, self)
+    }
+
+    fn skip_to_end(&self) {
+        let len = self.range.len();
+        let current = self.counter.fetch_max(len, Ordering::Acquire);
+        let num_taken_before = current.min(len);
+        // SAFETY: the remaining elements are reserved by the counter and dropped with the chunk
+        let _chunk = unsafe { self.chunk(num_taken_before, len) };
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1) // range.start + idx is in-bounds
+            .map(|idx| unsafe { take(self.ptr_at(self.range.start + idx)) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1) // range.start + idx is in-bounds
+            .map(|idx| (idx, unsafe { take(self.ptr_at(self.range.start + idx)) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.range.len().saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterVecDequeDrain<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.range.len().saturating_sub(num_taken)
+    }
+}
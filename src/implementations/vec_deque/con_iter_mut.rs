@@ -0,0 +1,185 @@
+use super::chunk_puller_mut::{ChunkPullerVecDequeMut, VecDequeMutChunk};
+use crate::{concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter};
+use alloc::collections::VecDeque;
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Concurrent iterator of a mutable reference to a [`VecDeque`].
+///
+/// It can be created by calling [`into_con_iter`] on a `&mut VecDeque`.
+///
+/// Alternatively, it can be created calling [`con_iter_mut`] on `VecDeque`.
+///
+/// Elements are yielded in the logical order of the deque; i.e., the first half of the ring buffer
+/// followed by the second half, as returned by [`VecDeque::as_mut_slices`].
+///
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`con_iter_mut`]: crate::ConcurrentCollectionMut::con_iter_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use std::collections::VecDeque;
+///
+/// let mut deque = VecDeque::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+///
+/// let con_iter = deque.con_iter_mut();
+/// while let Some(x) = con_iter.next() {
+///     *x *= 10;
+/// }
+///
+/// assert_eq!(deque, [10, 20, 30]);
+/// ```
+pub struct ConIterVecDequeMut<'a, T>
+where
+    T: 'a,
+{
+    _deque: PhantomData<&'a mut VecDeque<T>>,
+    first: *mut T,
+    first_len: usize,
+    second: *mut T,
+    second_len: usize,
+    counter: AtomicUsize,
+}
+
+unsafe impl<'a, T: Send + 'a> Sync for ConIterVecDequeMut<'a, T> {}
+
+impl<'a, T: 'a> ConIterVecDequeMut<'a, T> {
+    pub(super) fn new(deque: &'a mut VecDeque<T>) -> Self {
+        let (first, second) = deque.as_mut_slices();
+        Self {
+            _deque: PhantomData,
+            first: first.as_mut_ptr(),
+            first_len: first.len(),
+            second: second.as_mut_ptr(),
+            second_len: second.len(),
+            counter: 0.into(),
+        }
+    }
+
+    fn len_total(&self) -> usize {
+        self.first_len + self.second_len
+    }
+
+    fn ptr_at(&self, idx: usize) -> *mut T {
+        match idx < self.first_len {
+            // SAFETY: idx is in bounds of the first half
+            true => unsafe { self.first.add(idx) },
+            // SAFETY: idx - first_len is in bounds of the second half
+            false => unsafe { self.second.add(idx - self.first_len) },
+        }
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.len_total() {
+            true => Some(begin_idx),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable slices of both halves corresponding to the flat range `begin_idx..end_idx`.
+    ///
+    /// # SAFETY
+    ///
+    /// The range must be in bounds and must be reserved by the caller so that no other
+    /// mutable reference to the same elements is created.
+    unsafe fn slices(&self, begin_idx: usize, end_idx: usize) -> VecDequeMutChunk<'a, T> {
+        let first_end = end_idx.min(self.first_len);
+        let first = match begin_idx < first_end {
+            true => unsafe {
+                core::slice::from_raw_parts_mut(self.first.add(begin_idx), first_end - begin_idx)
+            },
+            false => &mut [],
+        };
+
+        let second_begin = begin_idx.max(self.first_len) - self.first_len;
+        let second_end = end_idx.max(self.first_len) - self.first_len;
+        let second = match second_begin < second_end {
+            true => unsafe {
+                core::slice::from_raw_parts_mut(
+                    self.second.add(second_begin),
+                    second_end - second_begin,
+                )
+            },
+            false => &mut [],
+        };
+
+        VecDequeMutChunk::new(first, second)
+    }
+
+    pub(super) fn progress_and_get_chunk(
+        &self,
+        chunk_size: usize,
+    ) -> Option<(usize, VecDequeMutChunk<'a, T>)> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| {
+                let end_idx = (begin_idx + chunk_size)
+                    .min(self.len_total())
+                    .max(begin_idx);
+                // SAFETY: begin_idx..end_idx is in bounds and reserved by the counter
+                (begin_idx, unsafe { self.slices(begin_idx, end_idx) })
+            })
+    }
+}
+
+impl<'a, T: 'a> ConcurrentIter for ConIterVecDequeMut<'a, T>
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type SequentialIter = VecDequeMutChunk<'a, T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerVecDequeMut<'i, 'a, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        let len = self.len_total();
+        let current = self.counter.load(Ordering::Acquire).min(len);
+        // SAFETY: the iterator is consumed, remaining elements are not yielded by anyone else
+        unsafe { self.slices(current, len) }
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.len_total(), Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| unsafe { &mut *self.ptr_at(idx) })
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        self.progress_and_get_begin_idx(1)
+            .map(|idx| (idx, unsafe { &mut *self.ptr_at(idx) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.len_total().saturating_sub(num_taken);
+        (remaining, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
+
+impl<T> ExactSizeConcurrentIter for ConIterVecDequeMut<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        self.len_total().saturating_sub(num_taken)
+    }
+}
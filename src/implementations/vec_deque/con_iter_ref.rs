@@ -1,6 +1,6 @@
 use super::vec_deque_ref::VecDequeRef;
 use crate::{
-    ConcurrentIter, ExactSizeConcurrentIter,
+    ConcurrentIter, ExactSizeConcurrentIter, ResettableConcurrentIter,
     implementations::jagged_arrays::{
         AsRawSlice, ConIterJaggedRef, JaggedIndex, JaggedIndexer, RawJaggedRef, Slices,
     },
@@ -139,3 +139,18 @@ where
         self.con_iter.len()
     }
 }
+
+impl<'a, T> ResettableConcurrentIter for ConIterVecDequeRef<'a, T>
+where
+    T: 'a + Sync,
+{
+    fn reset(&mut self) {
+        self.con_iter.reset();
+    }
+
+    fn restarted(&self) -> Self {
+        Self {
+            con_iter: self.con_iter.restarted(),
+        }
+    }
+}
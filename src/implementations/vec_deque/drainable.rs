@@ -0,0 +1,23 @@
+use super::con_iter_drain::ConIterVecDequeDrain;
+use crate::ConcurrentDrainableOverSlice;
+use alloc::collections::VecDeque;
+use core::ops::RangeBounds;
+
+impl<T> ConcurrentDrainableOverSlice for VecDeque<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type DrainingIter<'a>
+        = ConIterVecDequeDrain<'a, T>
+    where
+        Self: 'a;
+
+    fn con_drain<R>(&mut self, range: R) -> Self::DrainingIter<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        ConIterVecDequeDrain::new(self, range)
+    }
+}
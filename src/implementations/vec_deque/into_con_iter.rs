@@ -1,4 +1,4 @@
-use super::con_iter_ref::ConIterVecDequeRef;
+use super::{con_iter_mut::ConIterVecDequeMut, con_iter_ref::ConIterVecDequeRef};
 use crate::{IntoConcurrentIter, implementations::ConIterVec};
 use alloc::{collections::VecDeque, vec::Vec};
 
@@ -15,6 +15,19 @@ where
     }
 }
 
+impl<'a, T> IntoConcurrentIter for &'a mut VecDeque<T>
+where
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = ConIterVecDequeMut<'a, T>;
+
+    fn into_con_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
 impl<T> IntoConcurrentIter for VecDeque<T>
 where
     T: Send,
@@ -1,8 +1,15 @@
 #[cfg(test)]
 mod tests;
 
+mod chunk_puller_drain;
+mod chunk_puller_mut;
+mod con_iter_drain;
+mod con_iter_mut;
 mod con_iter_ref;
+mod drainable;
 mod into_con_iter;
 mod vec_deque_ref;
 
+pub use con_iter_drain::ConIterVecDequeDrain;
+pub use con_iter_mut::ConIterVecDequeMut;
 pub use con_iter_ref::ConIterVecDequeRef;
@@ -0,0 +1,176 @@
+use crate::{
+    concurrent_collection_mut::ConcurrentCollectionMut, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter, into_concurrent_iter::IntoConcurrentIter,
+    pullers::ChunkPuller,
+};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 126;
+#[cfg(not(miri))]
+const N: usize = 4734;
+
+/// Creates a deque whose elements are split into both halves of the ring buffer;
+/// the logical `i`-th element is `elem(i)`.
+fn new_vec(n: usize, elem: impl Fn(usize) -> String) -> VecDeque<String> {
+    let mut vec = VecDeque::new();
+    let half = n / 2;
+
+    for i in (0..half).rev() {
+        vec.push_front(elem(i));
+    }
+    for i in half..n {
+        vec.push_back(elem(i));
+    }
+
+    assert_eq!(vec.len(), n);
+
+    vec
+}
+
+fn expected(n: usize) -> Vec<String> {
+    (0..n).map(|i| (i + 10).to_string()).collect()
+}
+
+#[test]
+fn enumeration() {
+    let mut vec: VecDeque<_> = (3..6).collect();
+    for i in (0..3).rev() {
+        vec.push_front(i);
+    }
+
+    let iter = (&mut vec).into_con_iter();
+    assert_eq!(iter.next(), Some(&mut 0));
+    assert_eq!(iter.next_with_idx(), Some((1, &mut 1)));
+
+    let mut puller = iter.chunk_puller(3);
+    let (begin_idx, chunk) = puller.pull_with_idx().expect("");
+    assert_eq!(begin_idx, 2);
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(chunk.map(|x| *x).collect::<Vec<_>>(), [2, 3, 4]);
+
+    assert_eq!(iter.next_with_idx(), Some((5, &mut 5)));
+    assert_eq!(iter.next(), None);
+    assert!(puller.pull().is_none());
+}
+
+#[test]
+fn size_hint() {
+    let mut n = 26;
+    let mut vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = vec.con_iter_mut();
+
+    for _ in 0..10 {
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        let _ = iter.next();
+        n -= 1;
+    }
+
+    let _ = iter.chunk_puller(7).pull();
+    assert_eq!(iter.len(), n - 7);
+
+    iter.skip_to_end();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn next_with_idx(n: usize, nt: usize) {
+    let mut vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = vec.con_iter_mut();
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((idx, x)) = iter.next_with_idx() {
+                    assert_eq!(*x, (idx + 10).to_string());
+                    x.push('!');
+                    bag.push(idx);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+
+    let expected: Vec<_> = expected(n).into_iter().map(|x| x + "!").collect();
+    assert_eq!(vec, expected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [1, 7, 64])]
+fn chunk_puller_with_idx(n: usize, nt: usize, chunk_size: usize) {
+    let mut vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = vec.con_iter_mut();
+
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(chunk_size);
+                while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                    assert!(chunk.len() <= chunk_size);
+                    for (i, x) in chunk.enumerate() {
+                        assert_eq!(*x, (begin_idx + i + 10).to_string());
+                        x.push('!');
+                    }
+                }
+            });
+        }
+    });
+
+    let expected: Vec<_> = expected(n).into_iter().map(|x| x + "!").collect();
+    assert_eq!(vec, expected);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, nt: usize) {
+    let mut vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = vec.con_iter_mut();
+
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.chunk_puller(7).flattened() {
+                    x.push('!');
+                }
+            });
+        }
+    });
+
+    let expected: Vec<_> = expected(n).into_iter().map(|x| x + "!").collect();
+    assert_eq!(vec, expected);
+}
+
+#[test_matrix([0, 1, N], [0, N / 2, N])]
+fn into_seq_iter(n: usize, until: usize) {
+    let until = until.min(n);
+    let mut vec = new_vec(n, |x| (x + 10).to_string());
+    let iter = vec.con_iter_mut();
+
+    for _ in 0..until {
+        iter.next().expect("").push('!');
+    }
+    let seq = iter.into_seq_iter();
+    assert_eq!(seq.len(), n - until);
+    for x in seq {
+        x.push('?');
+    }
+
+    let expected: Vec<_> = expected(n)
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| match i < until {
+            true => x + "!",
+            false => x + "?",
+        })
+        .collect();
+    assert_eq!(vec, expected);
+}
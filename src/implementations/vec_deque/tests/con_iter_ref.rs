@@ -1,5 +1,6 @@
 use crate::{
-    concurrent_iter::ConcurrentIter, exact_size_concurrent_iter::ExactSizeConcurrentIter,
+    ResettableConcurrentIter, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
     implementations::vec_deque::ConIterVecDequeRef, pullers::ChunkPuller,
 };
 use alloc::{
@@ -469,3 +470,36 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix([0, 2, N], [1, 2, 4])]
+fn reset(n: usize, nt: usize) {
+    let vec = new_vec(n, |x| (x + 10).to_string());
+    let mut iter = ConIterVecDequeRef::new(&vec);
+
+    for until in [n / 2, n] {
+        let bag = ConcurrentBag::new();
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    let mut puller = iter.chunk_puller(7);
+                    while bag.len() < until {
+                        match puller.pull() {
+                            Some(chunk) => chunk.for_each(|x| _ = bag.push(x)),
+                            None => break,
+                        }
+                    }
+                });
+            }
+        });
+        assert!(bag.len() >= until);
+
+        iter.reset();
+        assert_eq!(iter.len(), n);
+        let restarted = iter.restarted();
+        assert_eq!(restarted.next(), iter.next());
+        iter.reset();
+    }
+
+    let all: Vec<_> = iter.into_seq_iter().collect();
+    assert_eq!(all, vec.iter().collect::<Vec<_>>());
+}
@@ -0,0 +1,205 @@
+use crate::{
+    ChunkPuller, ConcurrentDrainableOverSlice, concurrent_iter::ConcurrentIter,
+    exact_size_concurrent_iter::ExactSizeConcurrentIter,
+};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::RangeBounds;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 126;
+#[cfg(not(miri))]
+const N: usize = 4734;
+
+fn new_vec(n: usize) -> VecDeque<String> {
+    let mut vec = VecDeque::new();
+    let half = n / 2;
+    for i in (0..half).rev() {
+        vec.push_front(i.to_string());
+    }
+    for i in half..n {
+        vec.push_back(i.to_string());
+    }
+    vec
+}
+
+#[test_matrix(
+    [30],
+    [.., 5.., ..25, 5..25],
+    [0, 1, 15, 29, 30, 35]
+)]
+fn validation(n: usize, range: impl RangeBounds<usize> + Clone, num_pull: usize) {
+    let mut vec1 = new_vec(n);
+    let mut vec2 = vec1.clone();
+
+    {
+        let mut iter = vec1.drain(range.clone());
+        let con_iter = vec2.con_drain(range);
+
+        for _ in 0..num_pull {
+            let a = iter.next();
+            let b = con_iter.next();
+            assert_eq!(a, b);
+            assert_eq!(iter.len(), con_iter.len());
+        }
+    }
+
+    assert_eq!(vec1, vec2);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn con_drain(n: usize, nt: usize) {
+    let mut vec = new_vec(n);
+    let (begin, end) = (n / 4, n - n / 4);
+
+    let bag = ConcurrentBag::new();
+    {
+        let iter = vec.con_drain(begin..end);
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    while let Some((idx, x)) = iter.next_with_idx() {
+                        assert_eq!(x, (begin + idx).to_string());
+                        bag.push(x);
+                    }
+                });
+            }
+        });
+    }
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    let mut expected: Vec<_> = (begin..end).map(|i| i.to_string()).collect();
+    expected.sort();
+    assert_eq!(collected, expected);
+
+    let remaining: Vec<_> = (0..begin).chain(end..n).map(|i| i.to_string()).collect();
+    assert_eq!(vec, remaining);
+}
+
+#[test]
+fn con_drain_partially_consumed() {
+    let mut vec = new_vec(100);
+    let capacity = vec.capacity();
+
+    {
+        let iter = vec.con_drain(10..90);
+        let _ = iter.next();
+        let _ = iter.next();
+    }
+
+    assert_eq!(vec.capacity(), capacity);
+    let remaining: Vec<_> = (0..10).chain(90..100).map(|i| i.to_string()).collect();
+    assert_eq!(vec, remaining);
+
+    vec.push_front("front".to_string());
+    vec.push_back("back".to_string());
+    assert_eq!(vec.len(), 22);
+}
+
+#[test_matrix(
+    [0, 2, 30, 100],
+    [0, 1, 3, 64],
+    [0..0, 0..2, 1..29, 20..30, 0..30]
+)]
+fn con_drain_chunks(n: usize, front_len: usize, range: core::ops::Range<usize>) {
+    let range = range.start.min(n)..range.end.min(n);
+    let mut deque: VecDeque<_> = (front_len.min(n)..n).map(|i| i.to_string()).collect();
+    for i in (0..front_len.min(n)).rev() {
+        deque.push_front(i.to_string());
+    }
+    let capacity = deque.capacity();
+
+    let mut drained = Vec::new();
+    {
+        let con_iter = deque.con_drain(range.clone());
+        let mut puller = con_iter.chunk_puller(7);
+        while let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+            assert_eq!(begin_idx, drained.len());
+            drained.extend(chunk);
+        }
+    }
+
+    assert_eq!(deque.capacity(), capacity);
+    let expected: Vec<_> = range.clone().map(|i| i.to_string()).collect();
+    assert_eq!(drained, expected);
+    let remaining: Vec<_> = (0..range.start)
+        .chain(range.end..n)
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(deque, remaining);
+}
+
+#[test_matrix([0, 1, 3], [2..8, 1..3, 6..9])]
+fn keep_remaining(num_pull: usize, range: core::ops::Range<usize>) {
+    let mut deque = new_vec(10);
+
+    let con_iter = deque.con_drain(range.clone());
+    let drained: Vec<_> = (0..num_pull).filter_map(|_| con_iter.next()).collect();
+    con_iter.keep_remaining();
+
+    let num_taken = drained.len();
+    let expected_drained: Vec<_> = (range.start..range.start + num_taken)
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(drained, expected_drained);
+    let remaining: Vec<_> = (0..range.start)
+        .chain(range.start + num_taken..10)
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(deque, remaining);
+}
+
+#[test]
+fn into_seq_iter_after_partially_consumed() {
+    let mut deque = new_vec(20);
+
+    let con_iter = deque.con_drain(4..16);
+    let _ = con_iter.next();
+    let _ = con_iter.chunk_puller(3).pull();
+    let rest: Vec<_> = con_iter.into_seq_iter().take(5).collect();
+
+    let expected: Vec<_> = (8..13).map(|i| i.to_string()).collect();
+    assert_eq!(rest, expected);
+    let remaining: Vec<_> = (0..4).chain(16..20).map(|i| i.to_string()).collect();
+    assert_eq!(deque, remaining);
+}
+
+#[test]
+fn panic_while_dropping_untaken_elements() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NUM_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    struct PanicOnDrop(usize);
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            NUM_DROPPED.fetch_add(1, Ordering::Relaxed);
+            assert_ne!(self.0, 5, "dropping 5 panics");
+        }
+    }
+
+    let mut deque: VecDeque<_> = (3..10).map(PanicOnDrop).collect();
+    for i in (0..3).rev() {
+        deque.push_front(PanicOnDrop(i));
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let con_iter = deque.con_drain(2..8);
+        let _ = con_iter.next();
+    }));
+    assert!(result.is_err());
+
+    let remaining: Vec<_> = deque.iter().map(|x| x.0).collect();
+    assert_eq!(remaining, [0, 1, 8, 9]);
+
+    NUM_DROPPED.store(0, Ordering::Relaxed);
+    drop(deque);
+    assert_eq!(NUM_DROPPED.load(Ordering::Relaxed), 4);
+}
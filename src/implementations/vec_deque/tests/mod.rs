@@ -1,4 +1,6 @@
+mod con_iter_mut;
 mod con_iter_ref;
+mod drain;
 mod into;
 mod trait_bounds;
 mod transformations;
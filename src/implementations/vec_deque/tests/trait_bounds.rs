@@ -17,9 +17,21 @@ fn concurrent_collection<T: Send + Sync>(vec: VecDeque<T>) {
     let _con_iter = vec.as_concurrent_iterable();
 }
 
+fn concurrent_collection_mut<T: Send + Sync>(mut vec: VecDeque<T>) {
+    use crate::ConcurrentCollectionMut;
+    let _con_iter = vec.con_iter_mut();
+}
+
+fn con_drain<T: Send + Sync>(mut vec: VecDeque<T>) {
+    use crate::ConcurrentDrainableOverSlice;
+    let _con_iter = vec.con_drain(..);
+}
+
 #[test]
 fn vec_deque_con_iter_trait_bounds() {
     into_con_iter(VecDeque::<String>::new());
     concurrent_iterable(VecDeque::<String>::new());
     concurrent_collection(VecDeque::<String>::new());
+    concurrent_collection_mut(VecDeque::<String>::new());
+    con_drain(VecDeque::<String>::new());
 }
@@ -15,24 +15,29 @@ use core::{
 /// Concurrent drain iterator of a [`Vec`]:
 ///
 /// * once the iterator is dropped, the source vector will not be de-allocated;
-///   however, it will be empty without any elements;
+///   however, the drained range will be removed from the vector;
 /// * none, some or all elements may be traversed and returned by the concurrent iterator;
-///   regardless, all elements will be cleaned up.
+///   regardless, all elements will be cleaned up, unless the iterator is finalized by
+///   [`keep_remaining`] which keeps the elements which are not yet drained in the vector.
 ///
-/// TODO: It can be created by calling [`?`] on a vector.
+/// It can be created by calling [`con_drain`] on a vector.
 ///
-/// # Examples
+/// [`con_drain`]: crate::ConcurrentDrainableOverSlice::con_drain
+/// [`keep_remaining`]: Self::keep_remaining
 ///
-/// TODO: update the example for draining iterator.
+/// # Examples
 ///
 /// ```
 /// use orx_concurrent_iter::*;
 ///
-/// let vec = vec![1, 2];
-/// let con_iter = vec.into_con_iter();
-/// assert_eq!(con_iter.next(), Some(1));
+/// let mut vec = vec![1, 2, 3, 4];
+/// let con_iter = vec.con_drain(1..3);
 /// assert_eq!(con_iter.next(), Some(2));
+/// assert_eq!(con_iter.next(), Some(3));
 /// assert_eq!(con_iter.next(), None);
+/// drop(con_iter);
+///
+/// assert_eq!(vec, [1, 4]);
 /// ```
 pub struct ConIterVecDrain<'a, T> {
     vec: &'a mut Vec<T>,
@@ -113,6 +118,31 @@ impl<'a, T> ConIterVecDrain<'a, T> {
         }
     }
 
+    /// Finalizes the draining such that only the elements which are already taken by the concurrent
+    /// iterator are removed from the source vector; the remaining elements of the range which are not
+    /// yet drained are kept in the vector in their original order, rather than being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let mut vec: Vec<_> = (0..10).collect();
+    ///
+    /// let con_iter = vec.con_drain(2..8);
+    /// let _ = con_iter.next(); // 2
+    /// let _ = con_iter.chunk_puller(2).pull(); // 3, 4
+    /// con_iter.keep_remaining();
+    ///
+    /// assert_eq!(vec, [0, 1, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn keep_remaining(mut self) {
+        let num_taken = self.num_taken();
+        // the range is narrowed down to the taken elements, which leaves nothing to drop
+        // and the untaken elements of the range are shifted together with the right part on drop
+        self.range.end = self.range.start + num_taken;
+    }
+
     fn ptr(&self) -> *const T {
         self.vec.as_ptr()
     }
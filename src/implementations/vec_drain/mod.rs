@@ -3,3 +3,5 @@ mod tests;
 
 mod con_iter;
 mod drainable;
+
+pub use con_iter::ConIterVecDrain;
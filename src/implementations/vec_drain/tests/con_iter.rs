@@ -482,3 +482,35 @@ fn into_seq_iter(n: usize, nt: usize, until: usize) {
 
     assert_eq!(all, expected);
 }
+
+#[test_matrix(
+    [30],
+    [.., 5.., ..25, 5..25, 7..7],
+    [0, 1, 15, 29, 30, 35]
+)]
+fn keep_remaining(n: usize, range: impl RangeBounds<usize> + Clone, num_pull: usize) {
+    let mut vec1: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+    let mut vec2 = vec1.clone();
+    let (capacity, ptr) = (vec2.capacity(), vec2.as_ptr());
+
+    let drained: Vec<_> = vec1.drain(range.clone()).take(num_pull).collect();
+    vec1.clear();
+    vec1.extend((0..n).map(|x| x.to_string()));
+    let start = match range.start_bound() {
+        core::ops::Bound::Included(x) => *x,
+        _ => 0,
+    };
+    let _ = vec1.drain(start..(start + drained.len()));
+
+    let con_iter = ConIterVecDrain::new(&mut vec2, range);
+    let mut pulled = Vec::new();
+    for _ in 0..num_pull {
+        pulled.extend(con_iter.next());
+    }
+    con_iter.keep_remaining();
+
+    assert_eq!(pulled, drained);
+    assert_eq!(vec1, vec2);
+    assert_eq!(vec2.capacity(), capacity);
+    assert_eq!(vec2.as_ptr(), ptr);
+}
@@ -0,0 +1,73 @@
+use crate::implementations::ptr_utils::take;
+use core::{marker::PhantomData, ops::Range};
+
+/// A chunk pulled from a [`ConIterVecRetain`], yielding removed elements at consecutive positions
+/// of the vector.
+///
+/// Kept elements are never included in a chunk; hence, the position of the `i`-th element of a chunk
+/// pulled with index `begin_idx` is `begin_idx + i`.
+///
+/// Elements of the chunk which are not visited are dropped together with the chunk.
+///
+/// [`ConIterVecRetain`]: crate::implementations::ConIterVecRetain
+pub struct RetainChunk<'a, T> {
+    ptr: *mut T,
+    positions: Range<usize>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<T> Default for RetainChunk<'_, T> {
+    fn default() -> Self {
+        Self {
+            ptr: core::ptr::null_mut(),
+            positions: 0..0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> RetainChunk<'_, T> {
+    /// Creates the chunk of removed elements at the `positions` of the vector.
+    ///
+    /// # SAFETY
+    ///
+    /// All elements within `positions` must be marked as `REMOVED` and must be exclusively owned
+    /// by this chunk.
+    pub(super) unsafe fn new(ptr: *mut T, positions: Range<usize>) -> Self {
+        Self {
+            ptr,
+            positions,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for RetainChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next()
+            // SAFETY: idx is in bounds and the removed element is owned by this chunk
+            .map(|idx| unsafe { take(self.ptr.add(idx)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for RetainChunk<'_, T> {
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+impl<T> Drop for RetainChunk<'_, T> {
+    fn drop(&mut self) {
+        for idx in self.positions.by_ref() {
+            // SAFETY: idx is in bounds and the removed element is owned by this chunk
+            unsafe { self.ptr.add(idx).drop_in_place() };
+        }
+    }
+}
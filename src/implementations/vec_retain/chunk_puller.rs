@@ -0,0 +1,84 @@
+use super::{chunk::RetainChunk, con_iter::ConIterVecRetain};
+use crate::ChunkPuller;
+use core::ops::Range;
+
+/// Chunk puller of a [`ConIterVecRetain`].
+///
+/// The puller reserves `chunk_size` positions at a time and yields the removed elements among them
+/// as chunks of consecutive positions; therefore, a reserved range might be pulled as multiple
+/// chunks. Reserved positions which are not yet visited are evaluated when the iterator is dropped.
+pub struct ChunkPullerVecRetain<'i, 'a, T, F>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync,
+{
+    con_iter: &'i ConIterVecRetain<'a, T, F>,
+    chunk_size: usize,
+    reserved: Range<usize>,
+}
+
+impl<'i, 'a, T, F> ChunkPullerVecRetain<'i, 'a, T, F>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync,
+{
+    pub(super) fn new(con_iter: &'i ConIterVecRetain<'a, T, F>, chunk_size: usize) -> Self {
+        // reserving zero positions would never progress
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        Self {
+            con_iter,
+            chunk_size,
+            reserved: 0..0,
+        }
+    }
+
+    /// Returns the next run of consecutive removed positions within the reserved positions, reserving
+    /// new positions whenever the reserved ones are exhausted.
+    fn next_removed_run(&mut self) -> Option<Range<usize>> {
+        let con_iter = self.con_iter;
+        loop {
+            match self.reserved.by_ref().find(|idx| con_iter.visit(*idx)) {
+                Some(begin_idx) => {
+                    // the first kept position ending the run is visited and skipped as well
+                    let num_following = self
+                        .reserved
+                        .by_ref()
+                        .take_while(|idx| con_iter.visit(*idx))
+                        .count();
+                    return Some(begin_idx..(begin_idx + 1 + num_following));
+                }
+                None => self.reserved = con_iter.reserve(self.chunk_size)?,
+            }
+        }
+    }
+}
+
+impl<T, F> ChunkPuller for ChunkPullerVecRetain<'_, '_, T, F>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync,
+{
+    type ChunkItem = T;
+
+    type Chunk<'c>
+        = RetainChunk<'c, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        self.pull_with_idx().map(|(_, chunk)| chunk)
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        self.next_removed_run().map(|positions| {
+            let begin_idx = positions.start;
+            // SAFETY: positions are reserved by this puller, visited once and marked as removed
+            let chunk = unsafe { RetainChunk::new(self.con_iter.ptr(), positions) };
+            (begin_idx, chunk)
+        })
+    }
+}
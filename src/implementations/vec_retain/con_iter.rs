@@ -0,0 +1,213 @@
+use super::{
+    chunk_puller::ChunkPullerVecRetain,
+    states::{KEPT, REMOVED, UNVISITED},
+};
+use crate::{ConcurrentIter, implementations::ptr_utils::take};
+use alloc::vec::Vec;
+use core::{
+    ops::Range,
+    sync::atomic::{AtomicU8, AtomicUsize, Ordering},
+};
+
+/// Concurrent retaining iterator of a [`Vec`], created by [`con_retain`]:
+///
+/// * the iterator yields the elements for which the predicate returns false, removing them from
+///   the vector;
+/// * the predicate is evaluated concurrently by the threads pulling elements from the iterator;
+/// * once the iterator is dropped, the predicate is evaluated for the elements which are not
+///   visited yet and the removed ones among them are dropped; finally, the kept elements are
+///   compacted in their original order.
+///
+/// Since the kept elements are skipped, each pulled chunk contains the removed elements at consecutive
+/// positions, and hence, might be shorter than the chunk size even when there are remaining elements.
+///
+/// [`con_retain`]: crate::ConcurrentRetainable::con_retain
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let mut v: Vec<_> = (0..8).collect();
+/// let con_iter = v.con_retain(|x| x % 2 == 0);
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 1)));
+/// assert_eq!(con_iter.next(), Some(3));
+/// drop(con_iter);
+///
+/// assert_eq!(v, [0, 2, 4, 6]);
+/// ```
+pub struct ConIterVecRetain<'a, T, F>
+where
+    F: Fn(&T) -> bool + Sync,
+{
+    vec: &'a mut Vec<T>,
+    vec_len: usize,
+    predicate: F,
+    states: Vec<AtomicU8>,
+    counter: AtomicUsize,
+}
+
+unsafe impl<T: Send, F> Sync for ConIterVecRetain<'_, T, F> where F: Fn(&T) -> bool + Sync {}
+
+impl<T, F> Drop for ConIterVecRetain<'_, T, F>
+where
+    F: Fn(&T) -> bool + Sync,
+{
+    fn drop(&mut self) {
+        let ptr = self.ptr();
+        let mut num_kept = 0;
+        for idx in 0..self.vec_len {
+            // SAFETY: idx is in bounds
+            let src = unsafe { ptr.add(idx) };
+            let keep = match self.states[idx].load(Ordering::Acquire) {
+                KEPT => true,
+                REMOVED => false,
+                _ => match (self.predicate)(unsafe { &*src }) {
+                    true => true,
+                    false => {
+                        // SAFETY: the unvisited element is initialized and owned by this iterator
+                        unsafe { src.drop_in_place() };
+                        false
+                    }
+                },
+            };
+
+            if keep {
+                if num_kept != idx {
+                    // SAFETY: num_kept < idx, and position num_kept is already moved or removed
+                    unsafe { core::ptr::copy_nonoverlapping(src, ptr.add(num_kept), 1) };
+                }
+                num_kept += 1;
+            }
+        }
+
+        // SAFETY: the first num_kept elements are the initialized kept elements
+        unsafe { self.vec.set_len(num_kept) };
+    }
+}
+
+impl<'a, T, F> ConIterVecRetain<'a, T, F>
+where
+    F: Fn(&T) -> bool + Sync,
+{
+    pub(super) fn new(vec: &'a mut Vec<T>, predicate: F) -> Self {
+        let vec_len = vec.len();
+
+        // SAFETY: setting the length to 0 already as a safeguard for if the iterator is leaked
+        unsafe { vec.set_len(0) };
+
+        Self {
+            vec,
+            vec_len,
+            predicate,
+            states: (0..vec_len).map(|_| AtomicU8::new(UNVISITED)).collect(),
+            counter: 0.into(),
+        }
+    }
+
+    pub(super) fn ptr(&self) -> *mut T {
+        self.vec.as_ptr() as *mut T
+    }
+
+    fn progress_and_get_begin_idx(&self, number_to_fetch: usize) -> Option<usize> {
+        let begin_idx = self.counter.fetch_add(number_to_fetch, Ordering::Relaxed);
+        match begin_idx < self.vec_len {
+            true => Some(begin_idx),
+            false => None,
+        }
+    }
+
+    /// Evaluates the predicate on the element at position `idx` and marks its state; returns true if
+    /// the element is removed.
+    pub(super) fn visit(&self, idx: usize) -> bool {
+        // SAFETY: idx is in bounds and reserved by the counter to the caller
+        let value = unsafe { &*self.ptr().add(idx) };
+        let (state, removed) = match (self.predicate)(value) {
+            true => (KEPT, false),
+            false => (REMOVED, true),
+        };
+        self.states[idx].store(state, Ordering::Release);
+        removed
+    }
+
+    /// Reserves the next `chunk_size` positions; returns None if all positions are already reserved.
+    ///
+    /// The reserved positions are visited, one by one, by the caller.
+    pub(super) fn reserve(&self, chunk_size: usize) -> Option<Range<usize>> {
+        self.progress_and_get_begin_idx(chunk_size)
+            .map(|begin_idx| begin_idx..(begin_idx + chunk_size).min(self.vec_len))
+    }
+}
+
+/// Sequential iterator over the remaining removed elements of a [`ConIterVecRetain`].
+///
+/// Once dropped, the kept elements are compacted in the vector.
+pub struct VecRetainSeqIter<'a, T, F>
+where
+    F: Fn(&T) -> bool + Sync,
+{
+    con_iter: ConIterVecRetain<'a, T, F>,
+}
+
+impl<T, F> Iterator for VecRetainSeqIter<'_, T, F>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.con_iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.con_iter.size_hint()
+    }
+}
+
+impl<'a, T, F> ConcurrentIter for ConIterVecRetain<'a, T, F>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync,
+{
+    type Item = T;
+
+    type SequentialIter = VecRetainSeqIter<'a, T, F>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerVecRetain<'i, 'a, T, F>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        VecRetainSeqIter { con_iter: self }
+    }
+
+    fn skip_to_end(&self) {
+        let _ = self.counter.fetch_max(self.vec_len, Ordering::Acquire);
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        self.next_with_idx().map(|(_, value)| value)
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        loop {
+            let idx = self.progress_and_get_begin_idx(1)?;
+            if self.visit(idx) {
+                // SAFETY: the removed element at idx is handed out only once
+                return Some((idx, unsafe { take(self.ptr().add(idx)) }));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_taken = self.counter.load(Ordering::Acquire);
+        let remaining = self.vec_len.saturating_sub(num_taken);
+        (0, Some(remaining))
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        Self::ChunkPuller::new(self, chunk_size)
+    }
+}
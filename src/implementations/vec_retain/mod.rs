@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk;
+mod chunk_puller;
+mod con_iter;
+mod retainable;
+mod states;
+
+pub use chunk::RetainChunk;
+pub use con_iter::{ConIterVecRetain, VecRetainSeqIter};
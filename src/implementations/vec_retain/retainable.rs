@@ -0,0 +1,23 @@
+use super::con_iter::ConIterVecRetain;
+use crate::ConcurrentRetainable;
+use alloc::vec::Vec;
+
+impl<T> ConcurrentRetainable for Vec<T>
+where
+    T: Send,
+{
+    type Item = T;
+
+    type RetainingIter<'a, F>
+        = ConIterVecRetain<'a, T, F>
+    where
+        Self: 'a,
+        F: Fn(&Self::Item) -> bool + Sync;
+
+    fn con_retain<F>(&mut self, predicate: F) -> Self::RetainingIter<'_, F>
+    where
+        F: Fn(&Self::Item) -> bool + Sync,
+    {
+        ConIterVecRetain::new(self, predicate)
+    }
+}
@@ -0,0 +1,8 @@
+/// The predicate is not evaluated for the element yet.
+pub(super) const UNVISITED: u8 = 0;
+
+/// The predicate returned true for the element which is kept in the vector.
+pub(super) const KEPT: u8 = 1;
+
+/// The predicate returned false for the element which is removed from the vector.
+pub(super) const REMOVED: u8 = 2;
@@ -0,0 +1,188 @@
+use crate::{ChunkPuller, ConcurrentIter, ConcurrentRetainable};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+fn new_vec(n: usize) -> Vec<String> {
+    (0..n).map(|x| x.to_string()).collect()
+}
+
+fn keep(x: &str) -> bool {
+    x.len() % 2 == 1 || x.ends_with('3')
+}
+
+fn expected(n: usize) -> (Vec<String>, Vec<String>) {
+    new_vec(n).into_iter().partition(|x| keep(x))
+}
+
+#[test]
+fn enumeration() {
+    let mut vec = new_vec(15);
+    let con_iter = vec.con_retain(|x| keep(x));
+
+    assert_eq!(con_iter.next_with_idx(), Some((10, 10.to_string())));
+    assert_eq!(con_iter.size_hint(), (0, Some(4)));
+    assert_eq!(con_iter.next_with_idx(), Some((11, 11.to_string())));
+    assert_eq!(con_iter.next(), Some(12.to_string()));
+    assert_eq!(con_iter.next(), Some(14.to_string()));
+    assert_eq!(con_iter.next(), None);
+    assert_eq!(con_iter.next(), None);
+    drop(con_iter);
+
+    let expected: Vec<_> = (0..10).chain([13]).map(|x| x.to_string()).collect();
+    assert_eq!(vec, expected);
+}
+
+#[test_matrix([0, 1, 2, 7, 30])]
+fn partially_consumed(num_pull: usize) {
+    let mut vec = new_vec(120);
+    let (kept, removed) = expected(120);
+
+    {
+        let con_iter = vec.con_retain(|x| keep(x));
+        for i in 0..num_pull {
+            let x = con_iter.next();
+            assert_eq!(x.as_ref(), removed.get(i));
+        }
+    }
+
+    assert_eq!(vec, kept);
+}
+
+#[test_matrix([0, 1, 7], [1, 4, 64])]
+fn chunk_puller_with_indices(num_pull: usize, chunk_size: usize) {
+    let mut vec = new_vec(120);
+    let (kept, removed) = expected(120);
+
+    {
+        let con_iter = vec.con_retain(|x| keep(x));
+        let mut puller = con_iter.chunk_puller(chunk_size);
+        let mut collected = Vec::new();
+        for _ in 0..num_pull {
+            if let Some((begin_idx, chunk)) = puller.pull_with_idx() {
+                let len = chunk.len();
+                assert!(len >= 1 && len <= chunk_size);
+                for (i, x) in chunk.enumerate() {
+                    assert_eq!(x, (begin_idx + i).to_string());
+                    collected.push(x);
+                }
+            }
+        }
+        let pulled = collected.len();
+        assert_eq!(collected, removed[..pulled]);
+
+        let mut chunk = puller.pull().unwrap_or_default();
+        let _ = chunk.next();
+    }
+
+    assert_eq!(vec, kept);
+}
+
+#[test]
+fn into_seq_iter() {
+    let mut vec = new_vec(100);
+    let (kept, removed) = expected(100);
+
+    let con_iter = vec.con_retain(|x| keep(x));
+    let first = con_iter.next();
+    let rest: Vec<_> = first.into_iter().chain(con_iter.into_seq_iter()).collect();
+
+    assert_eq!(rest, removed);
+    assert_eq!(vec, kept);
+}
+
+#[test]
+fn skip_to_end() {
+    let mut vec = new_vec(100);
+    let (kept, _) = expected(100);
+
+    {
+        let con_iter = vec.con_retain(|x| keep(x));
+        let _ = con_iter.chunk_puller(5).pull();
+        con_iter.skip_to_end();
+        assert_eq!(con_iter.next(), None);
+        assert!(con_iter.chunk_puller(5).pull().is_none());
+    }
+
+    assert_eq!(vec, kept);
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk_size() {
+    let mut vec = new_vec(10);
+    let con_iter = vec.con_retain(|x| keep(x));
+    let _ = con_iter.chunk_puller(0);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [0, 1, 64])]
+fn con_retain(n: usize, nt: usize, chunk_size: usize) {
+    let mut vec = new_vec(n);
+    let (kept, mut removed) = expected(n);
+
+    let bag = ConcurrentBag::new();
+    {
+        let con_iter = vec.con_retain(|x| keep(x));
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| match chunk_size {
+                    0 => {
+                        while let Some((idx, x)) = con_iter.next_with_idx() {
+                            assert_eq!(x, idx.to_string());
+                            bag.push(x);
+                        }
+                    }
+                    _ => {
+                        let mut puller = con_iter.chunk_puller(chunk_size);
+                        while let Some(chunk) = puller.pull() {
+                            for x in chunk {
+                                bag.push(x);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    assert_eq!(vec, kept);
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    removed.sort();
+    assert_eq!(collected, removed);
+}
+
+#[test_matrix([1, 2, 4], [1, 3, 64])]
+fn flattened_with_idx_matches_positions(nt: usize, chunk_size: usize) {
+    let mut vec = new_vec(N);
+    let (kept, removed) = expected(N);
+
+    let bag = ConcurrentBag::new();
+    {
+        let con_iter = vec.con_retain(|x| keep(x));
+        std::thread::scope(|s| {
+            for _ in 0..nt {
+                s.spawn(|| {
+                    for (idx, x) in con_iter.chunk_puller(chunk_size).flattened_with_idx() {
+                        assert_eq!(x, idx.to_string());
+                        bag.push(x);
+                    }
+                });
+            }
+        });
+    }
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.parse::<usize>().expect("is a number"));
+    assert_eq!(collected, removed);
+    assert_eq!(vec, kept);
+}
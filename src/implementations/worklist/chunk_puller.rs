@@ -0,0 +1,132 @@
+use super::{con_iter::ConIterWorklist, work_item::WorkItem};
+use crate::{
+    concurrent_iter::ConcurrentIter,
+    implementations::queue::{ChunkPullerQueue, QueueChunk},
+    pullers::ChunkPuller,
+};
+use core::iter::FusedIterator;
+
+pub struct ChunkPullerWorklist<'a, T>
+where
+    T: Send,
+{
+    worklist: &'a ConIterWorklist<T>,
+    puller: ChunkPullerQueue<'a, T>,
+}
+
+impl<'a, T> ChunkPullerWorklist<'a, T>
+where
+    T: Send,
+{
+    pub(super) fn new(worklist: &'a ConIterWorklist<T>, chunk_size: usize) -> Self {
+        Self {
+            worklist,
+            puller: worklist.queue().chunk_puller(chunk_size),
+        }
+    }
+}
+
+impl<'a, T> ChunkPuller for ChunkPullerWorklist<'a, T>
+where
+    T: Send,
+{
+    type ChunkItem = WorkItem<'a, T>;
+
+    type Chunk<'c>
+        = WorklistChunk<'a, T>
+    where
+        Self: 'c;
+
+    fn chunk_size(&self) -> usize {
+        self.puller.chunk_size()
+    }
+
+    fn pull(&mut self) -> Option<Self::Chunk<'_>> {
+        let worklist = self.worklist;
+        self.puller
+            .pull()
+            .map(|chunk| WorklistChunk::new(worklist, chunk))
+    }
+
+    fn pull_with_idx(&mut self) -> Option<(usize, Self::Chunk<'_>)> {
+        let worklist = self.worklist;
+        self.puller
+            .pull_with_idx()
+            .map(|(begin_idx, chunk)| (begin_idx, WorklistChunk::new(worklist, chunk)))
+    }
+}
+
+// iter
+
+/// A chunk of elements pulled from the worklist, yielding each element as a [`WorkItem`].
+///
+/// Elements which are not consumed by the time the chunk is dropped are considered to be completed.
+pub struct WorklistChunk<'a, T>
+where
+    T: Send,
+{
+    worklist: Option<&'a ConIterWorklist<T>>,
+    chunk: QueueChunk<'a, T>,
+}
+
+impl<T> Default for WorklistChunk<'_, T>
+where
+    T: Send,
+{
+    fn default() -> Self {
+        Self {
+            worklist: None,
+            chunk: Default::default(),
+        }
+    }
+}
+
+impl<'a, T> WorklistChunk<'a, T>
+where
+    T: Send,
+{
+    fn new(worklist: &'a ConIterWorklist<T>, chunk: QueueChunk<'a, T>) -> Self {
+        Self {
+            worklist: Some(worklist),
+            chunk,
+        }
+    }
+}
+
+impl<T> Drop for WorklistChunk<'_, T>
+where
+    T: Send,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<'a, T> Iterator for WorklistChunk<'a, T>
+where
+    T: Send,
+{
+    type Item = WorkItem<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let worklist = self.worklist?;
+        self.chunk
+            .next()
+            .map(|value| WorkItem::new(worklist, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunk.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for WorklistChunk<'_, T>
+where
+    T: Send,
+{
+    fn len(&self) -> usize {
+        self.chunk.len()
+    }
+}
+
+impl<T> FusedIterator for WorklistChunk<'_, T> where T: Send {}
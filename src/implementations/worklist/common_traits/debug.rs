@@ -0,0 +1,14 @@
+use crate::implementations::ConIterWorklist;
+use core::fmt::Debug;
+
+impl<T> Debug for ConIterWorklist<T>
+where
+    T: Send,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConIterWorklist")
+            .field("num_in_flight", &self.num_in_flight())
+            .field("is_terminated", &self.is_terminated())
+            .finish()
+    }
+}
@@ -0,0 +1,163 @@
+use super::{chunk_puller::ChunkPullerWorklist, seq_iter::WorklistSeqIter, work_item::WorkItem};
+use crate::{concurrent_iter::ConcurrentIter, implementations::ConIterQueue};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A concurrent iterator over a dynamic worklist where processing an element might produce
+/// new elements to be processed, such as in graph traversals or branch-and-bound algorithms.
+///
+/// A reference to the worklist is a concurrent iterator yielding [`WorkItem`]s. A work item
+/// dereferences to the pulled element, and it can be used to push follow-up elements to the worklist.
+/// The element is considered to be completed when the work item is dropped.
+///
+/// The worklist keeps track of the number of in-flight elements, which are the elements that are
+/// pushed but not yet completed. Therefore, it is able to distinguish a worklist that is only
+/// temporarily empty from a terminated one:
+///
+/// * `next` and chunk pullers wait while the worklist is empty and there exist elements being
+///   processed by other threads, since these might still push new elements;
+/// * they return `None` only once the worklist is empty and there exists no in-flight element.
+///
+/// Note that holding on to a work item while pulling the next one from the same thread
+/// might wait forever if the worklist is otherwise empty; the work item must be dropped once
+/// its processing is completed.
+///
+/// # Examples
+///
+/// Traversing a binary tree represented by indices of its nodes, where the children of node `i`
+/// are `2i + 1` and `2i + 2`.
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::implementations::ConIterWorklist;
+///
+/// let num_nodes = 1000;
+/// let worklist = ConIterWorklist::new([0]);
+///
+/// let num_visited: usize = std::thread::scope(|s| {
+///     let handles: Vec<_> = (0..4)
+///         .map(|_| {
+///             s.spawn(|| {
+///                 let mut num_visited = 0;
+///                 while let Some(node) = (&worklist).next() {
+///                     num_visited += 1;
+///                     let children = [2 * *node + 1, 2 * *node + 2];
+///                     node.extend(children.into_iter().filter(|x| *x < num_nodes))
+///                         .unwrap();
+///                 }
+///                 num_visited
+///             })
+///         })
+///         .collect();
+///     handles.into_iter().map(|x| x.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(num_visited, num_nodes);
+/// ```
+pub struct ConIterWorklist<T> {
+    queue: ConIterQueue<T>,
+    num_in_flight: AtomicUsize,
+}
+
+impl<T> ConIterWorklist<T>
+where
+    T: Send,
+{
+    /// Creates a new worklist with the given `initial` elements.
+    ///
+    /// If `initial` is empty, the worklist is terminated right away.
+    pub fn new<I>(initial: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let queue = ConIterQueue::new();
+        let mut num_in_flight = 0;
+        for value in initial {
+            let _ = queue.push(value);
+            num_in_flight += 1;
+        }
+        if num_in_flight == 0 {
+            queue.close();
+        }
+
+        Self {
+            queue,
+            num_in_flight: num_in_flight.into(),
+        }
+    }
+
+    /// Returns the number of in-flight elements, which are the elements that are pushed to
+    /// the worklist but not yet completed.
+    ///
+    /// This includes the elements waiting in the worklist and the elements that are pulled and
+    /// still being processed.
+    pub fn num_in_flight(&self) -> usize {
+        self.num_in_flight.load(Ordering::Acquire)
+    }
+
+    /// Returns true if the worklist is terminated; i.e., either all elements are completed or the
+    /// iteration is terminated early by `skip_to_end`.
+    pub fn is_terminated(&self) -> bool {
+        self.queue.is_closed()
+    }
+
+    pub(super) fn push(&self, value: T) -> Result<(), T> {
+        self.num_in_flight.fetch_add(1, Ordering::AcqRel);
+        self.queue.push(value).inspect_err(|_| self.complete())
+    }
+
+    pub(super) fn complete(&self) {
+        if self.num_in_flight.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.queue.close();
+        }
+    }
+
+    pub(super) fn queue(&self) -> &ConIterQueue<T> {
+        &self.queue
+    }
+}
+
+impl<'a, T> ConcurrentIter for &'a ConIterWorklist<T>
+where
+    T: Send,
+{
+    type Item = WorkItem<'a, T>;
+
+    type SequentialIter = WorklistSeqIter<'a, T>;
+
+    type ChunkPuller<'i>
+        = ChunkPullerWorklist<'a, T>
+    where
+        Self: 'i;
+
+    fn into_seq_iter(self) -> Self::SequentialIter {
+        WorklistSeqIter::new(self)
+    }
+
+    fn skip_to_end(&self) {
+        self.queue.skip_to_end();
+    }
+
+    fn next(&self) -> Option<Self::Item> {
+        let worklist: &'a ConIterWorklist<T> = self;
+        worklist
+            .queue
+            .next()
+            .map(|value| WorkItem::new(worklist, value))
+    }
+
+    fn next_with_idx(&self) -> Option<(usize, Self::Item)> {
+        let worklist: &'a ConIterWorklist<T> = self;
+        worklist
+            .queue
+            .next_with_idx()
+            .map(|(idx, value)| (idx, WorkItem::new(worklist, value)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.queue.size_hint()
+    }
+
+    fn chunk_puller(&self, chunk_size: usize) -> Self::ChunkPuller<'_> {
+        ChunkPullerWorklist::new(self, chunk_size)
+    }
+}
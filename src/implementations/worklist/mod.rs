@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod common_traits;
+mod con_iter;
+mod seq_iter;
+mod work_item;
+
+pub use con_iter::ConIterWorklist;
+pub use work_item::WorkItem;
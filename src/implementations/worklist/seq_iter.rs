@@ -0,0 +1,40 @@
+use super::{con_iter::ConIterWorklist, work_item::WorkItem};
+use crate::concurrent_iter::ConcurrentIter;
+use core::iter::FusedIterator;
+
+/// Sequential iterator over the elements of a [`ConIterWorklist`].
+///
+/// Each element must be dropped before pulling the next one; otherwise, the iterator might wait forever
+/// for the in-flight element to be completed.
+pub struct WorklistSeqIter<'a, T>
+where
+    T: Send,
+{
+    worklist: &'a ConIterWorklist<T>,
+}
+
+impl<'a, T> WorklistSeqIter<'a, T>
+where
+    T: Send,
+{
+    pub(super) fn new(worklist: &'a ConIterWorklist<T>) -> Self {
+        Self { worklist }
+    }
+}
+
+impl<'a, T> Iterator for WorklistSeqIter<'a, T>
+where
+    T: Send,
+{
+    type Item = WorkItem<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.worklist.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.worklist.size_hint()
+    }
+}
+
+impl<T> FusedIterator for WorklistSeqIter<'_, T> where T: Send {}
@@ -0,0 +1,237 @@
+use crate::{
+    concurrent_iter::ConcurrentIter, implementations::ConIterWorklist, pullers::ChunkPuller,
+};
+use alloc::vec::Vec;
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+/// Children of node `i` of a complete binary tree with `n` nodes.
+fn children(i: usize, n: usize) -> impl Iterator<Item = usize> {
+    [2 * i + 1, 2 * i + 2].into_iter().filter(move |x| *x < n)
+}
+
+fn initial(n: usize) -> Vec<usize> {
+    (0..n.min(1)).collect()
+}
+
+#[test]
+fn enumeration() {
+    let worklist = ConIterWorklist::new([0, 1]);
+    let iter = &worklist;
+    assert_eq!(worklist.num_in_flight(), 2);
+
+    let a = iter.next().expect("");
+    assert_eq!(*a, 0);
+    a.push(2).expect("");
+    assert_eq!(worklist.num_in_flight(), 3);
+    drop(a);
+    assert_eq!(worklist.num_in_flight(), 2);
+
+    let (idx, b) = iter.next_with_idx().expect("");
+    assert_eq!((idx, *b), (1, 1));
+    drop(b);
+
+    let (idx, c) = iter.next_with_idx().expect("");
+    assert_eq!((idx, *c), (2, 2));
+    assert!(!worklist.is_terminated());
+    drop(c);
+
+    assert_eq!(worklist.num_in_flight(), 0);
+    assert!(worklist.is_terminated());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn empty() {
+    let worklist = ConIterWorklist::<usize>::new([]);
+    assert!(worklist.is_terminated());
+    assert!((&worklist).next().is_none());
+    assert!((&worklist).chunk_puller(4).pull().is_none());
+}
+
+#[test]
+fn size_hint() {
+    let worklist = ConIterWorklist::new(0..10);
+    let iter = &worklist;
+    assert_eq!(iter.size_hint(), (10, None));
+
+    let a = iter.next().expect("");
+    assert_eq!(iter.size_hint(), (9, None));
+    a.extend([10, 11]).expect("");
+    assert_eq!(iter.size_hint(), (11, None));
+}
+
+#[test]
+fn drop_chunk_completes_remaining() {
+    let worklist = ConIterWorklist::new(0..10);
+    let iter = &worklist;
+
+    let mut puller = iter.chunk_puller(4);
+    let mut chunk = puller.pull().expect("");
+    assert_eq!(chunk.len(), 4);
+    let a = chunk.next().expect("");
+    drop(chunk);
+    assert_eq!(worklist.num_in_flight(), 7);
+    drop(a);
+    assert_eq!(worklist.num_in_flight(), 6);
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn next(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some(x) = iter.next() {
+                    x.extend(children(*x, n)).expect("");
+                    bag.push(*x);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn next_with_idx(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some((idx, x)) = iter.next_with_idx() {
+                    x.extend(children(*x, n)).expect("");
+                    bag.push(idx);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn item_puller(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.item_puller() {
+                    x.extend(children(*x, n)).expect("");
+                    bag.push(*x);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn chunk_puller(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = iter.chunk_puller(7);
+                while let Some(chunk) = puller.pull() {
+                    assert!(chunk.len() <= 7);
+                    for x in chunk {
+                        x.extend(children(*x, n)).expect("");
+                        bag.push(*x);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn flattened_chunk_puller(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                for x in iter.chunk_puller(7).flattened() {
+                    x.extend(children(*x, n)).expect("");
+                    bag.push(*x);
+                }
+            });
+        }
+    });
+
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4])]
+fn skip_to_end(n: usize, nt: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+    let iter = &worklist;
+    let until = n / 2;
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                while let Some(x) = iter.next() {
+                    match *x < until {
+                        true => {
+                            let _ = x.extend(children(*x, n));
+                            bag.push(*x);
+                        }
+                        false => iter.skip_to_end(),
+                    }
+                }
+            });
+        }
+    });
+
+    assert!(worklist.is_terminated());
+    let collected = bag.into_inner().to_vec();
+    assert!(collected.iter().all(|x| *x < until));
+}
+
+#[test_matrix([0, 1, N])]
+fn into_seq_iter(n: usize) {
+    let worklist = ConIterWorklist::new(initial(n));
+
+    let mut collected = Vec::new();
+    for x in (&worklist).into_seq_iter() {
+        x.extend(children(*x, n)).expect("");
+        collected.push(*x);
+    }
+
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
@@ -0,0 +1,84 @@
+use super::con_iter::ConIterWorklist;
+use core::ops::{Deref, DerefMut};
+
+/// An element pulled from a [`ConIterWorklist`] which is being processed.
+///
+/// The element is considered to be in-flight as long as the work item is alive;
+/// dropping the work item marks the element as completed.
+///
+/// While processing the element, follow-up elements can be added to the worklist by
+/// [`push`] and [`extend`] methods of the work item.
+///
+/// [`push`]: WorkItem::push
+/// [`extend`]: WorkItem::extend
+pub struct WorkItem<'a, T>
+where
+    T: Send,
+{
+    worklist: &'a ConIterWorklist<T>,
+    value: T,
+}
+
+impl<'a, T> WorkItem<'a, T>
+where
+    T: Send,
+{
+    pub(super) fn new(worklist: &'a ConIterWorklist<T>, value: T) -> Self {
+        Self { worklist, value }
+    }
+
+    /// Pushes a follow-up element to the worklist.
+    ///
+    /// Returns back the value as the error only if the worklist is terminated early by
+    /// [`skip_to_end`].
+    ///
+    /// [`skip_to_end`]: crate::ConcurrentIter::skip_to_end
+    pub fn push(&self, value: T) -> Result<(), T> {
+        self.worklist.push(value)
+    }
+
+    /// Pushes all `values` to the worklist as follow-up elements.
+    ///
+    /// Returns back the first value that could not be pushed as the error only if the worklist
+    /// is terminated early by [`skip_to_end`].
+    ///
+    /// [`skip_to_end`]: crate::ConcurrentIter::skip_to_end
+    pub fn extend<I>(&self, values: I) -> Result<(), T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in values {
+            self.push(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Deref for WorkItem<'_, T>
+where
+    T: Send,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for WorkItem<'_, T>
+where
+    T: Send,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T> Drop for WorkItem<'_, T>
+where
+    T: Send,
+{
+    fn drop(&mut self) {
+        self.worklist.complete();
+    }
+}
@@ -0,0 +1,66 @@
+use crate::exact_size_concurrent_iter::ExactSizeConcurrentIter;
+
+/// Trait to convert a collection of collections into a concurrent iterator which yields the
+/// elements of the inner collections in flat order, using its [`into_flat_con_iter`] method.
+///
+/// Note that [`into_con_iter`] of these collections yields the inner collections themselves;
+/// for instance, `vec_of_vecs.into_con_iter()` yields `Vec<T>` items while
+/// `vec_of_vecs.into_flat_con_iter()` yields `T` items.
+///
+/// It is implemented for `&[Vec<T>]`, `&Vec<Vec<T>>` and `&[&[T]]` yielding `&T`; for
+/// `&mut [Vec<T>]`, `&mut Vec<Vec<T>>` and `&mut [&mut [T]]` yielding `&mut T`; and for
+/// `Vec<Vec<T>>` yielding owned `T` elements. The created iterators are backed by the raw jagged
+/// array iterators of the [`jagged_arrays`] module and know their exact length.
+///
+/// [`into_flat_con_iter`]: crate::IntoFlatConcurrentIter::into_flat_con_iter
+/// [`into_con_iter`]: crate::IntoConcurrentIter::into_con_iter
+/// [`jagged_arrays`]: crate::implementations::jagged_arrays
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let vectors = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+///
+/// let con_iter = vectors.as_slice().into_flat_con_iter();
+/// assert_eq!(con_iter.len(), 5);
+/// assert_eq!(con_iter.next(), Some(&1));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, &2)));
+/// assert_eq!(con_iter.next_with_idx(), Some((2, &3)));
+///
+/// let mut vectors = vectors;
+/// let con_iter = vectors.as_mut_slice().into_flat_con_iter();
+/// while let Some(x) = con_iter.next() {
+///     *x *= 10;
+/// }
+///
+/// let con_iter = vectors.into_flat_con_iter();
+/// let values: Vec<i32> = con_iter.into_seq_iter().collect();
+/// assert_eq!(values, [10, 20, 30, 40, 50]);
+/// ```
+pub trait IntoFlatConcurrentIter {
+    /// Type of the element of the inner collections that the concurrent iterator yields.
+    type Item;
+
+    /// Type of the flattened concurrent iterator that this type can be converted into.
+    type IntoIter: ExactSizeConcurrentIter<Item = Self::Item>;
+
+    /// Converts the collection of collections into a concurrent iterator yielding the elements
+    /// of the inner collections in flat order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = [4];
+    /// let slices = [a.as_slice(), b.as_slice()];
+    ///
+    /// let con_iter = slices.as_slice().into_flat_con_iter();
+    /// let sum: i32 = con_iter.chunk_puller(2).flattened().sum();
+    /// assert_eq!(sum, 10);
+    /// ```
+    fn into_flat_con_iter(self) -> Self::IntoIter;
+}
@@ -1,3 +1,3 @@
 mod special_iterators;
 
-pub use special_iterators::empty;
+pub use special_iterators::{empty, from_fn, once, repeat, repeat_n, shuffled, successors};
@@ -1,4 +1,11 @@
-use crate::implementations::ConIterEmpty;
+use crate::{
+    IntoConcurrentIter, IterIntoConcurrentIter,
+    implementations::{
+        ConIterArray, ConIterEmpty, ConIterFromFn, ConIterOfIter, ConIterRepeat, ConIterRepeatN,
+        ConIterShuffled, ShuffleSource,
+    },
+};
+use core::iter::Successors;
 
 /// Creates an empty concurrent iterator which does not yield any elements.
 ///
@@ -18,3 +25,153 @@ use crate::implementations::ConIterEmpty;
 pub fn empty<T>() -> ConIterEmpty<T> {
     Default::default()
 }
+
+/// Creates a concurrent iterator which endlessly yields clones of the `value`.
+///
+/// The iterator ends only when it is skipped to the end, for instance, due to an early exit.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::repeat(String::from("x"));
+/// assert_eq!(con_iter.next(), Some(String::from("x")));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, String::from("x"))));
+///
+/// con_iter.skip_to_end();
+/// assert_eq!(con_iter.next(), None);
+/// ```
+pub fn repeat<T>(value: T) -> ConIterRepeat<T>
+where
+    T: Clone + Send + Sync,
+{
+    ConIterRepeat::new(value)
+}
+
+/// Creates an exact-size concurrent iterator which yields clones of the `value` exactly `n` times.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::repeat_n(7, 100);
+/// assert_eq!(con_iter.len(), 100);
+///
+/// let sum: i32 = con_iter.chunk_puller(16).flattened().sum();
+/// assert_eq!(sum, 700);
+/// ```
+pub fn repeat_n<T>(value: T, n: usize) -> ConIterRepeatN<T>
+where
+    T: Clone + Send + Sync,
+{
+    ConIterRepeatN::new(value, n)
+}
+
+/// Creates an exact-size concurrent iterator which yields `f(i)` for each index `i` in `0..len`.
+///
+/// The elements are computed on demand by the threads pulling them; hence, the iterator has no
+/// backing storage.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::from_fn(5, |i| 2 * i);
+/// assert_eq!(con_iter.len(), 5);
+/// assert_eq!(con_iter.next_with_idx(), Some((0, 0)));
+/// assert_eq!(con_iter.next_with_idx(), Some((1, 2)));
+///
+/// let rest: Vec<_> = con_iter.into_seq_iter().collect();
+/// assert_eq!(rest, [4, 6, 8]);
+/// ```
+pub fn from_fn<T, F>(len: usize, f: F) -> ConIterFromFn<F>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    ConIterFromFn::new(len, f)
+}
+
+/// Creates a concurrent iterator which yields `first` followed by the successors computed by
+/// `succ` from the previous element, until `succ` returns None; as in [`core::iter::successors`].
+///
+/// Since each element depends on the previous one, the elements are computed sequentially by the
+/// threads pulling them, in the same way as [`iter_into_con_iter`] does.
+///
+/// [`iter_into_con_iter`]: crate::IterIntoConcurrentIter::iter_into_con_iter
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::successors(Some(1), |x| (*x < 100).then_some(x * 3));
+/// let powers: Vec<_> = con_iter.into_seq_iter().collect();
+/// assert_eq!(powers, [1, 3, 9, 27, 81, 243]);
+/// ```
+pub fn successors<T, F>(first: Option<T>, succ: F) -> ConIterOfIter<Successors<T, F>>
+where
+    T: Send,
+    F: FnMut(&T) -> Option<T>,
+{
+    core::iter::successors(first, succ).iter_into_con_iter()
+}
+
+/// Creates an exact-size concurrent iterator which yields the `value` exactly once.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::once(String::from("single"));
+/// assert_eq!(con_iter.len(), 1);
+/// assert_eq!(con_iter.next(), Some(String::from("single")));
+/// assert_eq!(con_iter.next(), None);
+///
+/// // useful for chaining
+/// let con_iter = iter::once(0).chain([1, 2]);
+/// let values: Vec<_> = con_iter.into_seq_iter().collect();
+/// assert_eq!(values, [0, 1, 2]);
+/// ```
+pub fn once<T>(value: T) -> ConIterArray<T, 1>
+where
+    T: Send,
+{
+    [value].into_con_iter()
+}
+
+/// Creates an exact-size concurrent iterator which visits the elements of the `source`, a slice or
+/// a range, in a pseudo-random order that is deterministic for the given `seed`.
+///
+/// `next_with_idx` reports the original index of each element in the source.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let con_iter = iter::shuffled(0..10, 1234);
+/// assert_eq!(con_iter.len(), 10);
+///
+/// let mut values: Vec<_> = con_iter.chunk_puller(3).flattened().collect();
+/// assert_ne!(values, (0..10).collect::<Vec<_>>());
+///
+/// values.sort();
+/// assert_eq!(values, (0..10).collect::<Vec<_>>());
+///
+/// let names = ["a", "b", "c"];
+/// let con_iter = iter::shuffled(names.as_slice(), 1234);
+/// while let Some((idx, name)) = con_iter.next_with_idx() {
+///     assert_eq!(name, &names[idx]);
+/// }
+/// ```
+pub fn shuffled<S>(source: S, seed: u64) -> ConIterShuffled<S>
+where
+    S: ShuffleSource,
+{
+    ConIterShuffled::new(source, seed)
+}
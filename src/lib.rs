@@ -10,7 +10,7 @@
     clippy::missing_panics_doc,
     clippy::todo
 )]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 extern crate alloc;
 
@@ -19,17 +19,23 @@ mod concurrent_collection_mut;
 mod concurrent_drainable;
 mod concurrent_iter;
 mod concurrent_iterable;
+mod concurrent_retainable;
 mod exact_size_concurrent_iter;
 /// Implementations of concurrent iterators.
 pub mod implementations;
 mod into_concurrent_iter;
+mod into_flat_concurrent_iter;
 /// Module for creating special iterators.
 pub mod iter;
 mod iter_into_concurrent_iter;
 mod pullers;
+mod resettable_concurrent_iter;
+mod splittable_concurrent_iter;
 
 // exported modules: transformations
 
+/// Cancellation of concurrent iterators by shared tokens.
+pub mod cancellation;
 /// Chain of two or more concurrent iterators.
 pub mod chain;
 /// Cloned transformation of concurrent iterators.
@@ -38,6 +44,12 @@ pub mod cloned;
 pub mod copied;
 /// Enumerated transformation of concurrent iterators.
 pub mod enumerate;
+/// Multiple passes over resettable concurrent iterators.
+pub mod epochs;
+/// Tracking, checkpointing and resuming the progress of concurrent iterations.
+pub mod progress;
+/// Fallible concurrent iteration short-circuiting on the first error.
+pub mod try_iter;
 
 // exported types
 
@@ -46,10 +58,15 @@ pub use concurrent_collection_mut::ConcurrentCollectionMut;
 pub use concurrent_drainable::ConcurrentDrainableOverSlice;
 pub use concurrent_iter::ConcurrentIter;
 pub use concurrent_iterable::ConcurrentIterable;
+pub use concurrent_retainable::ConcurrentRetainable;
 pub use exact_size_concurrent_iter::ExactSizeConcurrentIter;
 pub use into_concurrent_iter::IntoConcurrentIter;
+pub use into_flat_concurrent_iter::IntoFlatConcurrentIter;
 pub use iter_into_concurrent_iter::IterIntoConcurrentIter;
 pub use pullers::{
     ChunkPuller, EnumeratedItemPuller, FlattenedChunkPuller, FlattenedEnumeratedChunkPuller,
     ItemPuller,
 };
+pub use resettable_concurrent_iter::ResettableConcurrentIter;
+pub use splittable_concurrent_iter::SplittableConcurrentIter;
+pub use try_iter::{FirstErrorBy, TryConcurrentIter};
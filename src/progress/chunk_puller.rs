@@ -0,0 +1,109 @@
+use super::tracker::ProgressTracker;
+use crate::{ChunkPuller, ExactSizeConcurrentIter};
+
+/// Chunk puller of a [`ProgressTracker`] pulling [`TrackedChunk`]s which can be marked as completed.
+///
+/// It can be created by calling [`chunk_puller`] on the tracker.
+///
+/// [`chunk_puller`]: crate::progress::ProgressTracker::chunk_puller
+pub struct TrackedChunkPuller<'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+{
+    tracker: &'i ProgressTracker<I>,
+    puller: I::ChunkPuller<'i>,
+}
+
+impl<'i, I> TrackedChunkPuller<'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+{
+    pub(super) fn new(tracker: &'i ProgressTracker<I>, puller: I::ChunkPuller<'i>) -> Self {
+        Self { tracker, puller }
+    }
+
+    /// Target length of the pulled chunks.
+    pub fn chunk_size(&self) -> usize {
+        self.puller.chunk_size()
+    }
+
+    /// Pulls the next chunk from the tracked concurrent iterator; returns None if the iterator
+    /// is consumed.
+    ///
+    /// The elements of the chunk are considered completed only after [`complete`] is called.
+    ///
+    /// [`complete`]: crate::progress::TrackedChunk::complete
+    pub fn pull(&mut self) -> Option<TrackedChunk<'_, 'i, I>> {
+        let tracker = self.tracker;
+        self.puller
+            .pull_with_idx()
+            .map(|(begin_idx, chunk)| TrackedChunk {
+                tracker,
+                begin_idx,
+                len: chunk.len(),
+                chunk,
+            })
+    }
+}
+
+/// A chunk pulled by a [`TrackedChunkPuller`], which is an iterator over the elements of the chunk.
+///
+/// Once all elements are processed, the chunk must be marked as completed by [`complete`]. Dropping
+/// the chunk without completing it leaves its elements incomplete.
+///
+/// [`complete`]: crate::progress::TrackedChunk::complete
+pub struct TrackedChunk<'c, 'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+    I::ChunkPuller<'i>: 'c,
+{
+    tracker: &'i ProgressTracker<I>,
+    begin_idx: usize,
+    len: usize,
+    chunk: <I::ChunkPuller<'i> as ChunkPuller>::Chunk<'c>,
+}
+
+impl<'c, 'i, I> TrackedChunk<'c, 'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+    I::ChunkPuller<'i>: 'c,
+{
+    /// Index of the first element of the chunk as reported by the tracked concurrent iterator,
+    /// which is its position in the original source.
+    pub fn begin_idx(&self) -> usize {
+        self.begin_idx
+    }
+
+    /// Marks all elements of the chunk as completed, regardless of whether they are iterated or not.
+    pub fn complete(self) {
+        self.tracker
+            .mark_completed(self.begin_idx..(self.begin_idx + self.len));
+    }
+}
+
+impl<'c, 'i, I> Iterator for TrackedChunk<'c, 'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+    I::ChunkPuller<'i>: 'c,
+{
+    type Item = I::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunk.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunk.size_hint()
+    }
+}
+
+impl<'c, 'i, I> ExactSizeIterator for TrackedChunk<'c, 'i, I>
+where
+    I: ExactSizeConcurrentIter + 'i,
+    I::ChunkPuller<'i>: 'c,
+{
+    fn len(&self) -> usize {
+        self.chunk.len()
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests;
+
+mod chunk_puller;
+mod snapshot;
+mod tracker;
+
+pub use chunk_puller::{TrackedChunk, TrackedChunkPuller};
+pub use snapshot::ProgressSnapshot;
+pub use tracker::ProgressTracker;
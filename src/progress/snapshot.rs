@@ -0,0 +1,126 @@
+use crate::SplittableConcurrentIter;
+
+/// A snapshot of the progress of an iteration tracked by a [`ProgressTracker`], which can be persisted
+/// and later used to resume the iteration from the lowest index which is not yet completed.
+///
+/// All indices are positions in the original source of the iteration, such as the index of the
+/// element in the slice or the position of the number in the range.
+///
+/// Serialization is available with the `serde` feature.
+///
+/// [`ProgressTracker`]: crate::progress::ProgressTracker
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::progress::ProgressTracker;
+///
+/// let vec: Vec<_> = (0..10).collect();
+///
+/// let tracker = ProgressTracker::new(vec.con_iter());
+/// let mut puller = tracker.chunk_puller(4);
+/// let chunk = puller.pull().unwrap(); // 0..4
+/// chunk.complete();
+/// let _chunk = puller.pull().unwrap(); // 4..8, pulled but interrupted before completion
+///
+/// let snapshot = tracker.snapshot();
+/// assert_eq!(snapshot.completed_until(), 4);
+///
+/// // later
+/// let con_iter = snapshot.resume(vec.con_iter());
+/// assert_eq!(con_iter.next(), Some(&4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgressSnapshot {
+    completed_until: usize,
+    num_completed: usize,
+    end: usize,
+}
+
+impl ProgressSnapshot {
+    pub(super) fn new(completed_until: usize, num_completed: usize, end: usize) -> Self {
+        Self {
+            completed_until,
+            num_completed,
+            end,
+        }
+    }
+
+    /// Returns the lowest index which is not yet completed; in other words, all elements before
+    /// this index are completed.
+    ///
+    /// It is equal to [`end`] when the iteration is completed.
+    ///
+    /// [`end`]: crate::progress::ProgressSnapshot::end
+    pub fn completed_until(&self) -> usize {
+        self.completed_until
+    }
+
+    /// Returns the number of completed elements, including those completed out of order beyond
+    /// [`completed_until`].
+    ///
+    /// [`completed_until`]: crate::progress::ProgressSnapshot::completed_until
+    pub fn num_completed(&self) -> usize {
+        self.num_completed
+    }
+
+    /// Returns the exclusive end index of the iteration, which is the length of the original source.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns true if all elements are completed.
+    pub fn is_completed(&self) -> bool {
+        self.completed_until == self.end
+    }
+
+    /// Returns a concurrent iterator over the elements of the source which are not yet known to be
+    /// completed, starting from [`completed_until`].
+    ///
+    /// The `con_iter` is expected to be a new concurrent iterator over the original source, such as
+    /// a [`ConIterSlice`] or a [`ConIterRange`], whose parts are of the same type.
+    ///
+    /// The returned iterator reports the positions of its elements in the original source, starting
+    /// from `completed_until`. In order to continue tracking its progress, [`ProgressTracker::resumed`]
+    /// can be used instead.
+    ///
+    /// [`completed_until`]: crate::progress::ProgressSnapshot::completed_until
+    /// [`ConIterSlice`]: crate::implementations::ConIterSlice
+    /// [`ConIterRange`]: crate::implementations::ConIterRange
+    /// [`ProgressTracker::resumed`]: crate::progress::ProgressTracker::resumed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `con_iter` is not equal to [`end`].
+    ///
+    /// [`end`]: crate::progress::ProgressSnapshot::end
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use orx_concurrent_iter::progress::ProgressTracker;
+    ///
+    /// let tracker = ProgressTracker::new((0..100).into_con_iter());
+    /// tracker.mark_completed(0..30);
+    /// let snapshot = tracker.snapshot();
+    ///
+    /// let con_iter = snapshot.resume((0..100).into_con_iter());
+    /// assert_eq!(con_iter.len(), 70);
+    /// assert_eq!(con_iter.next_with_idx(), Some((30, 30)));
+    /// ```
+    pub fn resume<I>(&self, con_iter: I) -> I::Part
+    where
+        I: SplittableConcurrentIter,
+    {
+        assert_eq!(
+            con_iter.len(),
+            self.end,
+            "resumed iterator must be a new iterator over the original source"
+        );
+        let (_completed, remaining) = con_iter.split_at(self.completed_until);
+        remaining
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    ConcurrentCollection, ConcurrentIter, ExactSizeConcurrentIter, IntoConcurrentIter,
+    SplittableConcurrentIter,
+    progress::{ProgressSnapshot, ProgressTracker},
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+#[test]
+fn mark_completed_out_of_order() {
+    let tracker = ProgressTracker::new((0..200).into_con_iter());
+    assert_eq!(tracker.completed_until(), 0);
+
+    tracker.mark_completed(70..130);
+    assert_eq!(tracker.completed_until(), 0);
+    tracker.mark_completed(0..63);
+    assert_eq!(tracker.completed_until(), 63);
+    tracker.mark_completed(64..70);
+    assert_eq!(tracker.completed_until(), 63);
+    tracker.mark_completed(60..65);
+    assert_eq!(tracker.completed_until(), 130);
+
+    let snapshot = tracker.snapshot();
+    assert_eq!(snapshot, ProgressSnapshot::new(130, 130, 200));
+    assert!(!snapshot.is_completed());
+
+    tracker.mark_completed(130..200);
+    tracker.mark_completed(0..200);
+    let snapshot = tracker.snapshot();
+    assert_eq!(snapshot, ProgressSnapshot::new(200, 200, 200));
+    assert!(snapshot.is_completed());
+}
+
+#[test]
+fn empty() {
+    let tracker = ProgressTracker::new((0..0).into_con_iter());
+    assert!(tracker.chunk_puller(4).pull().is_none());
+    assert!(tracker.snapshot().is_completed());
+}
+
+#[test]
+fn pulled_but_not_completed() {
+    let vec: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+    let tracker = ProgressTracker::new(vec.con_iter());
+    let mut puller = tracker.chunk_puller(8);
+
+    let first = puller.pull().expect("has elements");
+    assert_eq!(first.begin_idx(), 0);
+    let _ = first; // dropped without completion
+    let second = puller.pull().expect("has elements");
+    assert_eq!(second.len(), 8);
+    second.complete();
+
+    assert_eq!(tracker.completed_until(), 0);
+    assert_eq!(tracker.snapshot().num_completed(), 8);
+}
+
+#[test_matrix([0, 1, 64, N], [1, 2, 4], [1, 7, 64], [0, 3, 1000])]
+fn interrupted(n: usize, nt: usize, chunk_size: usize, interrupt_at: usize) {
+    let vec: Vec<_> = (0..n).map(|x| (x + 10).to_string()).collect();
+    let tracker = ProgressTracker::new(vec.con_iter());
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                let mut puller = tracker.chunk_puller(chunk_size);
+                while let Some(mut chunk) = puller.pull() {
+                    let begin_idx = chunk.begin_idx();
+                    match (begin_idx..begin_idx + chunk.len()).contains(&interrupt_at) {
+                        true => continue,
+                        false => {
+                            for x in &mut chunk {
+                                bag.push(x.clone());
+                            }
+                            chunk.complete();
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let snapshot = tracker.snapshot();
+    let completed_until = match interrupt_at < n {
+        true => interrupt_at - interrupt_at % chunk_size,
+        false => n,
+    };
+    assert_eq!(snapshot.completed_until(), completed_until);
+    assert_eq!(snapshot.end(), n);
+    assert_eq!(snapshot.num_completed(), bag.len());
+
+    let resumed = snapshot.resume(vec.con_iter());
+    assert_eq!(resumed.len(), n - completed_until);
+    let remaining: Vec<_> = resumed.into_seq_iter().collect();
+    assert_eq!(remaining, vec[completed_until..].iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn resumed_repeatedly() {
+    let new_vec = || (0..N).map(|x| x.to_string()).collect::<Vec<_>>();
+    let mut snapshot = ProgressTracker::new(new_vec().into_con_iter()).snapshot();
+    let mut all = Vec::<String>::new();
+
+    let mut num_resumed = 0;
+    while !snapshot.is_completed() {
+        let tracker = ProgressTracker::resumed(new_vec().into_con_iter(), &snapshot);
+        let mut puller = tracker.chunk_puller(5);
+        for _ in 0..10 {
+            match puller.pull() {
+                Some(mut chunk) => {
+                    assert_eq!(
+                        chunk.begin_idx(),
+                        all.len(),
+                        "indices of the resumed iterator are source positions"
+                    );
+                    all.extend(&mut chunk);
+                    chunk.complete();
+                }
+                None => break,
+            }
+        }
+        snapshot = tracker.snapshot();
+        num_resumed += 1;
+    }
+
+    assert_eq!(num_resumed, N.div_ceil(50));
+    assert_eq!(all, new_vec());
+}
+
+#[test]
+fn with_offset_range() {
+    let snapshot = {
+        let tracker = ProgressTracker::new((0..100).into_con_iter());
+        tracker.mark_completed(0..40);
+        tracker.snapshot()
+    };
+
+    let tracker = ProgressTracker::resumed((0..100).into_con_iter(), &snapshot);
+    assert_eq!(tracker.con_iter().len(), 60);
+    assert_eq!(tracker.completed_until(), 40);
+
+    let mut puller = tracker.chunk_puller(25);
+    let chunk = puller.pull().expect("has elements");
+    assert_eq!(chunk.begin_idx(), 40);
+    assert_eq!(chunk.collect::<Vec<_>>(), (40..65).collect::<Vec<_>>());
+    let chunk = puller.pull().expect("has elements");
+    assert_eq!(chunk.begin_idx(), 65);
+    chunk.complete();
+    assert_eq!(tracker.completed_until(), 40);
+    tracker.mark_completed(40..65);
+    assert_eq!(tracker.snapshot(), ProgressSnapshot::new(90, 90, 100));
+}
+
+#[test]
+#[should_panic]
+fn with_offset_mark_completed_before_offset() {
+    let (_, part) = (0..100).into_con_iter().split_at(40);
+    let tracker = ProgressTracker::with_offset(part, 40);
+    tracker.mark_completed(30..50);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let tracker = ProgressTracker::new((0..100).into_con_iter());
+    tracker.mark_completed(0..40);
+    tracker.mark_completed(50..60);
+    let snapshot = tracker.snapshot();
+
+    let json = serde_json::to_string(&snapshot).expect("serializable");
+    let deserialized: ProgressSnapshot = serde_json::from_str(&json).expect("deserializable");
+    assert_eq!(deserialized, snapshot);
+}
@@ -0,0 +1,212 @@
+use super::{chunk_puller::TrackedChunkPuller, snapshot::ProgressSnapshot};
+use crate::{ExactSizeConcurrentIter, SplittableConcurrentIter};
+use alloc::vec::Vec;
+use core::{
+    ops::Range,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// Tracks which elements of an exact-size concurrent iterator are completed, not only pulled, in
+/// order to checkpoint the progress of the iteration and resume it later.
+///
+/// Elements are pulled in chunks by a [`TrackedChunkPuller`] created by [`chunk_puller`]. Each pulled
+/// [`TrackedChunk`] must be explicitly marked as completed by calling its [`complete`] method once its
+/// elements are processed. Chunks which are dropped without being completed, for instance due to an
+/// interruption, are considered incomplete.
+///
+/// The progress can be checked at any time by [`snapshot`], which, most importantly, reports the
+/// lowest index which is not yet completed. Chunks completing out of order are taken into account:
+/// this index moves forward as soon as all preceding chunks are completed.
+///
+/// Completion is recorded with one bit per element of the iterator.
+///
+/// [`chunk_puller`]: crate::progress::ProgressTracker::chunk_puller
+/// [`TrackedChunk`]: crate::progress::TrackedChunk
+/// [`complete`]: crate::progress::TrackedChunk::complete
+/// [`snapshot`]: crate::progress::ProgressTracker::snapshot
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+/// use orx_concurrent_iter::progress::ProgressTracker;
+///
+/// let vec: Vec<_> = (0..1000).collect();
+/// let tracker = ProgressTracker::new(vec.con_iter());
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             let mut puller = tracker.chunk_puller(10);
+///             while let Some(mut chunk) = puller.pull() {
+///                 if chunk.begin_idx() >= 500 {
+///                     break; // interrupted
+///                 }
+///                 for x in &mut chunk {
+///                     assert!(*x < 1000);
+///                 }
+///                 chunk.complete();
+///             }
+///         });
+///     }
+/// });
+///
+/// let snapshot = tracker.snapshot();
+/// assert_eq!(snapshot.completed_until(), 500);
+///
+/// // resume from where the iteration is left
+/// let tracker = ProgressTracker::resumed(vec.con_iter(), &snapshot);
+/// let mut puller = tracker.chunk_puller(10);
+/// while let Some(chunk) = puller.pull() {
+///     chunk.complete();
+/// }
+/// assert!(tracker.snapshot().is_completed());
+/// ```
+pub struct ProgressTracker<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    con_iter: I,
+    offset: usize,
+    len: usize,
+    completed: Vec<AtomicU64>,
+    num_completed: AtomicUsize,
+    num_full_words: AtomicUsize,
+}
+
+impl<I> ProgressTracker<I>
+where
+    I: ExactSizeConcurrentIter,
+{
+    /// Creates a progress tracker for the given concurrent iterator.
+    ///
+    /// Tracked indices are those reported by the iterator, such as `pull_with_idx` of its chunk
+    /// pullers. Therefore, `con_iter` is expected to be a new concurrent iterator from which no
+    /// element is pulled yet, and which reports the index of its first element as zero.
+    pub fn new(con_iter: I) -> Self {
+        Self::with_offset(con_iter, 0)
+    }
+
+    /// Creates a progress tracker for the given concurrent iterator which iterates over the elements
+    /// of a source starting at position `offset`, such as a part created by [`split_at`].
+    ///
+    /// Parts report the positions of their elements in the original source; hence, the first
+    /// element of `con_iter` is expected to be reported with index `offset`. Indices of the tracker
+    /// and its snapshots are positions in the original source as well.
+    ///
+    /// [`split_at`]: crate::SplittableConcurrentIter::split_at
+    pub fn with_offset(con_iter: I, offset: usize) -> Self {
+        let len = con_iter.len();
+        let completed = (0..len.div_ceil(BITS)).map(|_| AtomicU64::new(0)).collect();
+        Self {
+            con_iter,
+            offset,
+            len,
+            completed,
+            num_completed: 0.into(),
+            num_full_words: 0.into(),
+        }
+    }
+
+    /// Creates a progress tracker which resumes the iteration recorded by the `snapshot`.
+    ///
+    /// `con_iter` is expected to be a new concurrent iterator over the original source; its elements
+    /// before [`completed_until`] are skipped. Indices are kept as positions in the original source
+    /// so that snapshots of the resumed tracker can be used to resume once again.
+    ///
+    /// Note that elements after `completed_until` which were completed out of order are iterated
+    /// again.
+    ///
+    /// [`completed_until`]: crate::progress::ProgressSnapshot::completed_until
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `con_iter` is not equal to the [`end`] of the snapshot.
+    ///
+    /// [`end`]: crate::progress::ProgressSnapshot::end
+    pub fn resumed<S>(con_iter: S, snapshot: &ProgressSnapshot) -> Self
+    where
+        S: SplittableConcurrentIter<Part = I>,
+    {
+        Self::with_offset(snapshot.resume(con_iter), snapshot.completed_until())
+    }
+
+    /// Returns a reference to the tracked concurrent iterator.
+    ///
+    /// Elements pulled directly from the iterator are not completed automatically; they can be
+    /// marked as completed by [`mark_completed`].
+    ///
+    /// [`mark_completed`]: crate::progress::ProgressTracker::mark_completed
+    pub fn con_iter(&self) -> &I {
+        &self.con_iter
+    }
+
+    /// Consumes the tracker and returns the tracked concurrent iterator.
+    pub fn into_inner(self) -> I {
+        self.con_iter
+    }
+
+    /// Creates a chunk puller which pulls chunks of `chunk_size` elements from the tracked iterator,
+    /// each of which can be marked as completed once processed.
+    pub fn chunk_puller(&self, chunk_size: usize) -> TrackedChunkPuller<'_, I> {
+        TrackedChunkPuller::new(self, self.con_iter.chunk_puller(chunk_size))
+    }
+
+    /// Marks the elements at the given `indices` of the tracked iterator as completed.
+    ///
+    /// Indices are positions as reported by the `next_with_idx` or `pull_with_idx` methods of the
+    /// tracked concurrent iterator; i.e., positions in the original source, including the offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is out of bounds of the tracked iterator.
+    pub fn mark_completed(&self, indices: Range<usize>) {
+        assert!(
+            self.offset <= indices.start
+                && indices.start <= indices.end
+                && indices.end <= self.offset + self.len,
+            "indices must be within bounds of the tracked iterator"
+        );
+        let (mut begin, end) = (indices.start - self.offset, indices.end - self.offset);
+        while begin < end {
+            let (w, bit) = (begin / BITS, begin % BITS);
+            let num_bits = (BITS - bit).min(end - begin);
+            let mask = match num_bits {
+                BITS => u64::MAX,
+                n => ((1u64 << n) - 1) << bit,
+            };
+            let previous = self.completed[w].fetch_or(mask, Ordering::Release);
+            let num_new = (mask & !previous).count_ones() as usize;
+            self.num_completed.fetch_add(num_new, Ordering::Relaxed);
+            begin += num_bits;
+        }
+    }
+
+    /// Returns the lowest index which is not yet completed, as a position in the original source;
+    /// i.e., including the offset.
+    pub fn completed_until(&self) -> usize {
+        let mut w = self.num_full_words.load(Ordering::Acquire);
+        while w < self.completed.len() && self.completed[w].load(Ordering::Acquire) == u64::MAX {
+            w += 1;
+        }
+        self.num_full_words.fetch_max(w, Ordering::Release);
+
+        let relative = match w < self.completed.len() {
+            true => w * BITS + self.completed[w].load(Ordering::Acquire).trailing_ones() as usize,
+            false => self.len,
+        };
+        self.offset + relative.min(self.len)
+    }
+
+    /// Creates a snapshot of the current progress of the iteration.
+    ///
+    /// The snapshot might be created while the iteration is in progress; it then reflects the
+    /// chunks completed up to that point.
+    pub fn snapshot(&self) -> ProgressSnapshot {
+        let completed_until = self.completed_until();
+        let num_completed = self.offset + self.num_completed.load(Ordering::Relaxed);
+        ProgressSnapshot::new(completed_until, num_completed, self.offset + self.len)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{ConcurrentIter, epochs::ConIterEpochs};
+
+/// A concurrent iterator which can be restarted from the beginning of its source, allowing multiple
+/// passes over the same data without creating and sharing a new iterator for each pass.
+///
+/// This is implemented by iterators borrowing their source, such as iterators over slices, ranges,
+/// jagged arrays and references to vec deques.
+///
+/// Resetting requires exclusive access to the iterator, `&mut self`, which guarantees that no thread
+/// is pulling elements at the same time. When a fixed team of threads runs many passes over the
+/// same data, [`epochs`] can be used instead.
+///
+/// [`epochs`]: crate::ResettableConcurrentIter::epochs
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let vec: Vec<_> = (0..100).collect();
+/// let mut con_iter = vec.con_iter();
+///
+/// for _ in 0..3 {
+///     let sum: i32 = std::thread::scope(|s| {
+///         let handles: Vec<_> = (0..4)
+///             .map(|_| s.spawn(|| con_iter.item_puller().sum::<i32>()))
+///             .collect();
+///         handles.into_iter().map(|h| h.join().unwrap()).sum()
+///     });
+///     assert_eq!(sum, 4950);
+///
+///     con_iter.reset();
+/// }
+/// ```
+pub trait ResettableConcurrentIter: ConcurrentIter + Sized {
+    /// Resets the iterator so that it yields all elements of its source once again, from the beginning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let mut con_iter = (0..3).into_con_iter();
+    /// assert_eq!(con_iter.next(), Some(0));
+    /// assert_eq!(con_iter.next(), Some(1));
+    ///
+    /// con_iter.reset();
+    /// assert_eq!(con_iter.item_puller().collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    fn reset(&mut self) {
+        *self = self.restarted();
+    }
+
+    /// Creates a new concurrent iterator over the same source which yields all of its elements
+    /// from the beginning, regardless of the progress of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let vec = vec!['a', 'b', 'c'];
+    /// let con_iter = vec.con_iter();
+    /// assert_eq!(con_iter.next(), Some(&'a'));
+    ///
+    /// let restarted = con_iter.restarted();
+    /// assert_eq!(restarted.next(), Some(&'a'));
+    /// assert_eq!(con_iter.next(), Some(&'b'));
+    /// ```
+    fn restarted(&self) -> Self;
+
+    /// Creates `num_epochs` passes over the source of this iterator, each of which is an independent
+    /// concurrent iterator yielding all elements from the beginning.
+    ///
+    /// This allows a fixed team of threads to run many passes without re-creating or resetting the
+    /// iterator in between. See [`ConIterEpochs`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    /// use std::sync::Barrier;
+    ///
+    /// let vec: Vec<_> = (0..100).collect();
+    /// let epochs = vec.con_iter().epochs(3);
+    /// let barrier = Barrier::new(4);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| {
+    ///             for con_iter in &epochs {
+    ///                 for x in con_iter.item_puller() {
+    ///                     assert!(*x < 100);
+    ///                 }
+    ///                 // wait for the other threads to complete this pass
+    ///                 barrier.wait();
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert!(epochs.iter().all(|e| e.next().is_none()));
+    /// ```
+    fn epochs(mut self, num_epochs: usize) -> ConIterEpochs<Self> {
+        self.reset();
+        ConIterEpochs::new(self, num_epochs)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::ExactSizeConcurrentIter;
+use alloc::vec::Vec;
+
+/// An exact-size concurrent iterator which can address its elements by index, and hence, can be
+/// split into independent concurrent iterators over disjoint parts of its remaining elements.
+///
+/// Each part has its own progress counter; therefore, groups of threads pulling from different parts
+/// do not contend on the same atomic. This is useful for multi-level parallelism, such as distributing
+/// parts to sockets and elements of each part to the cores of the socket.
+///
+/// Note that the parts cover only the remaining elements which are not yet pulled from this iterator.
+/// Indices reported by `next_with_idx` or `pull_with_idx` of a part are the indices that this iterator
+/// would have reported for the same elements; i.e., positions in the original source.
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let vec: Vec<_> = (0..10).collect();
+/// let con_iter = vec.into_con_iter();
+/// let _ = con_iter.next(); // 0
+///
+/// let parts = con_iter.split_into(3);
+/// let parts: Vec<Vec<_>> = parts
+///     .into_iter()
+///     .map(|x| x.item_puller().collect())
+///     .collect();
+/// assert_eq!(parts, [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+/// ```
+pub trait SplittableConcurrentIter: ExactSizeConcurrentIter {
+    /// Type of the concurrent iterators over parts of this iterator.
+    type Part: SplittableConcurrentIter<Item = Self::Item, Part = Self::Part>;
+
+    /// Splits the remaining elements of the iterator into two concurrent iterators such that
+    ///
+    /// * the first one yields the first `mid` remaining elements, and
+    /// * the second one yields the other remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the number of remaining elements, `self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let con_iter = (0..6).into_con_iter();
+    /// let _ = con_iter.next(); // 0
+    ///
+    /// let (left, right) = con_iter.split_at(2);
+    /// assert_eq!(left.len(), 2);
+    /// assert_eq!(left.item_puller().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(right.next_with_idx(), Some((3, 3)));
+    /// assert_eq!(right.item_puller().collect::<Vec<_>>(), [4, 5]);
+    /// ```
+    fn split_at(self, mid: usize) -> (Self::Part, Self::Part);
+
+    /// Splits the remaining elements of the iterator into `k` concurrent iterators over contiguous
+    /// and disjoint parts, in order.
+    ///
+    /// Lengths of parts differ by at most one, the earlier parts being the longer ones.
+    /// Some of the parts are empty if `k` is greater than the number of remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_concurrent_iter::*;
+    ///
+    /// let vec: Vec<_> = (0..7).collect();
+    /// let parts = vec.con_iter().split_into(3);
+    /// let lengths: Vec<_> = parts.iter().map(|x| x.len()).collect();
+    /// assert_eq!(lengths, [3, 2, 2]);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for part in &parts {
+    ///         s.spawn(move || {
+    ///             let sum: i32 = part.item_puller().sum();
+    ///             assert!(sum > 0);
+    ///         });
+    ///     }
+    /// });
+    /// ```
+    fn split_into(self, k: usize) -> Vec<Self::Part>
+    where
+        Self: Sized,
+    {
+        assert!(k > 0, "number of parts must be positive");
+
+        let len = self.len();
+        let part_len = |i: usize| len / k + usize::from(i < len % k);
+
+        let mut parts = Vec::with_capacity(k);
+        let (first, mut rest) = self.split_at(part_len(0));
+        parts.push(first);
+        for i in 1..(k - 1) {
+            let (part, remaining) = rest.split_at(part_len(i));
+            parts.push(part);
+            rest = remaining;
+        }
+        if k > 1 {
+            parts.push(rest);
+        }
+        parts
+    }
+}
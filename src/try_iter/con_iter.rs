@@ -0,0 +1,88 @@
+use super::{
+    error_slot::ErrorSlot, first_error_by::FirstErrorBy, try_concurrent_iter::TryConcurrentIter,
+};
+use crate::ConcurrentIter;
+use core::marker::PhantomData;
+
+/// A fallible concurrent iterator over a concurrent iterator yielding `Result<T, E>` elements,
+/// which short-circuits on the first error.
+///
+/// It can be created by calling [`into_try`] on a concurrent iterator; see [`TryConcurrentIter`]
+/// for its api.
+///
+/// [`into_try`]: crate::ConcurrentIter::into_try
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let results: Vec<Result<usize, String>> = vec![Ok(1), Ok(2), Err("x".into()), Ok(4)];
+///
+/// let iter = results.into_con_iter().into_try(FirstErrorBy::Time);
+/// assert_eq!(iter.try_next(), Some(1));
+/// assert_eq!(iter.try_next(), Some(2));
+/// assert_eq!(iter.try_next(), None);
+/// assert_eq!(iter.try_next(), None);
+///
+/// assert_eq!(iter.into_result(), Err("x".to_string()));
+/// ```
+pub struct ConIterTry<I, T, E>
+where
+    I: ConcurrentIter<Item = Result<T, E>>,
+{
+    iter: I,
+    error: ErrorSlot<E>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<I, T, E> ConIterTry<I, T, E>
+where
+    I: ConcurrentIter<Item = Result<T, E>>,
+{
+    pub(crate) fn new(iter: I, first_error_by: FirstErrorBy) -> Self {
+        Self {
+            iter,
+            error: ErrorSlot::new(first_error_by),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, T, E> TryConcurrentIter for ConIterTry<I, T, E>
+where
+    I: ConcurrentIter<Item = Result<T, E>>,
+    T: Send,
+    E: Send,
+{
+    type Ok = T;
+
+    type Error = E;
+
+    fn try_next_with_idx(&self) -> Option<(usize, Self::Ok)> {
+        let (idx, result) = self.iter.next_with_idx()?;
+        match self.error.is_stopped_at(idx) {
+            true => None,
+            false => match result {
+                Ok(x) => Some((idx, x)),
+                Err(error) => {
+                    self.record_error(idx, error);
+                    None
+                }
+            },
+        }
+    }
+
+    fn record_error(&self, idx: usize, error: Self::Error) {
+        self.error.record(idx, error);
+        self.iter.skip_to_end();
+    }
+
+    fn has_error(&self) -> bool {
+        self.error.has_error()
+    }
+
+    fn into_error(self) -> Option<(usize, Self::Error)> {
+        self.error.into_inner()
+    }
+}
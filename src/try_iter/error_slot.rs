@@ -0,0 +1,70 @@
+use super::first_error_by::FirstErrorBy;
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// Index representing the absence of an error.
+const NO_ERROR: usize = usize::MAX;
+
+/// Slot holding the first error observed by any of the threads together with its index.
+pub(super) struct ErrorSlot<E> {
+    first_error_by: FirstErrorBy,
+    error_idx: AtomicUsize,
+    is_locked: AtomicBool,
+    error: UnsafeCell<Option<(usize, E)>>,
+}
+
+unsafe impl<E: Send> Sync for ErrorSlot<E> {}
+
+impl<E> ErrorSlot<E> {
+    pub(super) fn new(first_error_by: FirstErrorBy) -> Self {
+        Self {
+            first_error_by,
+            error_idx: NO_ERROR.into(),
+            is_locked: false.into(),
+            error: None.into(),
+        }
+    }
+
+    pub(super) fn has_error(&self) -> bool {
+        self.error_idx.load(Ordering::Acquire) != NO_ERROR
+    }
+
+    /// Returns whether or not the element at the given `idx` must be skipped due to a recorded error.
+    pub(super) fn is_stopped_at(&self, idx: usize) -> bool {
+        match self.first_error_by {
+            FirstErrorBy::Index => idx >= self.error_idx.load(Ordering::Acquire),
+            FirstErrorBy::Time => self.has_error(),
+        }
+    }
+
+    /// Records the `error` observed at position `idx` if it is the first error with respect to
+    /// the [`FirstErrorBy`] strategy; drops it otherwise.
+    pub(super) fn record(&self, idx: usize, error: E) {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        let current_idx = self.error_idx.load(Ordering::Acquire);
+        let is_first = match self.first_error_by {
+            FirstErrorBy::Index => idx < current_idx,
+            FirstErrorBy::Time => current_idx == NO_ERROR,
+        };
+        if is_first {
+            // SAFETY: error is accessed only while holding the lock or by exclusive reference
+            unsafe { *self.error.get() = Some((idx, error)) };
+            self.error_idx.store(idx, Ordering::Release);
+        }
+
+        self.is_locked.store(false, Ordering::Release);
+    }
+
+    pub(super) fn into_inner(self) -> Option<(usize, E)> {
+        self.error.into_inner()
+    }
+}
@@ -0,0 +1,17 @@
+/// Determines which error is kept when multiple threads observe errors of a fallible concurrent iterator.
+///
+/// In either case, the source iterator is skipped to its end as soon as the first error is observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FirstErrorBy {
+    /// The error with the smallest index in the source is kept.
+    ///
+    /// Elements which are already pulled before the error is observed and which have a smaller index
+    /// than the error are still yielded, since they might lead to an error that comes earlier.
+    /// Hence, the result is deterministic regardless of the number of threads.
+    #[default]
+    Index,
+    /// The error which is observed first in time is kept.
+    ///
+    /// No further element is yielded once an error is observed.
+    Time,
+}
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests;
+
+mod con_iter;
+mod error_slot;
+mod first_error_by;
+mod try_concurrent_iter;
+
+pub use con_iter::ConIterTry;
+pub use first_error_by::FirstErrorBy;
+pub use try_concurrent_iter::TryConcurrentIter;
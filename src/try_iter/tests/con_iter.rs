@@ -0,0 +1,165 @@
+use crate::{
+    ConcurrentIter, IntoConcurrentIter, IterIntoConcurrentIter,
+    try_iter::{FirstErrorBy, TryConcurrentIter},
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use orx_concurrent_bag::ConcurrentBag;
+use test_case::test_matrix;
+
+#[cfg(miri)]
+const N: usize = 125;
+#[cfg(not(miri))]
+const N: usize = 4735;
+
+/// Elements `0..n` where elements at the `errors` positions are errors.
+fn results(n: usize, errors: &[usize]) -> Vec<Result<usize, String>> {
+    (0..n)
+        .map(|i| match errors.contains(&i) {
+            true => Err(i.to_string()),
+            false => Ok(i),
+        })
+        .collect()
+}
+
+#[test_matrix([FirstErrorBy::Index, FirstErrorBy::Time])]
+fn try_next(first_error_by: FirstErrorBy) {
+    let iter = results(6, &[3, 4]).into_con_iter().into_try(first_error_by);
+
+    assert!(!iter.has_error());
+    assert_eq!(iter.try_next(), Some(0));
+    assert_eq!(iter.try_next_with_idx(), Some((1, 1)));
+    assert_eq!(iter.try_next(), Some(2));
+    assert_eq!(iter.try_next(), None);
+    assert!(iter.has_error());
+    assert_eq!(iter.try_next(), None);
+
+    assert_eq!(iter.into_error(), Some((3, 3.to_string())));
+}
+
+#[test]
+fn no_error() {
+    let iter = results(6, &[])
+        .into_con_iter()
+        .into_try(FirstErrorBy::Index);
+    assert_eq!(iter.try_fold(0, |a, x| Ok(a + x)), Some(15));
+    assert!(!iter.has_error());
+    assert_eq!(iter.into_result(), Ok(()));
+}
+
+#[test]
+fn first_error_by_index() {
+    let iter = results(10, &[])
+        .into_con_iter()
+        .into_try(FirstErrorBy::Index);
+    assert_eq!(iter.try_next(), Some(0));
+    assert_eq!(iter.try_next(), Some(1));
+
+    iter.record_error(6, 6.to_string());
+    iter.record_error(3, 3.to_string());
+    iter.record_error(5, 5.to_string());
+    assert_eq!(iter.try_next(), None);
+    assert_eq!(iter.into_error(), Some((3, 3.to_string())));
+}
+
+#[test]
+fn first_error_by_time() {
+    let iter = results(10, &[])
+        .into_con_iter()
+        .into_try(FirstErrorBy::Time);
+
+    iter.record_error(6, 6.to_string());
+    iter.record_error(3, 3.to_string());
+    assert_eq!(iter.try_next(), None);
+    assert_eq!(iter.into_error(), Some((6, 6.to_string())));
+}
+
+#[test]
+fn error_of_closure() {
+    let iter = results(10, &[7])
+        .into_con_iter()
+        .into_try(FirstErrorBy::Index);
+
+    let mut processed = Vec::new();
+    iter.try_for_each(|x| match x < 4 {
+        true => {
+            processed.push(x);
+            Ok(())
+        }
+        false => Err(alloc::format!("closure-{x}")),
+    });
+
+    assert_eq!(processed, [0, 1, 2, 3]);
+    assert_eq!(iter.into_result(), Err("closure-4".to_string()));
+}
+
+#[test_matrix([0, 1, N], [1, 2, 4], [FirstErrorBy::Index, FirstErrorBy::Time])]
+fn try_for_each_without_error(n: usize, nt: usize, first_error_by: FirstErrorBy) {
+    let iter = results(n, &[]).into_con_iter().into_try(first_error_by);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                iter.try_for_each(|x| {
+                    bag.push(x);
+                    Ok(())
+                })
+            });
+        }
+    });
+
+    assert_eq!(iter.into_error(), None);
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected, (0..n).collect::<Vec<_>>());
+}
+
+#[test_matrix([2, N], [1, 2, 4])]
+fn try_fold_first_error_by_index(n: usize, nt: usize) {
+    let errors = [n / 2, n / 2 + 1, n - 1];
+    let iter = results(n, &errors)
+        .into_iter()
+        .iter_into_con_iter()
+        .into_try(FirstErrorBy::Index);
+
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| {
+                iter.try_fold(0, |a, x| {
+                    bag.push(x);
+                    Ok(a + x)
+                })
+            });
+        }
+    });
+
+    let first = errors[0];
+    assert_eq!(iter.into_error(), Some((first, first.to_string())));
+
+    // all elements before the first error are processed, regardless of the number of threads
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort();
+    assert_eq!(collected[..first], (0..first).collect::<Vec<_>>());
+}
+
+#[test_matrix([2, N], [1, 2, 4])]
+fn try_next_first_error_by_time(n: usize, nt: usize) {
+    let errors = [n / 2, n - 1];
+    let iter = results(n, &errors)
+        .into_con_iter()
+        .into_try(FirstErrorBy::Time);
+
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| while iter.try_next().is_some() {});
+        }
+    });
+
+    let (idx, error) = iter.into_error().expect("");
+    assert!(errors.contains(&idx));
+    assert_eq!(error, idx.to_string());
+}
@@ -0,0 +1,113 @@
+/// A fallible concurrent iterator which short-circuits on the first error.
+///
+/// Errors are recorded atomically together with their indices. As soon as an error is observed by any
+/// of the threads, iteration stops for all threads; and the error can be obtained by [`into_error`]
+/// or [`into_result`] once all threads complete.
+///
+/// A fallible concurrent iterator can be created by calling [`into_try`] on a concurrent iterator
+/// yielding `Result`s.
+///
+/// [`into_error`]: crate::TryConcurrentIter::into_error
+/// [`into_result`]: crate::TryConcurrentIter::into_result
+/// [`into_try`]: crate::ConcurrentIter::into_try
+///
+/// # Examples
+///
+/// ```
+/// use orx_concurrent_iter::*;
+///
+/// let records = vec!["1", "2", "x", "4", "y", "6"];
+/// let parsed = records.iter().map(|x| x.parse::<usize>());
+///
+/// let iter = parsed.iter_into_con_iter().into_try(FirstErrorBy::Index);
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..2 {
+///         s.spawn(|| iter.try_fold(0, |sum, x| Ok(sum + x)));
+///     }
+/// });
+///
+/// // "x" is the error with the smallest index
+/// let (idx, _error) = iter.into_error().unwrap();
+/// assert_eq!(idx, 2);
+/// ```
+pub trait TryConcurrentIter: Sync {
+    /// Type of the successful elements yielded by the iterator.
+    type Ok: Send;
+
+    /// Type of the error.
+    type Error: Send;
+
+    /// Returns the next successful element together with its index in the source.
+    ///
+    /// Returns `None` when the iterator is consumed, or when an error is observed by any of the threads.
+    fn try_next_with_idx(&self) -> Option<(usize, Self::Ok)>;
+
+    /// Records the `error` observed while processing the element at position `idx` of the source, and
+    /// stops the iteration for all threads.
+    fn record_error(&self, idx: usize, error: Self::Error);
+
+    /// Returns true if an error is observed by any of the threads.
+    fn has_error(&self) -> bool;
+
+    /// Consumes the iterator and returns the recorded error together with its index, if any.
+    fn into_error(self) -> Option<(usize, Self::Error)>;
+
+    /// Returns the next successful element.
+    ///
+    /// Returns `None` when the iterator is consumed, or when an error is observed by any of the threads.
+    fn try_next(&self) -> Option<Self::Ok> {
+        self.try_next_with_idx().map(|(_, x)| x)
+    }
+
+    /// Calls the fallible `f` on the remaining elements until the iterator is consumed or an error is observed.
+    ///
+    /// Errors returned by `f` are recorded in the same way as the errors of the source; hence, they stop the
+    /// iteration of all threads.
+    fn try_for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(Self::Ok) -> Result<(), Self::Error>,
+    {
+        while let Some((idx, x)) = self.try_next_with_idx() {
+            if let Err(error) = f(x) {
+                self.record_error(idx, error);
+                break;
+            }
+        }
+    }
+
+    /// Folds the remaining elements with the fallible `f` starting from `init`, until the iterator is
+    /// consumed or an error is observed.
+    ///
+    /// Errors returned by `f` are recorded in the same way as the errors of the source; hence, they stop the
+    /// iteration of all threads.
+    ///
+    /// Returns the accumulated value of this thread; or `None` if the iteration is stopped by an error.
+    fn try_fold<B, F>(&self, init: B, mut f: F) -> Option<B>
+    where
+        F: FnMut(B, Self::Ok) -> Result<B, Self::Error>,
+    {
+        let mut acc = init;
+        while let Some((idx, x)) = self.try_next_with_idx() {
+            match f(acc, x) {
+                Ok(x) => acc = x,
+                Err(error) => {
+                    self.record_error(idx, error);
+                    return None;
+                }
+            }
+        }
+        (!self.has_error()).then_some(acc)
+    }
+
+    /// Consumes the iterator and returns the recorded error, if any.
+    fn into_result(self) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        match self.into_error() {
+            Some((_, error)) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
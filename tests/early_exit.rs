@@ -52,7 +52,7 @@ fn early_exit_array() {
         for (i, x) in array.iter_mut().enumerate() {
             *x = i as i32;
         }
-        let iter = array.into_con_iter().cloned();
+        let iter = array.into_con_iter();
 
         let found = par_find(iter, predicate, num_threads, batch);
         assert_eq!(found, Some((987, 987)));
@@ -11,9 +11,9 @@ fn con_iter() {
     let array = ['a', 'b', 'c'];
 
     let con_iter = array.into_con_iter();
-    assert_eq!(con_iter.next(), Some(&'a'));
-    assert_eq!(con_iter.next(), Some(&'b'));
-    assert_eq!(con_iter.next(), Some(&'c'));
+    assert_eq!(con_iter.next(), Some('a'));
+    assert_eq!(con_iter.next(), Some('b'));
+    assert_eq!(con_iter.next(), Some('c'));
     assert_eq!(con_iter.next(), None);
 }
 
@@ -49,7 +49,7 @@ fn into_seq_iter_unused() {
 
     assert_eq!(seq_iter.len(), LEN);
     for (i, x) in seq_iter.enumerate() {
-        assert_eq!(x, &i);
+        assert_eq!(x, i);
     }
 }
 
@@ -73,7 +73,7 @@ fn into_seq_iter_used_singly() {
 
     assert_eq!(seq_iter.len(), LEN - 114);
     for (i, x) in seq_iter.enumerate() {
-        assert_eq!(x, &(114 + i));
+        assert_eq!(x, 114 + i);
     }
 }
 
@@ -83,7 +83,7 @@ fn into_seq_iter_used_in_batches() {
     for (i, x) in array.iter_mut().enumerate() {
         *x = i;
     }
-    let con_iter = array.into_con_iter().cloned();
+    let con_iter = array.into_con_iter();
 
     std::thread::scope(|s| {
         s.spawn(|| {
@@ -113,7 +113,7 @@ fn into_seq_iter_doc() {
     for (i, x) in array.iter_mut().enumerate() {
         *x = i;
     }
-    let con_iter = array.into_con_iter().cloned();
+    let con_iter = array.into_con_iter();
 
     std::thread::scope(|s| {
         s.spawn(|| {
@@ -143,7 +143,7 @@ fn into_seq_iter_not_used() {
     for (i, x) in values.iter_mut().enumerate() {
         *x = i;
     }
-    let iter = values.into_con_iter().cloned().into_seq_iter();
+    let iter = values.into_con_iter().into_seq_iter();
     let result: Vec<_> = iter.collect();
 
     assert_eq!(result, values);
@@ -156,7 +156,7 @@ fn into_seq_iter_used(take: usize) {
         *x = i;
     }
 
-    let iter = values.into_con_iter().cloned();
+    let iter = values.into_con_iter();
     for _ in 0..take {
         _ = iter.next();
     }
@@ -177,7 +177,7 @@ fn buffered(chunk_size: usize) {
     for (i, x) in values.iter_mut().enumerate() {
         *x = 100 + i;
     }
-    let iter = values.into_con_iter().cloned();
+    let iter = values.into_con_iter();
     let mut puller = iter.chunk_puller(chunk_size);
 
     let mut current = 100;
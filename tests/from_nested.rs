@@ -0,0 +1,123 @@
+use orx_concurrent_bag::ConcurrentBag;
+use orx_concurrent_iter::*;
+use test_case::test_matrix;
+
+#[cfg(not(miri))]
+const LEN: usize = 1024;
+#[cfg(miri)]
+const LEN: usize = 127;
+
+fn nested(len: usize) -> Vec<Vec<String>> {
+    let mut nested = vec![];
+    let mut begin = 0;
+    let mut f = 0;
+    while begin < len {
+        let end = (begin + (f * 7) % 13).min(len);
+        nested.push((begin..end).map(|x| x.to_string()).collect());
+        begin = end;
+        f += 1;
+    }
+    nested
+}
+
+fn collect<C>(con_iter: C, nt: usize, chunk: usize) -> Vec<(usize, C::Item)>
+where
+    C: ConcurrentIter,
+    C::Item: Sync,
+{
+    let bag = ConcurrentBag::new();
+    std::thread::scope(|s| {
+        for _ in 0..nt {
+            s.spawn(|| match chunk {
+                0 => {
+                    while let Some(x) = con_iter.next_with_idx() {
+                        bag.push(x);
+                    }
+                }
+                _ => {
+                    for x in con_iter.chunk_puller(chunk).flattened_with_idx() {
+                        bag.push(x);
+                    }
+                }
+            });
+        }
+    });
+    let mut collected = bag.into_inner().to_vec();
+    collected.sort_by_key(|x| x.0);
+    collected
+}
+
+#[test]
+fn flat_con_iter() {
+    let vectors = vec![vec!['a'], vec![], vec!['b', 'c']];
+
+    let con_iter = vectors.as_slice().into_flat_con_iter();
+    assert_eq!(con_iter.len(), 3);
+    assert_eq!(con_iter.next(), Some(&'a'));
+    assert_eq!(con_iter.next_with_idx(), Some((1, &'b')));
+    assert_eq!(con_iter.next(), Some(&'c'));
+    assert_eq!(con_iter.next(), None);
+
+    let con_iter = (&vectors).into_flat_con_iter();
+    assert_eq!(con_iter.into_seq_iter().count(), 3);
+
+    let slices: Vec<&[char]> = vectors.iter().map(|x| x.as_slice()).collect();
+    let con_iter = slices.as_slice().into_flat_con_iter();
+    assert_eq!(con_iter.into_seq_iter().collect::<String>(), "abc");
+
+    let con_iter = vectors.into_flat_con_iter();
+    assert_eq!(con_iter.next(), Some('a'));
+    assert_eq!(con_iter.into_seq_iter().collect::<String>(), "bc");
+}
+
+#[test]
+fn flat_con_iter_empty() {
+    let vectors: Vec<Vec<String>> = vec![];
+    assert_eq!(vectors.as_slice().into_flat_con_iter().next(), None);
+    assert_eq!(vectors.into_flat_con_iter().next(), None);
+
+    let vectors: Vec<Vec<String>> = vec![vec![], vec![]];
+    assert_eq!(vectors.as_slice().into_flat_con_iter().len(), 0);
+    assert_eq!(vectors.into_flat_con_iter().next(), None);
+}
+
+#[test_matrix([0, 1, LEN], [1, 4], [0, 1, 64])]
+fn flat_ref(len: usize, nt: usize, chunk: usize) {
+    let vectors = nested(len);
+    let expected: Vec<_> = vectors.iter().flatten().enumerate().collect();
+
+    let collected = collect(vectors.as_slice().into_flat_con_iter(), nt, chunk);
+    assert_eq!(collected, expected);
+
+    let slices: Vec<&[String]> = vectors.iter().map(|x| x.as_slice()).collect();
+    let collected = collect(slices.as_slice().into_flat_con_iter(), nt, chunk);
+    assert_eq!(collected, expected);
+}
+
+#[test_matrix([0, 1, LEN], [1, 4], [0, 1, 64], [0, 10])]
+fn flat_owned(len: usize, nt: usize, chunk: usize, num_taken: usize) {
+    let vectors = nested(len);
+    let expected: Vec<_> = vectors.iter().flatten().cloned().enumerate().collect();
+
+    let con_iter = vectors.into_flat_con_iter();
+    let mut taken = vec![];
+    for _ in 0..num_taken {
+        if let Some(x) = con_iter.next_with_idx() {
+            taken.push(x);
+        }
+    }
+    taken.extend(collect(con_iter, nt, chunk));
+    assert_eq!(taken, expected);
+}
+
+#[test]
+fn flat_owned_early_exit() {
+    let vectors = nested(LEN);
+    let con_iter = vectors.into_flat_con_iter();
+    for _ in 0..(LEN / 2) {
+        let _ = con_iter.next();
+    }
+    let _ = con_iter.chunk_puller(5).pull();
+    con_iter.skip_to_end();
+    assert_eq!(con_iter.next(), None);
+}